@@ -0,0 +1,126 @@
+//! Plays the gun tower's one-shot shoot animation on every
+//! press of [`KeyCode::Space`], through the game's own
+//! [`shoot_animation`] observer.
+
+use bevy::animation::AnimationTarget;
+use bevy::prelude::*;
+use bevy::scene::SceneInstanceReady;
+use recipe_game::{
+    AnimationGraphMap, Tower, TowerFired, shoot_animation,
+};
+
+const GUN_TOWER_PATH: &str = "prefabs/gun_tower.glb";
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            // Inserts the `Tower` authored in Blender.
+            bevy_skein::SkeinPlugin::default(),
+        ))
+        .register_type::<Tower>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (spawn_gun_tower, shoot))
+        .add_observer(shoot_animation)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(0.0, 2.0, -4.0)
+            .looking_at(Vec3::Y * 0.8, Vec3::Y),
+    ));
+    commands.spawn((
+        DirectionalLight {
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(2.0, 4.0, -2.0)
+            .looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    commands.insert_resource(GunTowerGltf(
+        asset_server.load(GUN_TOWER_PATH),
+    ));
+    commands.spawn(Text::new("Press Space to shoot!"));
+}
+
+/// Spawn the gun tower once its gltf is loaded, along with
+/// the animation graph built the same way as in game.
+fn spawn_gun_tower(
+    mut commands: Commands,
+    gun_tower_gltf: Option<Res<GunTowerGltf>>,
+    gltfs: Res<Assets<Gltf>>,
+    mut graphs: ResMut<Assets<AnimationGraph>>,
+) -> Result {
+    let Some(gun_tower_gltf) = gun_tower_gltf else {
+        return Ok(());
+    };
+    let Some(gltf) = gltfs.get(&gun_tower_gltf.0) else {
+        return Ok(());
+    };
+
+    let scene = gltf
+        .default_scene
+        .clone()
+        .ok_or("Gun tower should have a default scene.")?;
+
+    commands
+        .spawn((
+            SceneRoot(scene),
+            GunTowerAnimation(AnimationGraphMap::new(
+                gltf,
+                &mut graphs,
+            )),
+        ))
+        .observe(setup_animation_player);
+    commands.remove_resource::<GunTowerGltf>();
+
+    Ok(())
+}
+
+fn setup_animation_player(
+    trigger: Trigger<SceneInstanceReady>,
+    mut commands: Commands,
+    q_gun_tower_animations: Query<&GunTowerAnimation>,
+    q_children: Query<&Children>,
+    q_towers: Query<&AnimationTarget, With<Tower>>,
+) -> Result {
+    let entity = trigger.target();
+    let animation = &q_gun_tower_animations.get(entity)?.0;
+
+    for child in q_children.iter_descendants(entity) {
+        let Ok(animation_target) = q_towers.get(child) else {
+            continue;
+        };
+
+        commands.entity(child).insert(animation.node_map.clone());
+        commands.entity(animation_target.player).insert((
+            AnimationGraphHandle(animation.graph.clone()),
+            AnimationTransitions::new(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn shoot(
+    mut commands: Commands,
+    q_towers: Query<Entity, With<Tower>>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    if keys.just_pressed(KeyCode::Space) == false {
+        return;
+    }
+
+    for tower in q_towers.iter() {
+        commands.trigger_targets(TowerFired, tower);
+    }
+}
+
+#[derive(Resource)]
+struct GunTowerGltf(Handle<Gltf>);
+
+#[derive(Component)]
+struct GunTowerAnimation(AnimationGraphMap);
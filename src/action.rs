@@ -1,20 +1,29 @@
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
+use strum::EnumIter;
 
 use crate::player::{PlayerState, PlayerType, QueryPlayers};
 use crate::util::PropagateComponentAppExt;
 
+pub mod gamepad_connection;
+pub mod input_context;
+
 pub(super) struct ActionPlugin;
 
 impl Plugin for ActionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(InputManagerPlugin::<PlayerAction>::default())
-            .add_systems(
-                Update,
-                hookup_target_action
-                    .run_if(in_state(PlayerState::Possessed)),
-            )
-            .add_observer(setup_gamepad_index).propagate_component::<TargetAction, Children>();
+        app.add_plugins((
+            InputManagerPlugin::<PlayerAction>::default(),
+            gamepad_connection::GamepadConnectionPlugin,
+            input_context::InputContextPlugin,
+        ))
+        .add_systems(
+            Update,
+            hookup_target_action
+                .run_if(in_state(PlayerState::Possessed)),
+        )
+        .add_observer(setup_gamepad_index)
+        .propagate_component::<TargetAction, Children>();
     }
 }
 
@@ -58,8 +67,19 @@ fn setup_gamepad_index(
     info!("Setup `GamepadIndex` input map for gamepad {entity}.");
 }
 
+/// Every action is toggled according to the active
+/// [`InputContext`][input_context::InputContext],
+/// iterate them with [`IntoEnumIterator`][strum::IntoEnumIterator].
 #[derive(
-    Actionlike, Reflect, PartialEq, Eq, Clone, Copy, Hash, Debug,
+    Actionlike,
+    Reflect,
+    EnumIter,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Hash,
+    Debug,
 )]
 pub enum PlayerAction {
     #[actionlike(DualAxis)]
@@ -74,6 +94,19 @@ pub enum PlayerAction {
     CyclePrev,
     Placement,
     Cancel,
+    /// Pick up a placed tower to move it.
+    MoveTower,
+    /// Upgrade the inspected tower.
+    Upgrade,
+    /// Hold to sell the inspected tower.
+    Sell,
+    /// Show the enemy path and tower ranges in between waves.
+    PlanOverlay,
+    // Menu actions.
+    #[actionlike(DualAxis)]
+    Navigate,
+    Confirm,
+    Back,
 }
 
 impl PlayerAction {
@@ -96,6 +129,18 @@ impl PlayerAction {
             .with(Self::CyclePrev, GamepadButton::RightTrigger)
             .with(Self::Placement, GamepadButton::North)
             .with(Self::Cancel, GamepadButton::East)
+            .with(Self::MoveTower, GamepadButton::DPadUp)
+            .with(Self::Upgrade, GamepadButton::DPadDown)
+            .with(Self::Sell, GamepadButton::DPadLeft)
+            .with(Self::PlanOverlay, GamepadButton::Select)
+            // Menu bindings, sharing the buttons of gameplay.
+            .with_dual_axis(
+                Self::Navigate,
+                GamepadStick::LEFT.with_deadzone_symmetric(0.1),
+            )
+            .with_dual_axis(Self::Navigate, VirtualDPad::dpad())
+            .with(Self::Confirm, GamepadButton::South)
+            .with(Self::Back, GamepadButton::East)
     }
 
     /// Create a new [`InputMap`] for keyboard and mouse.
@@ -111,6 +156,17 @@ impl PlayerAction {
             .with(Self::CyclePrev, MouseScrollDirection::UP)
             .with(Self::Placement, MouseButton::Right)
             .with(Self::Cancel, KeyCode::KeyQ)
+            .with(Self::MoveTower, KeyCode::KeyR)
+            .with(Self::Upgrade, KeyCode::KeyF)
+            .with(Self::Sell, KeyCode::KeyX)
+            .with(Self::PlanOverlay, KeyCode::Tab)
+            // Menu bindings, sharing the keys of gameplay.
+            .with_dual_axis(Self::Navigate, VirtualDPad::wasd())
+            .with_dual_axis(Self::Navigate, VirtualDPad::arrow_keys())
+            .with(Self::Confirm, KeyCode::Space)
+            .with(Self::Confirm, KeyCode::Enter)
+            .with(Self::Back, KeyCode::KeyQ)
+            .with(Self::Back, KeyCode::Escape)
     }
 }
 
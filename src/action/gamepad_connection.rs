@@ -0,0 +1,292 @@
+use bevy::color::palettes::tailwind::*;
+use bevy::input::gamepad::{
+    GamepadConnection, GamepadConnectionEvent,
+};
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use leafwing_input_manager::prelude::*;
+
+use crate::camera_controller::UI_RENDER_LAYER;
+use crate::player::{
+    PlayerPossessor, PlayerState, PlayerType, Possession,
+    PossessorType,
+};
+use crate::ui::locale::LocalizedText;
+
+use super::PlayerAction;
+
+pub(super) struct GamepadConnectionPlugin;
+
+impl Plugin for GamepadConnectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DisconnectedPlayers>().add_systems(
+            Update,
+            (
+                cancel_disconnected_possession
+                    .run_if(resource_exists::<PlayerPossessor>)
+                    .run_if(in_state(PlayerState::Possessing)),
+                (handle_gamepad_connections, reconnect_prompt_ui)
+                    .chain()
+                    .run_if(in_state(PlayerState::Possessed)),
+            ),
+        );
+    }
+}
+
+/// Cancel the possession of gamepads that disconnects
+/// before the players are ready.
+fn cancel_disconnected_possession(
+    mut commands: Commands,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+    player_possessor: Res<PlayerPossessor>,
+) {
+    for event in connection_events.read() {
+        if event.disconnected() == false {
+            continue;
+        }
+
+        let possessor = PossessorType::Gamepad(event.gamepad);
+        if player_possessor.player_a == Some(possessor)
+            || player_possessor.player_b == Some(possessor)
+        {
+            commands.trigger(Possession {
+                player_type: None,
+                possessor,
+            });
+        }
+    }
+}
+
+/// Pause the game when a gamepad that is bound to a player disconnects
+/// and resume once every disconnected player gets a gamepad back.
+///
+/// A reconnecting gamepad is re-bound to the same player slot,
+/// otherwise it will be bound to the first disconnected player.
+fn handle_gamepad_connections(
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+    mut q_input_maps: Query<(
+        &PlayerType,
+        &mut InputMap<PlayerAction>,
+    )>,
+    mut disconnected_players: ResMut<DisconnectedPlayers>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    for event in connection_events.read() {
+        match &event.connection {
+            GamepadConnection::Disconnected => {
+                for (player_type, input_map) in q_input_maps.iter() {
+                    if input_map.gamepad() != Some(event.gamepad) {
+                        continue;
+                    }
+
+                    warn!(
+                        "Gamepad {} of {player_type:?} disconnected!",
+                        event.gamepad
+                    );
+                    disconnected_players.insert(*player_type);
+                }
+            }
+            GamepadConnection::Connected { name, .. } => {
+                // Same gamepad reconnecting to its original slot.
+                let bound_player = q_input_maps
+                    .iter()
+                    .find(|(_, input_map)| {
+                        input_map.gamepad() == Some(event.gamepad)
+                    })
+                    .map(|(player_type, _)| *player_type);
+
+                if let Some(player_type) = bound_player {
+                    info!("{name} reconnected to {player_type:?}.");
+                    disconnected_players.remove(player_type);
+                    continue;
+                }
+
+                // Hand the new gamepad to the first player waiting for one.
+                let Some(player_type) =
+                    disconnected_players.first().copied()
+                else {
+                    continue;
+                };
+
+                for (other_type, mut input_map) in
+                    q_input_maps.iter_mut()
+                {
+                    if *other_type == player_type {
+                        input_map.set_gamepad(event.gamepad);
+                    }
+                }
+
+                info!("{name} bound to {player_type:?}.");
+                disconnected_players.remove(player_type);
+            }
+        }
+    }
+
+    if disconnected_players.is_changed() == false {
+        return;
+    }
+
+    if disconnected_players.is_empty() {
+        time.unpause();
+    } else {
+        time.pause();
+    }
+}
+
+/// Show a reconnect prompt for every player that lost their gamepad.
+fn reconnect_prompt_ui(
+    mut commands: Commands,
+    q_prompts: Query<Entity, With<ReconnectPrompt>>,
+    disconnected_players: Res<DisconnectedPlayers>,
+) {
+    if disconnected_players.is_changed() == false {
+        return;
+    }
+
+    for entity in q_prompts.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    if disconnected_players.is_empty() {
+        return;
+    }
+
+    let root = commands
+        .spawn((
+            ReconnectPrompt,
+            UI_RENDER_LAYER,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(ZINC_950.with_alpha(0.6).into()),
+            FocusPolicy::Block,
+            // Should be on top of all other uis.
+            GlobalZIndex(20),
+        ))
+        .id();
+
+    for player_type in disconnected_players.iter() {
+        let key = match player_type {
+            PlayerType::A => "controller.reconnect_a",
+            PlayerType::B => "controller.reconnect_b",
+        };
+
+        commands.entity(root).with_child((
+            Node {
+                padding: UiRect::all(Val::Px(16.0)),
+                margin: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(RED_900.with_alpha(0.8).into()),
+            BorderRadius::all(Val::Px(8.0)),
+            Children::spawn(Spawn((
+                LocalizedText::new(key),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ))),
+        ));
+    }
+}
+
+/// Players that lost their gamepad mid-game, in the order of disconnection.
+#[derive(Resource, Deref, Default, Debug)]
+pub struct DisconnectedPlayers(Vec<PlayerType>);
+
+impl DisconnectedPlayers {
+    pub fn insert(&mut self, player_type: PlayerType) {
+        if self.0.contains(&player_type) == false {
+            self.0.push(player_type);
+        }
+    }
+
+    pub fn remove(&mut self, player_type: PlayerType) {
+        self.0.retain(|p| *p != player_type);
+    }
+}
+
+/// Tag component for the reconnect controller prompt ui.
+#[derive(Component)]
+pub struct ReconnectPrompt;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn connection_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<GamepadConnectionEvent>()
+            .init_resource::<DisconnectedPlayers>()
+            .add_systems(Update, handle_gamepad_connections);
+
+        let gamepad = app.world_mut().spawn_empty().id();
+        app.world_mut().spawn((
+            PlayerAction::new_gamepad().with_gamepad(gamepad),
+            PlayerType::A,
+        ));
+        app.world_mut()
+            .spawn((PlayerAction::new_kbm(), PlayerType::B));
+
+        (app, gamepad)
+    }
+
+    #[test]
+    fn test_disconnect_pauses_and_prompts() {
+        let (mut app, gamepad) = connection_app();
+
+        app.world_mut().send_event(GamepadConnectionEvent {
+            gamepad,
+            connection: GamepadConnection::Disconnected,
+        });
+        app.update();
+
+        let disconnected =
+            app.world().resource::<DisconnectedPlayers>();
+        assert_eq!(disconnected.as_slice(), &[PlayerType::A]);
+        assert!(app.world().resource::<Time<Virtual>>().is_paused());
+    }
+
+    #[test]
+    fn test_reconnect_rebinds_and_resumes() {
+        let (mut app, gamepad) = connection_app();
+
+        app.world_mut().send_event(GamepadConnectionEvent {
+            gamepad,
+            connection: GamepadConnection::Disconnected,
+        });
+        app.update();
+
+        // A different gamepad takes over the empty slot.
+        let new_gamepad = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(GamepadConnectionEvent {
+            gamepad: new_gamepad,
+            connection: GamepadConnection::Connected {
+                name: "Test Gamepad".to_string(),
+                vendor_id: None,
+                product_id: None,
+            },
+        });
+        app.update();
+
+        assert!(
+            app.world().resource::<DisconnectedPlayers>().is_empty()
+        );
+        assert!(
+            app.world().resource::<Time<Virtual>>().is_paused()
+                == false
+        );
+
+        let mut q_input_maps = app
+            .world_mut()
+            .query::<(&PlayerType, &InputMap<PlayerAction>)>();
+        let gamepad_a = q_input_maps
+            .iter(app.world())
+            .find(|(p, _)| **p == PlayerType::A)
+            .and_then(|(_, input_map)| input_map.gamepad());
+        assert_eq!(gamepad_a, Some(new_gamepad));
+    }
+}
@@ -0,0 +1,273 @@
+use bevy::prelude::*;
+use leafwing_input_manager::plugin::InputManagerSystem;
+use leafwing_input_manager::prelude::*;
+use strum::IntoEnumIterator;
+
+use crate::tower::InPlacementMode;
+use crate::ui::Screen;
+
+use super::gamepad_connection::DisconnectedPlayers;
+use super::{PlayerAction, TargetAction};
+
+pub(super) struct InputContextPlugin;
+
+impl Plugin for InputContextPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<
+            InputMap<PlayerAction>,
+            InputContexts,
+        >();
+
+        app.add_systems(
+            PreUpdate,
+            apply_input_context.after(InputManagerSystem::Update),
+        )
+        .add_systems(
+            Update,
+            (
+                menu_context_on_pause
+                    .run_if(resource_changed::<DisconnectedPlayers>),
+                menu_context_on_menu_screens,
+            ),
+        )
+        .add_observer(push_placement_context)
+        .add_observer(pop_placement_context);
+    }
+}
+
+/// Only keep the [`PlayerAction`]s of the current
+/// [`InputContext`] enabled.
+fn apply_input_context(
+    mut q_actions: Query<
+        (&InputContexts, &mut ActionState<PlayerAction>),
+        Changed<InputContexts>,
+    >,
+) {
+    for (contexts, mut action_state) in q_actions.iter_mut() {
+        let context = contexts.current();
+
+        for action in PlayerAction::iter() {
+            let enabled = context.allows(action);
+
+            if enabled && action_state.action_disabled(&action) {
+                action_state.enable_action(&action);
+            } else if enabled == false
+                && action_state.action_disabled(&action) == false
+            {
+                action_state.disable_action(&action);
+            }
+        }
+    }
+}
+
+/// Every player navigates the reconnect prompt
+/// while the game is paused.
+fn menu_context_on_pause(
+    mut q_contexts: Query<&mut InputContexts>,
+    disconnected_players: Res<DisconnectedPlayers>,
+    mut paused: Local<bool>,
+) {
+    let should_pause = disconnected_players.is_empty() == false;
+    if *paused == should_pause {
+        return;
+    }
+    *paused = should_pause;
+
+    for mut contexts in q_contexts.iter_mut() {
+        if should_pause {
+            contexts.push(InputContext::Menu);
+        } else {
+            contexts.pop(InputContext::Menu);
+        }
+    }
+}
+
+/// Navigate the buttons of the main menu and the game over screen.
+fn menu_context_on_menu_screens(
+    mut commands: Commands,
+    mut q_contexts: Query<(
+        &mut InputContexts,
+        Has<MenuScreenContext>,
+        Entity,
+    )>,
+    screen: Option<Res<State<Screen>>>,
+) {
+    let on_menu_screen = screen.is_some_and(|screen| {
+        matches!(screen.get(), Screen::Menu | Screen::GameOver)
+    });
+
+    for (mut contexts, pushed, entity) in q_contexts.iter_mut() {
+        if on_menu_screen && pushed == false {
+            contexts.push(InputContext::Menu);
+            commands.entity(entity).insert(MenuScreenContext);
+        } else if on_menu_screen == false && pushed {
+            contexts.pop(InputContext::Menu);
+            commands.entity(entity).remove::<MenuScreenContext>();
+        }
+    }
+}
+
+fn push_placement_context(
+    trigger: Trigger<OnAdd, InPlacementMode>,
+    q_target_actions: Query<&TargetAction>,
+    mut q_contexts: Query<&mut InputContexts>,
+) -> Result {
+    let target_action = q_target_actions.get(trigger.target())?;
+    q_contexts
+        .get_mut(target_action.get())?
+        .push(InputContext::Placement);
+
+    Ok(())
+}
+
+fn pop_placement_context(
+    trigger: Trigger<OnRemove, InPlacementMode>,
+    q_target_actions: Query<&TargetAction>,
+    mut q_contexts: Query<&mut InputContexts>,
+) {
+    let Ok(target_action) = q_target_actions.get(trigger.target())
+    else {
+        return;
+    };
+
+    // The action entity might be despawned along with the level.
+    if let Ok(mut contexts) = q_contexts.get_mut(target_action.get())
+    {
+        contexts.pop(InputContext::Placement);
+    }
+}
+
+/// The [`InputContext::Menu`] of a menu screen was pushed.
+#[derive(Component, Debug)]
+struct MenuScreenContext;
+
+/// Whether any player is in the given [`InputContext`].
+pub fn in_input_context(
+    context: InputContext,
+) -> impl Fn(Query<&InputContexts>) -> bool + Clone {
+    move |q_contexts: Query<&InputContexts>| {
+        q_contexts
+            .iter()
+            .any(|contexts| contexts.current() == context)
+    }
+}
+
+/// What the inputs of a player are currently used for.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputContext {
+    #[default]
+    Gameplay,
+    /// Navigating a menu or a prompt.
+    Menu,
+    /// Placing or moving a tower.
+    Placement,
+}
+
+impl InputContext {
+    /// Whether the action is used in this context.
+    pub fn allows(&self, action: PlayerAction) -> bool {
+        use PlayerAction::*;
+
+        match self {
+            InputContext::Gameplay => {
+                matches!(action, Navigate | Confirm | Back) == false
+            }
+            InputContext::Menu => {
+                matches!(action, Navigate | Confirm | Back)
+            }
+            InputContext::Placement => matches!(
+                action,
+                Move | Aim
+                    | Jump
+                    | CycleNext
+                    | CyclePrev
+                    | Placement
+                    | Cancel
+                    | PlanOverlay
+            ),
+        }
+    }
+}
+
+/// Stack of [`InputContext`]s of a player, the topmost one is active.
+///
+/// Required by the [`InputMap<PlayerAction>`] entities.
+#[derive(Component, Default, Debug, Clone)]
+pub struct InputContexts(Vec<InputContext>);
+
+impl InputContexts {
+    /// The active context, [`InputContext::Gameplay`] when empty.
+    pub fn current(&self) -> InputContext {
+        self.0.last().copied().unwrap_or_default()
+    }
+
+    pub fn push(&mut self, context: InputContext) {
+        self.0.push(context);
+    }
+
+    /// Remove the topmost entry of the given context,
+    /// regardless of whether it is the active one.
+    pub fn pop(&mut self, context: InputContext) {
+        if let Some(index) =
+            self.0.iter().rposition(|c| *c == context)
+        {
+            self.0.remove(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_movement_ignored_in_menu_context() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, apply_input_context);
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                InputContexts::default(),
+                ActionState::<PlayerAction>::default(),
+            ))
+            .id();
+
+        let press_move = |app: &mut App| {
+            let mut action_state = app
+                .world_mut()
+                .get_mut::<ActionState<PlayerAction>>(entity)
+                .unwrap();
+            action_state.set_axis_pair(&PlayerAction::Move, Vec2::Y);
+            action_state.axis_pair(&PlayerAction::Move)
+        };
+
+        app.update();
+        assert_eq!(press_move(&mut app), Vec2::Y);
+
+        app.world_mut()
+            .get_mut::<InputContexts>(entity)
+            .unwrap()
+            .push(InputContext::Menu);
+        app.update();
+
+        assert_eq!(press_move(&mut app), Vec2::ZERO);
+        let action_state = app
+            .world()
+            .get::<ActionState<PlayerAction>>(entity)
+            .unwrap();
+        assert!(
+            action_state.action_disabled(&PlayerAction::Navigate)
+                == false
+        );
+
+        app.world_mut()
+            .get_mut::<InputContexts>(entity)
+            .unwrap()
+            .pop(InputContext::Menu);
+        app.update();
+
+        assert_eq!(press_move(&mut app), Vec2::Y);
+    }
+}
@@ -38,6 +38,7 @@ pub struct SceneAssetsLoader<'w, 's> {
     scenes: Res<'w, SceneAssets>,
     gltfs: Res<'w, Assets<Gltf>>,
     current_scene: ResMut<'w, CurrentScene>,
+    q_level_scoped: Query<'w, 's, Entity, With<LevelScoped>>,
 }
 
 impl SceneAssetsLoader<'_, '_> {
@@ -62,25 +63,31 @@ impl SceneAssetsLoader<'_, '_> {
             .get(&self.scenes.level1)
             .ok_or("Scene should have been loaded")?;
 
-        self.load_scene(
+        let scene = self.load_scene(
             gltf.default_scene
                 .clone()
                 .expect("Should have a default scene."),
         );
+        self.commands.entity(scene).insert(LevelId("level1"));
 
         Ok(())
     }
 
-    /// Despawn the last scene and spawns a new scene,
-    /// overwritting the [`CurrentScene`].
-    fn load_scene(&mut self, scene: Handle<Scene>) {
+    /// Despawn the last scene (along with every [`LevelScoped`]
+    /// entity) and spawns a new scene, overwritting the [`CurrentScene`].
+    fn load_scene(&mut self, scene: Handle<Scene>) -> Entity {
         if let Some(last_scene) = self.current_scene.get() {
             self.commands.entity(last_scene).despawn();
         }
 
+        for entity in self.q_level_scoped.iter() {
+            self.commands.entity(entity).try_despawn();
+        }
+
         let id = self.commands.spawn(SceneRoot(scene)).id();
 
         self.current_scene.0 = Some(id);
+        id
     }
 }
 
@@ -89,9 +96,9 @@ impl SceneAssetsLoader<'_, '_> {
 #[cfg_attr(feature = "dev", reflect(Resource))]
 pub struct SceneAssets {
     #[asset(key = "scenes.default")]
-    default_scene: Handle<Gltf>,
+    pub default_scene: Handle<Gltf>,
     #[asset(key = "scenes.level1")]
-    level1: Handle<Gltf>,
+    pub level1: Handle<Gltf>,
 }
 
 #[derive(AssetCollection, Resource, Debug)]
@@ -148,6 +155,17 @@ pub enum AssetState {
     Loaded,
 }
 
+/// Entities that belong to the current level even when they are not
+/// a descendant of the [`CurrentScene`] (e.g. projectiles), despawned
+/// whenever a new scene is loaded.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct LevelScoped;
+
+/// Identifies the level that a scene root was loaded from,
+/// used as the key into per-level metadata.
+#[derive(Component, Deref, Debug, Clone, Copy)]
+pub struct LevelId(pub &'static str);
+
 /// The current loaded scene instance.
 #[derive(Resource, Deref, Default, Debug)]
 pub struct CurrentScene(Option<Entity>);
@@ -156,4 +174,69 @@ impl CurrentScene {
     pub fn get(&self) -> Option<Entity> {
         self.0
     }
+
+    /// Overwrite the current scene without loading it.
+    #[cfg(test)]
+    pub fn set(&mut self, scene: Entity) {
+        self.0 = Some(scene);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::enemy::Enemy;
+    use crate::test_util::empty_gltf;
+
+    #[test]
+    fn test_level_entities_are_despawned_on_load() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Gltf>()
+            .init_asset::<Scene>()
+            .init_resource::<CurrentScene>();
+
+        let default_scene = empty_gltf(app.world_mut());
+        let level1 = empty_gltf(app.world_mut());
+        app.insert_resource(SceneAssets {
+            default_scene,
+            level1,
+        });
+
+        app.world_mut()
+            .run_system_once(|mut scenes: SceneAssetsLoader| {
+                scenes.load_level1()
+            })
+            .unwrap()
+            .unwrap();
+        let last_scene = app.world().resource::<CurrentScene>().get();
+
+        // Leftovers from the previous level.
+        let scene_child =
+            app.world_mut().spawn(ChildOf(last_scene.unwrap())).id();
+        let enemy = app
+            .world_mut()
+            .spawn(Enemy {
+                movement_speed: 1.0,
+                damage: 1.0,
+                attack_cooldown: 1.0,
+            })
+            .id();
+
+        app.world_mut()
+            .run_system_once(|mut scenes: SceneAssetsLoader| {
+                scenes.load_default_scene()
+            })
+            .unwrap()
+            .unwrap();
+
+        assert!(app.world().get_entity(scene_child).is_err());
+        assert!(app.world().get_entity(enemy).is_err());
+        assert_ne!(
+            app.world().resource::<CurrentScene>().get(),
+            last_scene
+        );
+    }
 }
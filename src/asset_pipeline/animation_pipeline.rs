@@ -34,32 +34,9 @@ fn setup_prefab_animation_graphs(
             .get_gltf(PrefabName::Absolute(name), &gltfs)
             .ok_or("Prefab should have been loaded.")?;
 
-        let mut graph = AnimationGraph::new();
-        let mut node_map = HashMap::new();
-
-        for (name, clip) in gltf.named_animations.iter() {
-            let Some(node_name) =
-                name.split('.').nth(1).map(|name| name.to_string())
-            else {
-                warn!(
-                    "Animation should have exactly 1 '.', got '{name}' instead."
-                );
-                continue;
-            };
-
-            node_map.insert(
-                node_name,
-                graph.add_clip(clip.clone(), 1.0, graph.root),
-            );
-        }
-
-        let graph_handle = graphs.add(graph);
         named_graphs.push((
             name.clone(),
-            AnimationGraphMap {
-                graph: graph_handle,
-                node_map: NodeMap(Arc::new(node_map)),
-            },
+            AnimationGraphMap::new(gltf, &mut graphs),
         ));
     }
 
@@ -106,6 +83,39 @@ pub struct AnimationGraphMap {
     pub node_map: NodeMap,
 }
 
+impl AnimationGraphMap {
+    /// Add every named animation of the `gltf` to a new graph,
+    /// "Gun.OnShoot" is mapped as "OnShoot".
+    pub fn new(
+        gltf: &Gltf,
+        graphs: &mut Assets<AnimationGraph>,
+    ) -> Self {
+        let mut graph = AnimationGraph::new();
+        let mut node_map = HashMap::new();
+
+        for (name, clip) in gltf.named_animations.iter() {
+            let Some(node_name) =
+                name.split('.').nth(1).map(|name| name.to_string())
+            else {
+                warn!(
+                    "Animation should have exactly 1 '.', got '{name}' instead."
+                );
+                continue;
+            };
+
+            node_map.insert(
+                node_name,
+                graph.add_clip(clip.clone(), 1.0, graph.root),
+            );
+        }
+
+        Self {
+            graph: graphs.add(graph),
+            node_map: NodeMap(Arc::new(node_map)),
+        }
+    }
+}
+
 #[derive(Component, Deref, Debug, Clone)]
 #[cfg_attr(feature = "dev", derive(Reflect))]
 pub struct NodeMap(Arc<HashMap<String, AnimationNodeIndex>>);
@@ -1,8 +1,18 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_seedling::prelude::*;
 use bevy_seedling::sample::Sample;
 
+use crate::enemy::boss_phase::BossPhases;
+use crate::enemy::spawner::{SpawnWave, WaveStarted};
+use crate::enemy::{EnemyDied, EnemyHit, EnemySfx};
 use crate::machine::{Machine, OperationTimer};
+use crate::player::player_mark::PlayerMark;
+use crate::rng::GameRng;
+use crate::settings::Settings;
+use crate::tower::tower_attack::{
+    FiredBy, ProjectileImpact, TowerFired, TowerSfx,
+};
 use crate::ui::Screen;
 
 pub(super) struct AudioPlugin;
@@ -11,43 +21,172 @@ impl Plugin for AudioPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(SeedlingPlugin::default())
             .init_resource::<GameAudio>()
+            .init_resource::<MusicStems>()
+            .init_resource::<MusicIntensity>()
+            .init_resource::<SfxVoices>()
+            .add_systems(Startup, spawn_sfx_pool)
+            .add_systems(Update, drop_failed_sfx_voices)
             .add_systems(OnEnter(Screen::Menu), start_menu_music)
             .add_systems(
                 OnEnter(Screen::EnterLevel),
                 start_game_music,
             )
+            .add_systems(
+                Update,
+                (
+                    calm_music_between_waves
+                        .run_if(state_changed::<SpawnWave>),
+                    fade_music_stems,
+                )
+                    .chain()
+                    .run_if(in_state(Screen::EnterLevel)),
+            )
             .add_systems(OnEnter(Screen::GameOver), game_over_music)
+            .add_observer(intensify_music_on_wave_start)
+            .add_observer(play_boss_sting)
             .add_observer(start_machine_audio)
-            .add_observer(stop_machine_audio);
+            .add_observer(stop_machine_audio)
+            .add_observer(play_enemy_spawn_sfx)
+            .add_observer(play_enemy_death_sfx)
+            .add_observer(play_enemy_hit_sfx)
+            .add_observer(play_tower_fire_sfx)
+            .add_observer(play_projectile_impact_sfx)
+            .add_observer(release_sfx_voice);
     }
 }
 
 /// Start menu background music.
-fn start_menu_music(mut commands: Commands, audio: Res<GameAudio>) {
+fn start_menu_music(
+    mut commands: Commands,
+    audio: Res<GameAudio>,
+    settings: Res<Settings>,
+) {
     commands.spawn((
         SamplePlayer::new(audio.menu_music.clone())
             .looping()
-            .with_volume(Volume::Linear(0.4)),
+            .with_volume(Volume::Linear(0.4 * settings.music_volume)),
         StateScoped(Screen::Menu),
     ));
 }
 
-/// Start in-game background music.
-fn start_game_music(mut commands: Commands, audio: Res<GameAudio>) {
-    commands.spawn((
-        SamplePlayer::new(audio.game_music.clone())
-            .looping()
-            .with_volume(Volume::Linear(0.3)),
-        StateScoped(Screen::EnterLevel),
-    ));
+/// Start every in-game [`MusicStem`] silently,
+/// [`fade_music_stems`] brings in the ones that match
+/// the current [`MusicIntensity`].
+fn start_game_music(
+    mut commands: Commands,
+    stems: Res<MusicStems>,
+    mut intensity: ResMut<MusicIntensity>,
+) {
+    *intensity = MusicIntensity::Calm;
+
+    for stem in stems.iter() {
+        let mut entity = commands.spawn((
+            SamplePlayer::new(stem.sample.clone()).looping(),
+            MusicStem {
+                intensity: stem.intensity,
+                max_volume: stem.volume,
+                volume: 0.0,
+            },
+            StateScoped(Screen::EnterLevel),
+        ));
+
+        let volume = VolumeNode {
+            volume: Volume::SILENT,
+        };
+        match stem.low_pass {
+            Some(frequency) => entity.insert(sample_effects![
+                LowPassNode { frequency },
+                volume
+            ]),
+            None => entity.insert(sample_effects![volume]),
+        };
+    }
 }
 
-/// Start game over background music.
-fn game_over_music(mut commands: Commands, audio: Res<GameAudio>) {
+/// Planning phase in between waves.
+fn calm_music_between_waves(mut intensity: ResMut<MusicIntensity>) {
+    *intensity = MusicIntensity::Calm;
+}
+
+fn intensify_music_on_wave_start(
+    _: Trigger<WaveStarted>,
+    mut intensity: ResMut<MusicIntensity>,
+) {
+    *intensity = MusicIntensity::Combat;
+}
+
+/// Cross-fade between the stems based on the [`MusicIntensity`].
+fn fade_music_stems(
+    mut q_stems: Query<(&mut MusicStem, &SampleEffects)>,
+    mut q_volume_nodes: Query<&mut VolumeNode>,
+    intensity: Res<MusicIntensity>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) {
+    /// Duration in seconds for a stem to fade fully in or out.
+    const FADE_DURATION: f32 = 2.0;
+
+    for (mut stem, effects) in q_stems.iter_mut() {
+        let target = if stem.intensity == *intensity {
+            stem.max_volume * settings.music_volume
+        } else {
+            0.0
+        };
+
+        let max_delta =
+            stem.max_volume / FADE_DURATION * time.delta_secs();
+        let volume = stem.volume
+            + (target - stem.volume).clamp(-max_delta, max_delta);
+
+        if volume == stem.volume {
+            continue;
+        }
+        stem.volume = volume;
+
+        for effect in effects.iter() {
+            if let Ok(mut volume_node) =
+                q_volume_nodes.get_mut(effect)
+            {
+                volume_node.volume = Volume::Linear(volume);
+            }
+        }
+    }
+}
+
+/// Announce a boss with a one-shot sting.
+fn play_boss_sting(
+    _: Trigger<OnAdd, BossPhases>,
+    mut commands: Commands,
+    audio: Res<GameAudio>,
+    settings: Res<Settings>,
+) {
+    commands.spawn(
+        SamplePlayer::new(audio.boss_sting.clone())
+            .with_volume(Volume::Linear(0.6 * settings.music_volume)),
+    );
+}
+
+/// Start game over background music,
+/// led in by a victory or defeat sting.
+fn game_over_music(
+    mut commands: Commands,
+    audio: Res<GameAudio>,
+    settings: Res<Settings>,
+    player_mark: Res<PlayerMark>,
+) {
+    let sting = match player_mark.0 > 0 {
+        true => audio.victory_sting.clone(),
+        false => audio.defeat_sting.clone(),
+    };
+    commands.spawn((
+        SamplePlayer::new(sting)
+            .with_volume(Volume::Linear(0.6 * settings.music_volume)),
+        StateScoped(Screen::GameOver),
+    ));
     commands.spawn((
         SamplePlayer::new(audio.game_over_music.clone())
             .looping()
-            .with_volume(Volume::Linear(0.4)),
+            .with_volume(Volume::Linear(0.4 * settings.music_volume)),
         StateScoped(Screen::GameOver),
     ));
 }
@@ -111,10 +250,330 @@ fn stop_machine_audio(
     commands.entity(machine_entity).remove::<PlayingAudio>();
 }
 
+fn play_enemy_spawn_sfx(
+    trigger: Trigger<OnAdd, EnemySfx>,
+    q_sfxs: Query<(&EnemySfx, Option<&GlobalTransform>)>,
+    mut sfx_player: SfxPlayer,
+) -> Result {
+    let (sfx, global_transform) = q_sfxs.get(trigger.target())?;
+
+    if let Some(path) = &sfx.spawn {
+        sfx_player.play_from(
+            &format!("audios/enemy/{path}"),
+            global_transform,
+        );
+    }
+
+    Ok(())
+}
+
+fn play_enemy_death_sfx(
+    trigger: Trigger<EnemyDied>,
+    q_sfxs: Query<(&EnemySfx, Option<&GlobalTransform>)>,
+    mut sfx_player: SfxPlayer,
+) {
+    let Ok((sfx, global_transform)) = q_sfxs.get(trigger.target())
+    else {
+        return;
+    };
+
+    if let Some(path) = &sfx.death {
+        sfx_player.play_from(
+            &format!("audios/enemy/{path}"),
+            global_transform,
+        );
+    }
+}
+
+fn play_enemy_hit_sfx(
+    trigger: Trigger<EnemyHit>,
+    q_sfxs: Query<(&EnemySfx, Option<&GlobalTransform>)>,
+    mut sfx_player: SfxPlayer,
+) {
+    let Ok((sfx, global_transform)) = q_sfxs.get(trigger.target())
+    else {
+        return;
+    };
+
+    if let Some(path) = &sfx.hit {
+        sfx_player.play_from(
+            &format!("audios/enemy/{path}"),
+            global_transform,
+        );
+    }
+}
+
+fn play_tower_fire_sfx(
+    trigger: Trigger<TowerFired>,
+    q_sfxs: Query<(&TowerSfx, &GlobalTransform)>,
+    mut sfx_player: SfxPlayer,
+) {
+    let Ok((sfx, global_transform)) = q_sfxs.get(trigger.target())
+    else {
+        return;
+    };
+
+    if let Some(path) = &sfx.fire {
+        sfx_player.play_at(
+            &format!("audios/tower/{path}"),
+            global_transform.translation(),
+        );
+    }
+}
+
+/// Play the impact sound of the tower that fired the projectile.
+fn play_projectile_impact_sfx(
+    trigger: Trigger<ProjectileImpact>,
+    q_fired_bys: Query<&FiredBy>,
+    q_sfxs: Query<&TowerSfx>,
+    mut sfx_player: SfxPlayer,
+) {
+    let Some(sfx) = q_fired_bys
+        .get(trigger.target())
+        .and_then(|fired_by| q_sfxs.get(fired_by.0))
+        .ok()
+    else {
+        return;
+    };
+
+    if let Some(path) = &sfx.impact {
+        sfx_player.play_at(
+            &format!("audios/tower/{path}"),
+            trigger.position,
+        );
+    }
+}
+
+/// Startup system: spawn the [`SfxPool`] with a sampler
+/// for every voice.
+fn spawn_sfx_pool(mut commands: Commands) {
+    commands.spawn((
+        SamplerPool(SfxPool),
+        PoolSize(SfxVoices::MAX_ACTIVE..=SfxVoices::MAX_ACTIVE),
+        sample_effects![sfx_spatial_node()],
+    ));
+}
+
+/// Pans and attenuates positional sound effects,
+/// leaves the rest centered at full volume.
+fn sfx_spatial_node() -> SpatialBasicNode {
+    SpatialBasicNode {
+        panning_threshold: 0.4,
+        ..default()
+    }
+}
+
+/// Free the voice of sound effects that will never play.
+fn drop_failed_sfx_voices(
+    mut commands: Commands,
+    q_voices: Query<(&SamplePlayer, Entity), With<SfxVoice>>,
+    asset_server: Res<AssetServer>,
+) {
+    for (player, entity) in q_voices.iter() {
+        if asset_server.load_state(&player.sample).is_failed() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn release_sfx_voice(
+    _: Trigger<OnRemove, SfxVoice>,
+    mut voices: ResMut<SfxVoices>,
+) {
+    voices.active = voices.active.saturating_sub(1);
+}
+
+/// Plays one-shot sound effects with a slight pitch variation,
+/// dropping them once [`SfxVoices::MAX_ACTIVE`] are playing.
+///
+/// Positional sound effects are panned towards and attenuated by
+/// the nearest [`SpatialListener3D`], so in split-screen a sound is
+/// as loud as it is for the closest player.
+#[derive(SystemParam)]
+pub struct SfxPlayer<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    voices: ResMut<'w, SfxVoices>,
+    settings: Res<'w, Settings>,
+    rng: ResMut<'w, GameRng>,
+    q_listeners: Query<
+        'w,
+        's,
+        &'static GlobalTransform,
+        With<SpatialListener3D>,
+    >,
+}
+
+impl SfxPlayer<'_, '_> {
+    /// Max deviation of the playback speed from `1.0`.
+    pub const PITCH_VARIATION: f32 = 0.1;
+
+    /// Distance from the listener at which positional
+    /// sound effects become inaudible.
+    pub const MAX_DISTANCE: f32 = 30.0;
+
+    /// Returns `false` if the sound effect got dropped.
+    pub fn play(&mut self, path: &str) -> bool {
+        self.spawn(path, None)
+    }
+
+    /// Play a sound effect at `position`, attenuated and panned
+    /// by the [`SpatialBasicNode`] of the [`SfxPool`].
+    ///
+    /// Returns `false` if the sound effect got dropped,
+    /// ones beyond [`Self::MAX_DISTANCE`] never take up a voice.
+    pub fn play_at(&mut self, path: &str, position: Vec3) -> bool {
+        // Without any listener, nothing is out of reach.
+        let in_reach = self
+            .q_listeners
+            .iter()
+            .map(|listener| listener.translation().distance(position))
+            .reduce(f32::min)
+            .is_none_or(|distance| distance < Self::MAX_DISTANCE);
+
+        if in_reach == false {
+            return false;
+        }
+
+        self.spawn(path, Some(position))
+    }
+
+    /// Play at the given transform if any, see [`Self::play_at`].
+    pub fn play_from(
+        &mut self,
+        path: &str,
+        global_transform: Option<&GlobalTransform>,
+    ) -> bool {
+        match global_transform {
+            Some(global_transform) => {
+                self.play_at(path, global_transform.translation())
+            }
+            None => self.play(path),
+        }
+    }
+
+    fn spawn(&mut self, path: &str, position: Option<Vec3>) -> bool {
+        if self.voices.active >= SfxVoices::MAX_ACTIVE {
+            return false;
+        }
+        self.voices.active += 1;
+
+        let speed = self.rng.range_f32(
+            1.0 - Self::PITCH_VARIATION,
+            1.0 + Self::PITCH_VARIATION,
+        );
+
+        let mut voice = self.commands.spawn((
+            SfxVoice,
+            SfxPool,
+            SamplePlayer::new(
+                self.asset_server.load(path.to_string()),
+            )
+            .with_volume(Volume::Linear(self.settings.sfx_volume)),
+            bevy_seedling::prelude::PlaybackSettings {
+                speed: speed as f64,
+                ..default()
+            },
+        ));
+
+        // Without a transform, the spatial node stays centered.
+        if let Some(position) = position {
+            voice.insert(Transform::from_translation(position));
+        }
+
+        true
+    }
+}
+
+/// Number of sound effects playing at once.
+#[derive(Resource, Default, Debug)]
+pub struct SfxVoices {
+    active: usize,
+}
+
+impl SfxVoices {
+    /// Sound effects beyond this are dropped to prevent
+    /// clipping during large waves.
+    pub const MAX_ACTIVE: usize = 12;
+
+    pub fn active(&self) -> usize {
+        self.active
+    }
+}
+
+/// Sampler pool that plays every sound effect.
+#[derive(PoolLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SfxPool;
+
+/// A sound effect counted by the [`SfxVoices`],
+/// despawned once it finishes playing.
+#[derive(Component, Debug)]
+pub struct SfxVoice;
+
 /// Component that stores the entity ID of the playing audio
 #[derive(Component)]
 struct PlayingAudio(Entity);
 
+/// How intense the in-game music should be.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MusicIntensity {
+    /// Planning phase in between waves.
+    #[default]
+    Calm,
+    /// While a wave is active.
+    Combat,
+}
+
+/// A looping music track that plays along with the other stems,
+/// audible only during its [`MusicIntensity`].
+#[derive(Component, Debug)]
+pub struct MusicStem {
+    pub intensity: MusicIntensity,
+    /// Linear volume when fully faded in.
+    pub max_volume: f32,
+    /// Current linear volume.
+    pub volume: f32,
+}
+
+/// Music stems that are played in-game.
+///
+/// Add more entries to layer more stems.
+#[derive(Resource, Deref, DerefMut)]
+pub struct MusicStems(Vec<MusicStemConfig>);
+
+pub struct MusicStemConfig {
+    pub sample: Handle<Sample>,
+    pub intensity: MusicIntensity,
+    /// Linear volume when fully faded in.
+    pub volume: f32,
+    /// Cutoff frequency in hertz to muffle the stem with.
+    pub low_pass: Option<f32>,
+}
+
+impl FromWorld for MusicStems {
+    fn from_world(world: &mut World) -> Self {
+        let game_music =
+            world.resource::<GameAudio>().game_music.clone();
+
+        // Both stems share one track so they stay in sync,
+        // the calm stem is a muffled take on it.
+        Self(vec![
+            MusicStemConfig {
+                sample: game_music.clone(),
+                intensity: MusicIntensity::Calm,
+                volume: 0.3,
+                low_pass: Some(800.0),
+            },
+            MusicStemConfig {
+                sample: game_music,
+                intensity: MusicIntensity::Combat,
+                volume: 0.45,
+                low_pass: None,
+            },
+        ])
+    }
+}
+
 /// Resource containing all game audio handles
 #[derive(Resource)]
 pub struct GameAudio {
@@ -125,6 +584,10 @@ pub struct GameAudio {
     pub menu_music: Handle<Sample>,
     pub game_music: Handle<Sample>,
     pub game_over_music: Handle<Sample>,
+    // Stings
+    pub boss_sting: Handle<Sample>,
+    pub victory_sting: Handle<Sample>,
+    pub defeat_sting: Handle<Sample>,
 }
 
 impl FromWorld for GameAudio {
@@ -140,6 +603,175 @@ impl FromWorld for GameAudio {
                 .load("audios/music/game_bgm.ogg"),
             game_over_music: asset_server
                 .load("audios/music/game_over.ogg"),
+            boss_sting: asset_server
+                .load("audios/music/boss_sting.wav"),
+            victory_sting: asset_server
+                .load("audios/music/victory_sting.wav"),
+            defeat_sting: asset_server
+                .load("audios/music/defeat_sting.wav"),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_death_sfx_are_throttled() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Sample>()
+            .init_resource::<Settings>()
+            .init_resource::<SfxVoices>()
+            .insert_resource(GameRng::new(0))
+            .add_observer(play_enemy_death_sfx)
+            .add_observer(release_sfx_voice);
+
+        let kill_wave = |app: &mut App| {
+            for _ in 0..SfxVoices::MAX_ACTIVE * 2 {
+                let enemy = app
+                    .world_mut()
+                    .spawn(EnemySfx {
+                        death: Some("death.ogg".to_string()),
+                        ..default()
+                    })
+                    .id();
+                app.world_mut().trigger_targets(EnemyDied, enemy);
+                app.world_mut().despawn(enemy);
+            }
+            app.update();
+        };
+        let voice_count = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), With<SfxVoice>>()
+                .iter(app.world())
+                .count()
+        };
+
+        kill_wave(&mut app);
+        assert_eq!(voice_count(&mut app), SfxVoices::MAX_ACTIVE);
+
+        // Finished playing.
+        let voices = app
+            .world_mut()
+            .query_filtered::<Entity, With<SfxVoice>>()
+            .iter(app.world())
+            .collect::<Vec<_>>();
+        for voice in voices {
+            app.world_mut().despawn(voice);
+        }
+        assert_eq!(app.world().resource::<SfxVoices>().active(), 0);
+
+        kill_wave(&mut app);
+        assert_eq!(voice_count(&mut app), SfxVoices::MAX_ACTIVE);
+    }
+
+    #[test]
+    fn test_failed_sfx_free_their_voice() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Sample>()
+            .init_resource::<Settings>()
+            .init_resource::<SfxVoices>()
+            .insert_resource(GameRng::new(0))
+            .add_systems(Update, drop_failed_sfx_voices)
+            .add_observer(release_sfx_voice);
+
+        // No loader is registered for the extension.
+        app.world_mut()
+            .run_system_once(|mut sfx_player: SfxPlayer| {
+                sfx_player.play("missing.ogg");
+            })
+            .unwrap();
+        assert_eq!(app.world().resource::<SfxVoices>().active(), 1);
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<SfxVoices>().active(), 0);
+    }
+
+    #[test]
+    fn test_sfx_volume_decreases_with_distance() {
+        let volumes = [0.0, 5.0, 15.0, 29.0].map(|distance| {
+            let values = SpatialBasicNode {
+                offset: Vec3::X * distance,
+                ..sfx_spatial_node()
+            }
+            .compute_values(0.0);
+            values.gain_l + values.gain_r
+        });
+
+        assert!(
+            volumes.windows(2).all(|pair| pair[0] > pair[1]),
+            "Expected decreasing volumes, got {volumes:?}."
+        );
+    }
+
+    #[test]
+    fn test_positional_sfx_leave_attenuation_to_the_pool() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Sample>()
+            .init_resource::<Settings>()
+            .init_resource::<SfxVoices>()
+            .insert_resource(GameRng::new(0));
+
+        app.world_mut()
+            .spawn((SpatialListener3D, GlobalTransform::default()));
+
+        app.world_mut()
+            .run_system_once(|mut sfx_player: SfxPlayer| {
+                sfx_player.play_at("far.ogg", Vec3::X * 20.0);
+            })
+            .unwrap();
+
+        let sfx_volume =
+            app.world().resource::<Settings>().sfx_volume;
+        let (player, transform) = app
+            .world_mut()
+            .query_filtered::<(&SamplePlayer, &Transform), With<SfxVoice>>()
+            .single(app.world())
+            .unwrap();
+
+        // The spatial node attenuates, not the sample volume.
+        assert_eq!(player.volume, Volume::Linear(sfx_volume));
+        assert_eq!(transform.translation, Vec3::X * 20.0);
+    }
+
+    #[test]
+    fn test_inaudible_sfx_take_no_voice() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Sample>()
+            .init_resource::<Settings>()
+            .init_resource::<SfxVoices>()
+            .insert_resource(GameRng::new(0));
+
+        app.world_mut()
+            .spawn((SpatialListener3D, GlobalTransform::default()));
+
+        let played = app
+            .world_mut()
+            .run_system_once(|mut sfx_player: SfxPlayer| {
+                [
+                    sfx_player.play_at("near.ogg", Vec3::X * 2.0),
+                    sfx_player.play_at(
+                        "far.ogg",
+                        Vec3::X * SfxPlayer::MAX_DISTANCE * 2.0,
+                    ),
+                ]
+            })
+            .unwrap();
+
+        assert_eq!(played, [true, false]);
+        assert_eq!(app.world().resource::<SfxVoices>().active(), 1);
+    }
+}
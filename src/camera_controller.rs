@@ -14,9 +14,14 @@ use crate::tower::Projectile;
 
 pub mod split_screen;
 
+/// Shared ui rendered by the [`split_screen::CameraFull`].
 pub const UI_RENDER_LAYER: RenderLayers = RenderLayers::layer(1);
 pub const A_RENDER_LAYER: RenderLayers = RenderLayers::layer(2);
 pub const B_RENDER_LAYER: RenderLayers = RenderLayers::layer(3);
+/// Ui that belongs to [`crate::player::PlayerA`]'s viewport half.
+pub const UI_A_RENDER_LAYER: RenderLayers = RenderLayers::layer(4);
+/// Ui that belongs to [`crate::player::PlayerB`]'s viewport half.
+pub const UI_B_RENDER_LAYER: RenderLayers = RenderLayers::layer(5);
 
 pub(super) struct CameraControllerPlugin;
 
@@ -31,6 +36,7 @@ impl Plugin for CameraControllerPlugin {
                     third_person_camera,
                     obstacle_snap_front,
                     snap_camera,
+                    clamp_camera_bounds,
                 )
                     .chain()
                     .after(TransformSystem::TransformPropagate),
@@ -39,7 +45,8 @@ impl Plugin for CameraControllerPlugin {
 
         app.register_type::<CameraSnap>()
             .register_type::<ThirdPersonCamera>()
-            .register_type::<CameraTarget>();
+            .register_type::<CameraTarget>()
+            .register_type::<CameraBounds>();
     }
 }
 
@@ -70,17 +77,9 @@ fn obstacle_snap_front(
             ..ShapeCastConfig::DEFAULT
         };
 
-        let mut mask = LayerMask::ALL;
-        mask.remove([
-            GameLayer::Player,
-            GameLayer::Enemy,
-            GameLayer::InventoryItem,
-            GameLayer::Tower,
-            GameLayer::Projectile,
-        ]);
-
-        // Exclude the character's own entity from the raycast
-        let filter = SpatialQueryFilter::default().with_mask(mask);
+        // Only collide with the environment.
+        let filter = SpatialQueryFilter::default()
+            .with_mask(GameLayer::environment_mask());
 
         let direction = Dir3::new(diff)?;
 
@@ -215,6 +214,28 @@ fn snap_camera(
     Ok(())
 }
 
+/// Keep the cameras within the level's [`CameraBounds`],
+/// levels without one are not clamped.
+fn clamp_camera_bounds(
+    mut q_cameras: QueryCameras<&mut Transform>,
+    q_bounds: Query<&CameraBounds>,
+) {
+    let Ok(bounds) = q_bounds.single() else {
+        return;
+    };
+
+    for camera_type in [CameraType::A, CameraType::B] {
+        let Ok(mut transform) = q_cameras.get_mut(camera_type) else {
+            continue;
+        };
+
+        let clamped = bounds.clamp(transform.translation);
+        if transform.translation != clamped {
+            transform.translation = clamped;
+        }
+    }
+}
+
 /// Copy parent transform and clear the replace the parent
 /// with the [`CurrentScene`]'s entity!
 fn setup_third_person_camera(
@@ -246,11 +267,21 @@ fn setup_third_person_camera(
 // TODO: Move to another script.
 fn setup_directional_light(
     trigger: Trigger<OnAdd, DirectionalLight>,
+    mut commands: Commands,
     mut q_lights: Query<&mut DirectionalLight>,
 ) -> Result {
-    let mut light = q_lights.get_mut(trigger.target())?;
+    let entity = trigger.target();
+    let mut light = q_lights.get_mut(entity)?;
     light.shadows_enabled = true;
 
+    // Culled enemies are only on the layer of the
+    // cameras that still render them.
+    commands.entity(entity).insert(
+        RenderLayers::default()
+            .union(&A_RENDER_LAYER)
+            .union(&B_RENDER_LAYER),
+    );
+
     Ok(())
 }
 
@@ -264,6 +295,21 @@ pub struct CameraTarget;
 #[reflect(Component)]
 pub struct CameraSnap;
 
+/// Axis-aligned box that the cameras can't leave,
+/// to be placed in the level scene.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct CameraBounds {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl CameraBounds {
+    pub fn clamp(&self, position: Vec3) -> Vec3 {
+        position.clamp(self.min, self.max)
+    }
+}
+
 #[derive(Component, Reflect)]
 #[require(OrbitAngle)]
 #[reflect(Component, Default)]
@@ -312,3 +358,60 @@ impl Default for ViewCastShape {
         Self(Collider::sphere(0.1))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use split_screen::{CameraA, CameraB};
+
+    #[test]
+    fn test_camera_outside_bounds_is_clamped() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, clamp_camera_bounds);
+
+        let camera_a = app
+            .world_mut()
+            .spawn((
+                Camera::default(),
+                CameraA,
+                Transform::from_xyz(20.0, 5.0, -30.0),
+            ))
+            .id();
+        let camera_b = app
+            .world_mut()
+            .spawn((
+                Camera::default(),
+                CameraB,
+                Transform::from_xyz(1.0, 2.0, 3.0),
+            ))
+            .id();
+
+        let translation = |app: &App, camera: Entity| {
+            app.world().get::<Transform>(camera).unwrap().translation
+        };
+
+        // No bounds, no clamping.
+        app.update();
+        assert_eq!(
+            translation(&app, camera_a),
+            Vec3::new(20.0, 5.0, -30.0)
+        );
+
+        app.world_mut().spawn(CameraBounds {
+            min: Vec3::new(-10.0, 0.0, -10.0),
+            max: Vec3::new(10.0, 10.0, 10.0),
+        });
+        app.update();
+
+        assert_eq!(
+            translation(&app, camera_a),
+            Vec3::new(10.0, 5.0, -10.0)
+        );
+        // Each camera is clamped independently.
+        assert_eq!(
+            translation(&app, camera_b),
+            Vec3::new(1.0, 2.0, 3.0)
+        );
+    }
+}
@@ -14,9 +14,13 @@ use bevy::render::camera::{CameraOutputMode, Viewport};
 use bevy::render::view::{Layer, RenderLayers};
 use bevy::window::WindowResized;
 
+use crate::settings::{GraphicsQuality, Settings};
 use crate::util::PropagateComponentAppExt;
 
-use super::{A_RENDER_LAYER, B_RENDER_LAYER, UI_RENDER_LAYER};
+use super::{
+    A_RENDER_LAYER, B_RENDER_LAYER, UI_A_RENDER_LAYER,
+    UI_B_RENDER_LAYER, UI_RENDER_LAYER,
+};
 
 pub(super) struct SplitScreenPlugin;
 
@@ -24,7 +28,14 @@ impl Plugin for SplitScreenPlugin {
     fn build(&self, app: &mut App) {
         app.propagate_component::<CameraType, Children>()
             .add_systems(PreStartup, setup_camera_and_environment)
-            .add_systems(Update, set_camera_split_viewports);
+            .add_systems(
+                Update,
+                (
+                    set_camera_split_viewports,
+                    apply_graphics_quality
+                        .run_if(resource_changed::<Settings>),
+                ),
+            );
 
         app.register_type::<CameraType>();
     }
@@ -34,29 +45,95 @@ fn set_camera_split_viewports(
     windows: Query<&Window>,
     mut resize_events: EventReader<WindowResized>,
     mut q_cameras: QueryCameras<&mut Camera>,
+    // Window that was minimized while resizing.
+    mut minimized_window: Local<Option<Entity>>,
 ) -> Result {
     // We need to dynamically resize the camera's viewports whenever the
     // window size changes so then each camera always takes up half the screen.
     // A resize_event is sent when the window is first created,
     // allowing us to reuse this system for initial setup.
+    let resized_windows = resize_events
+        .read()
+        .map(|resize_event| resize_event.window)
+        .chain(minimized_window.take())
+        .collect::<Vec<_>>();
+
+    for window in resized_windows {
+        let window_size = windows.get(window)?.physical_size();
+
+        // Minimized windows report a zero size, keep the previous
+        // viewports until the window is restored.
+        if window_size.cmpeq(UVec2::ZERO).any() {
+            *minimized_window = Some(window);
+            continue;
+        }
+
+        let [viewport_a, viewport_b] = split_viewports(window_size);
+
+        q_cameras.get_mut(CameraType::A)?.viewport = Some(viewport_a);
+        q_cameras.get_mut(CameraType::B)?.viewport = Some(viewport_b);
+    }
 
-    for resize_event in resize_events.read() {
-        let window_size =
-            windows.get(resize_event.window).unwrap().physical_size();
-        let additional_pixel = window_size.x % 2;
-        let split_size = UVec2::new(window_size.x / 2, window_size.y);
+    Ok(())
+}
+
+/// Smallest physical size of a split viewport.
+pub const MIN_VIEWPORT_SIZE: UVec2 = UVec2::ONE;
+
+/// Physical viewports of [`CameraType::A`] and [`CameraType::B`]
+/// for the given physical window size.
+///
+/// Each viewport is at least [`MIN_VIEWPORT_SIZE`].
+pub fn split_viewports(window_size: UVec2) -> [Viewport; 2] {
+    let additional_pixel = window_size.x % 2;
+    let split_size = UVec2::new(window_size.x / 2, window_size.y)
+        .max(MIN_VIEWPORT_SIZE);
 
-        q_cameras.get_mut(CameraType::A)?.viewport = Some(Viewport {
+    [
+        Viewport {
             physical_position: UVec2::ZERO,
             physical_size: split_size,
             ..default()
-        });
-        q_cameras.get_mut(CameraType::B)?.viewport = Some(Viewport {
+        },
+        Viewport {
             physical_position: UVec2::new(split_size.x, 0),
             physical_size: split_size
                 + UVec2::new(additional_pixel, 0),
             ..default()
-        });
+        },
+    ]
+}
+
+/// Drop the environment of the game cameras on
+/// [`GraphicsQuality::Low`] and bring it back otherwise.
+///
+/// The game cameras share a single render target and never clear
+/// it, leaving the flat background of the clear color camera.
+fn apply_graphics_quality(
+    mut commands: Commands,
+    q_cameras: QueryCameras<(Has<Skybox>, Entity)>,
+    environment_map: Res<EnvironmentMap>,
+    settings: Res<Settings>,
+) -> Result {
+    for camera_type in [CameraType::A, CameraType::B] {
+        let (has_skybox, entity) = q_cameras.get(camera_type)?;
+
+        match settings.graphics_quality {
+            GraphicsQuality::Low => {
+                if has_skybox {
+                    commands
+                        .entity(entity)
+                        .remove::<(Skybox, EnvironmentMapLight)>();
+                }
+            }
+            GraphicsQuality::High => {
+                if has_skybox == false {
+                    commands
+                        .entity(entity)
+                        .insert(environment_map.bundle());
+                }
+            }
+        }
     }
 
     Ok(())
@@ -66,6 +143,13 @@ fn setup_camera_and_environment(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
 ) {
+    let environment_map = EnvironmentMap {
+        diffuse_map: asset_server
+            .load("pisa_diffuse_rgb9e5_zstd.ktx2"),
+        specular_map: asset_server
+            .load("pisa_specular_rgb9e5_zstd.ktx2"),
+    };
+
     // Spawn a camera with clear color.
     commands.spawn((
         Camera3d::default(),
@@ -81,15 +165,21 @@ fn setup_camera_and_environment(
     ));
 
     commands.spawn((
-        game_camera_bundle(&asset_server, 0),
+        game_camera_bundle(&environment_map, 0),
         CameraType::A,
-        A_RENDER_LAYER.with(Layer::default()),
+        // Also renders the player specific ui.
+        A_RENDER_LAYER
+            .union(&UI_A_RENDER_LAYER)
+            .with(Layer::default()),
     ));
 
     commands.spawn((
-        game_camera_bundle(&asset_server, 1),
+        game_camera_bundle(&environment_map, 1),
         CameraType::B,
-        B_RENDER_LAYER.with(Layer::default()),
+        // Also renders the player specific ui.
+        B_RENDER_LAYER
+            .union(&UI_B_RENDER_LAYER)
+            .with(Layer::default()),
     ));
 
     commands.spawn((
@@ -97,17 +187,14 @@ fn setup_camera_and_environment(
         CameraType::Full,
         UI_RENDER_LAYER,
     ));
+
+    commands.insert_resource(environment_map);
 }
 
 fn game_camera_bundle(
-    asset_server: &AssetServer,
+    environment_map: &EnvironmentMap,
     order: isize,
 ) -> impl Bundle {
-    let diffuse_map =
-        asset_server.load("pisa_diffuse_rgb9e5_zstd.ktx2");
-    let specular_map =
-        asset_server.load("pisa_specular_rgb9e5_zstd.ktx2");
-
     let projection = PerspectiveProjection {
         fov: core::f32::consts::PI / 2.0,
         ..default()
@@ -128,20 +215,35 @@ fn game_camera_bundle(
         Projection::Perspective(projection),
         Tonemapping::None,
         Msaa::Off,
-        Skybox {
-            image: diffuse_map.clone(),
-            brightness: 1000.0,
-            ..default()
-        },
-        EnvironmentMapLight {
-            diffuse_map: diffuse_map.clone(),
-            specular_map: specular_map.clone(),
-            intensity: 1000.0,
-            ..default()
-        },
+        environment_map.bundle(),
     )
 }
 
+/// Skybox and environment lighting of the game cameras.
+#[derive(Resource, Debug, Clone)]
+pub struct EnvironmentMap {
+    pub diffuse_map: Handle<Image>,
+    pub specular_map: Handle<Image>,
+}
+
+impl EnvironmentMap {
+    pub fn bundle(&self) -> impl Bundle {
+        (
+            Skybox {
+                image: self.diffuse_map.clone(),
+                brightness: 1000.0,
+                ..default()
+            },
+            EnvironmentMapLight {
+                diffuse_map: self.diffuse_map.clone(),
+                specular_map: self.specular_map.clone(),
+                intensity: 1000.0,
+                ..default()
+            },
+        )
+    }
+}
+
 fn ui_camera_bundle(order: isize) -> impl Bundle {
     (
         Camera3d {
@@ -287,3 +389,133 @@ pub struct CameraA;
 /// Usually used to render the POV of [`crate::player::PlayerB`]
 #[derive(Component, Debug)]
 pub struct CameraB;
+
+#[cfg(test)]
+mod test {
+    use bevy::window::WindowResolution;
+
+    use super::*;
+
+    #[test]
+    fn test_minimized_window_keeps_viewports() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<WindowResized>()
+            .add_systems(Update, set_camera_split_viewports);
+
+        let window = app
+            .world_mut()
+            .spawn(Window {
+                resolution: WindowResolution::new(1280.0, 720.0),
+                ..default()
+            })
+            .id();
+        for camera_type in [CameraType::A, CameraType::B] {
+            app.world_mut().spawn((Camera::default(), camera_type));
+        }
+
+        let resize = |app: &mut App, width: f32, height: f32| {
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .resolution
+                .set(width, height);
+            app.world_mut().send_event(WindowResized {
+                window,
+                width,
+                height,
+            });
+            app.update();
+        };
+
+        let viewport_sizes = |app: &mut App| {
+            app.world_mut()
+                .query::<&Camera>()
+                .iter(app.world())
+                .map(|camera| {
+                    camera.viewport.as_ref().unwrap().physical_size
+                })
+                .collect::<Vec<_>>()
+        };
+
+        resize(&mut app, 1280.0, 720.0);
+        assert_eq!(
+            viewport_sizes(&mut app),
+            [UVec2::new(640, 720); 2]
+        );
+
+        // Minimized.
+        resize(&mut app, 0.0, 0.0);
+        assert_eq!(
+            viewport_sizes(&mut app),
+            [UVec2::new(640, 720); 2]
+        );
+
+        // Restored without a resize event of its own.
+        app.world_mut()
+            .get_mut::<Window>(window)
+            .unwrap()
+            .resolution
+            .set(1000.0, 600.0);
+        app.update();
+        assert_eq!(
+            viewport_sizes(&mut app),
+            [UVec2::new(500, 600); 2]
+        );
+    }
+
+    #[test]
+    fn test_low_quality_removes_the_skybox() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(Settings {
+                graphics_quality: GraphicsQuality::Low,
+                ..default()
+            })
+            .add_systems(
+                Update,
+                apply_graphics_quality
+                    .run_if(resource_changed::<Settings>),
+            );
+
+        let environment_map = EnvironmentMap {
+            diffuse_map: default(),
+            specular_map: default(),
+        };
+        for camera_type in [CameraType::A, CameraType::B] {
+            app.world_mut().spawn((
+                Camera::default(),
+                camera_type,
+                environment_map.bundle(),
+            ));
+        }
+        app.insert_resource(environment_map);
+
+        let skybox_count = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), With<Skybox>>()
+                .iter(app.world())
+                .count()
+        };
+        let clear_colors_untouched = |app: &mut App| {
+            app.world_mut().query::<&Camera>().iter(app.world()).all(
+                |camera| {
+                    matches!(
+                        camera.clear_color,
+                        ClearColorConfig::Default
+                    )
+                },
+            )
+        };
+
+        app.update();
+        assert_eq!(skybox_count(&mut app), 0);
+        assert!(clear_colors_untouched(&mut app));
+
+        app.world_mut().resource_mut::<Settings>().graphics_quality =
+            GraphicsQuality::High;
+        app.update();
+        assert_eq!(skybox_count(&mut app), 2);
+        assert!(clear_colors_untouched(&mut app));
+    }
+}
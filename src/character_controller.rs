@@ -7,9 +7,11 @@ use crate::action::{PlayerAction, RequireAction, TargetAction};
 use crate::camera_controller::split_screen::{
     CameraType, QueryCameras,
 };
+use crate::enemy::contact_damage::ContactCooldown;
 use crate::inventory::Inventory;
 use crate::physics::GameLayer;
 use crate::player::PlayerType;
+use crate::tower::tower_attack::MaxHealth;
 
 mod animation;
 
@@ -423,7 +425,9 @@ pub struct IsMoving(pub bool);
     TransformInterpolation,
     CollisionEventsEnabled,
     CollisionLayers::new(GameLayer::Player, LayerMask::ALL,),
-    SpatialListener3D
+    SpatialListener3D,
+    MaxHealth(CharacterController::MAX_HEALTH),
+    ContactCooldown
 )]
 #[reflect(Component, Default)]
 pub struct CharacterController {
@@ -440,6 +444,11 @@ pub struct CharacterController {
     pub gravity: Vec3,
 }
 
+impl CharacterController {
+    /// [`MaxHealth`] of every character.
+    pub const MAX_HEALTH: f32 = 10.0;
+}
+
 impl Default for CharacterController {
     fn default() -> Self {
         Self {
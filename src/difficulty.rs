@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Global difficulty of the game.
+#[derive(
+    Resource,
+    Reflect,
+    Default,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Deserialize,
+)]
+#[reflect(Resource)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Multiplier applied to the enemy's max health.
+    pub fn enemy_health_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+        }
+    }
+
+    /// [`BaseShield`][crate::player::player_mark::BaseShield]
+    /// charges restored at the start of every wave.
+    pub fn base_shield_charges(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 3,
+            Difficulty::Normal => 2,
+            Difficulty::Hard => 1,
+        }
+    }
+}
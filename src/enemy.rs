@@ -1,32 +1,61 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
+use routing::{ChosenRoute, Router};
 
+use crate::asset_pipeline::LevelScoped;
+use crate::difficulty::Difficulty;
 use crate::physics::GameLayer;
 use crate::player::player_attack::AttackCooldown;
-use crate::player::player_mark::PlayerMark;
+use crate::player::player_mark::{BaseShield, PlayerMark};
 use crate::tile::{PlacedBy, TileMap};
-use crate::tower::tower_attack::{Health, Tower};
+use crate::tower::tower_attack::{
+    Health, Invulnerable, MaxHealth, SpawnProtection, Tower,
+};
 use crate::ui::Screen;
 use crate::util::PropagateComponentAppExt;
 
+pub mod ability;
 mod animation;
+pub mod boss_phase;
+pub mod contact_damage;
+pub mod culling;
+pub mod hit_flash;
+pub mod routing;
 pub mod spawner;
+pub mod waves;
 
 pub(super) struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
+            ability::EnemyAbilityPlugin,
             animation::EnemyAnimationPlugin,
+            boss_phase::BossPhasePlugin,
+            contact_damage::ContactDamagePlugin,
+            culling::EnemyCullingPlugin,
+            hit_flash::HitFlashPlugin,
+            routing::RoutingPlugin,
             spawner::EnemySpawnerPlugin,
+            waves::WavesPlugin,
         ));
 
-        app.propagate_component::<IsEnemy, Children>()
+        app.init_resource::<GlobalEnemyModifiers>()
+            .propagate_component::<IsEnemy, Children>()
             .add_systems(
                 PostUpdate,
                 pathfind.after(TransformSystem::TransformPropagate),
             )
-            .add_systems(FixedUpdate, enemy_movement)
+            .add_systems(
+                FixedUpdate,
+                (
+                    tick_slows,
+                    enemy_movement,
+                    crowd_separation,
+                    update_path_progress,
+                )
+                    .chain(),
+            )
             .add_systems(
                 Update,
                 (
@@ -35,40 +64,65 @@ impl Plugin for EnemyPlugin {
                 )
                     .run_if(in_state(Screen::EnterLevel)),
             )
-            .add_observer(on_path_changed);
+            .add_observer(on_path_changed)
+            .add_observer(apply_health_modifiers);
 
-        app.register_type::<FinalTarget>().register_type::<Enemy>();
+        app.register_type::<FinalTarget>()
+            .register_type::<Enemy>()
+            .register_type::<GlobalEnemyModifiers>()
+            .register_type::<PathSmoothing>()
+            .register_type::<CrowdSeparation>()
+            .register_type::<DeathDrop>()
+            .register_type::<Bounty>()
+            .register_type::<Armor>()
+            .register_type::<Slow>()
+            .register_type::<EnemySfx>();
     }
 }
 
 fn pathfind(
     mut commands: Commands,
-    q_enemies: Query<(&Path, &GlobalTransform, Entity)>,
+    q_enemies: Query<(
+        &Path,
+        &GlobalTransform,
+        Option<&ChosenRoute>,
+        Entity,
+    )>,
     q_final_target: Query<&GlobalTransform, With<FinalTarget>>,
     tile_map: Res<TileMap>,
+    mut router: Router,
 ) {
     let Ok(final_target) = q_final_target.single() else {
         return;
     };
 
-    for (enemy_path, transform, entity) in q_enemies.iter() {
+    // Redirect the enemies whenever a branch opens or closes.
+    let branches_changed = router.branches_changed();
+
+    for (enemy_path, transform, route, entity) in q_enemies.iter() {
         // Pathfind if it's just newly added or the tile map has been updated.
-        if enemy_path.is_empty() || tile_map.is_changed() {
+        if enemy_path.is_empty()
+            || tile_map.is_changed()
+            || branches_changed
+        {
             let start_translation = transform.translation();
             let end_translation = final_target.translation();
 
             debug!(
                 "pathfind: {start_translation}, {end_translation}"
             );
-            if let Some(path_to_final) = tile_map.pathfind_to(
-                &start_translation,
-                &end_translation,
-                false,
+            if let Some((path_to_final, route)) = router.route(
+                &tile_map,
+                start_translation,
+                end_translation,
+                route,
             ) {
                 debug!("To target: {:?}", path_to_final);
-                commands
-                    .entity(entity)
-                    .insert((Path(path_to_final), TargetType::Final));
+                commands.entity(entity).insert((
+                    Path(path_to_final),
+                    route,
+                    TargetType::Final,
+                ));
             } else if let Some(path_to_tower) = tile_map.pathfind_to(
                 &start_translation,
                 &end_translation,
@@ -95,6 +149,33 @@ fn on_path_changed(
         .remove::<(TargetReached, TargetTower)>();
 }
 
+/// Scale the enemy's health on spawn
+/// based on the [`GlobalEnemyModifiers`] and [`Difficulty`].
+fn apply_health_modifiers(
+    trigger: Trigger<OnAdd, Enemy>,
+    mut commands: Commands,
+    q_max_healths: Query<&MaxHealth>,
+    modifiers: Res<GlobalEnemyModifiers>,
+    difficulty: Res<Difficulty>,
+) {
+    let entity = trigger.target();
+
+    let Ok(max_health) = q_max_healths.get(entity) else {
+        return;
+    };
+
+    let multiplier =
+        modifiers.health_mult * difficulty.enemy_health_multiplier();
+    if multiplier == 1.0 {
+        return;
+    }
+
+    let max_health = max_health.0 * multiplier;
+    commands
+        .entity(entity)
+        .insert((MaxHealth(max_health), Health(max_health)));
+}
+
 fn enemy_movement(
     mut commands: Commands,
     mut q_enemies: Query<
@@ -104,10 +185,13 @@ fn enemy_movement(
             &mut PathIndex,
             &mut LinearVelocity,
             &Position,
+            Option<&PathSmoothing>,
+            Option<&Slow>,
             Entity,
         ),
         Without<TargetReached>,
     >,
+    modifiers: Res<GlobalEnemyModifiers>,
 ) {
     for (
         enemy,
@@ -115,28 +199,137 @@ fn enemy_movement(
         mut path_index,
         mut linear_velocity,
         position,
+        smoothing,
+        slow,
         entity,
     ) in q_enemies.iter_mut()
     {
-        let Some(target_position) = path.get_target(&path_index)
-        else {
+        let current_position = position.xz();
+        let look_ahead =
+            smoothing.map(|s| s.look_ahead()).unwrap_or_default();
+
+        let Some(target_position) = path.steer_target(
+            &mut path_index,
+            current_position,
+            look_ahead,
+        ) else {
             linear_velocity.0 = Vec3::ZERO;
             commands.entity(entity).insert(TargetReached);
             continue;
         };
 
+        let target_velocity = (target_position - current_position)
+            .normalize_or_zero()
+            * enemy.movement_speed
+            * modifiers.speed_mult
+            * slow.map(|slow| slow.factor).unwrap_or(1.0);
+
+        linear_velocity.0 =
+            Vec3::new(target_velocity.x, 0.0, target_velocity.y);
+    }
+}
+
+/// Count down every [`Slow`], removing it once it runs out.
+pub(crate) fn tick_slows(
+    mut commands: Commands,
+    mut q_slows: Query<(&mut Slow, Entity)>,
+    time: Res<Time>,
+) {
+    for (mut slow, entity) in q_slows.iter_mut() {
+        slow.duration -= time.delta_secs();
+
+        if slow.duration <= 0.0 {
+            commands.entity(entity).try_remove::<Slow>();
+        }
+    }
+}
+
+fn update_path_progress(
+    mut q_enemies: Query<(
+        &Path,
+        &PathIndex,
+        &Position,
+        &mut PathProgress,
+    )>,
+) {
+    for (path, path_index, position, mut progress) in
+        q_enemies.iter_mut()
+    {
+        progress.set_if_neq(PathProgress(
+            path.progress(path_index, position.xz()),
+        ));
+    }
+}
+
+/// Spread crowded enemies laterally so that they don't jam
+/// each other while still progressing along their [`Path`].
+fn crowd_separation(
+    mut q_enemies: Query<
+        (&CrowdSeparation, &mut LinearVelocity, &Position, Entity),
+        (With<Enemy>, Without<TargetReached>),
+    >,
+    q_positions: Query<&Position, With<Enemy>>,
+    q_collider_ofs: Query<&ColliderOf>,
+    spatial_query: SpatialQuery,
+) {
+    for (separation, mut linear_velocity, position, entity) in
+        q_enemies.iter_mut()
+    {
+        let velocity = linear_velocity.xz();
+        let speed = velocity.length();
+        let Some(direction) = velocity.try_normalize() else {
+            continue;
+        };
+
         let current_position = position.xz();
+        let mut push = Vec2::ZERO;
+
+        // Only nearby neighbors are considered.
+        for neighbor in spatial_query.shape_intersections(
+            &Collider::sphere(separation.radius),
+            position.0,
+            Quat::IDENTITY,
+            &GameLayer::Enemy.query_filter(),
+        ) {
+            let neighbor = q_collider_ofs
+                .get(neighbor)
+                .map(|c| c.body)
+                .unwrap_or(neighbor);
 
-        if current_position.distance(target_position) < 0.1 {
-            path_index.increment();
+            if neighbor == entity {
+                continue;
+            }
+
+            let Ok(neighbor_position) = q_positions.get(neighbor)
+            else {
+                continue;
+            };
+
+            let offset = current_position - neighbor_position.xz();
+            let distance = offset.length();
+
+            let away = if distance > f32::EPSILON {
+                offset / distance
+            } else {
+                // Perfectly overlapped, split them deterministically.
+                let side = if entity < neighbor { 1.0 } else { -1.0 };
+                direction.perp() * side
+            };
+
+            push +=
+                away * (1.0 - distance / separation.radius).max(0.0);
         }
 
-        let target_velocity = (target_position - current_position)
-            .normalize()
-            * enemy.movement_speed;
+        // Only spread sideways to keep progressing along the path.
+        let lateral = push - direction * push.dot(direction);
+        let new_velocity =
+            velocity + lateral * separation.strength * speed;
 
-        linear_velocity.0 =
-            Vec3::new(target_velocity.x, 0.0, target_velocity.y);
+        linear_velocity.0 = Vec3::new(
+            new_velocity.x,
+            linear_velocity.y,
+            new_velocity.y,
+        );
     }
 }
 
@@ -151,15 +344,16 @@ fn target_reach_respond(
     q_placed_by: Query<&PlacedBy>,
     tile_map: Res<TileMap>,
     mut player_mark: ResMut<PlayerMark>,
+    mut base_shield: ResMut<BaseShield>,
 ) {
     for (target_type, path, entity) in q_enemies.iter() {
         if *target_type != TargetType::Tower {
-            // Decrease mark.
-            player_mark.0 = player_mark.saturating_sub(1);
+            // Decrease mark, once the shield is down.
+            base_shield.leak(&mut player_mark);
 
             info!(
-                "Enemy reached destination, mark decreased {}!",
-                player_mark.0
+                "Enemy reached destination, mark {} shield {}!",
+                player_mark.0, base_shield.charges
             );
             commands.entity(entity).despawn();
             continue;
@@ -203,18 +397,29 @@ fn target_reach_respond(
                     .and_then(|p| p.first().copied())
             });
 
-        if let Some(tower_parent) = tower_parent {
-            // Find the tower in the child hierarchy
-            // (as we are only getting the SceneRoot).
-            for child in q_children.iter_descendants(tower_parent) {
-                if q_is_tower.contains(child) {
-                    info!("Set target tower {tower_parent}");
-                    commands.entity(entity).try_insert(TargetTower {
-                        root: tower_parent,
-                        target: child,
-                    });
-                    break;
-                }
+        // Find the tower in the child hierarchy
+        // (as we are only getting the SceneRoot).
+        let target_tower = tower_parent.and_then(|tower_parent| {
+            q_children
+                .iter_descendants(tower_parent)
+                .find(|child| q_is_tower.contains(*child))
+                .map(|target| TargetTower {
+                    root: tower_parent,
+                    target,
+                })
+        });
+
+        match target_tower {
+            Some(target_tower) => {
+                info!("Set target tower {}", target_tower.root);
+                commands.entity(entity).try_insert(target_tower);
+            }
+            None => {
+                // Don't idle at the end of the path forever,
+                // pathfind again towards the final target or
+                // another tower.
+                debug!("No tower beside enemy {entity}, repathing.");
+                commands.entity(entity).try_insert(Path::default());
             }
         }
     }
@@ -227,6 +432,10 @@ fn attack_tower(
         With<TargetReached>,
     >,
     mut q_healths: Query<&mut Health>,
+    q_invulnerables: Query<
+        (),
+        Or<(With<Invulnerable>, With<SpawnProtection>)>,
+    >,
 ) {
     for (target_tower, enemy, mut cooldown, entity) in
         q_enemies.iter_mut()
@@ -237,16 +446,22 @@ fn attack_tower(
                 continue;
             }
 
+            // Keep attacking once the protection wears off.
+            if q_invulnerables.contains(target_tower.target) {
+                continue;
+            }
+
             health.0 -= enemy.damage;
             cooldown.0 = enemy.attack_cooldown;
 
+            // Several enemies can finish off the same tower.
             if health.0 <= 0.0 {
-                commands.entity(target_tower.root).despawn();
+                commands.entity(target_tower.root).try_despawn();
             }
             info!("attacking {}", health.0);
         } else {
             // No more target, find another one.
-            commands.entity(entity).remove::<TargetTower>();
+            commands.entity(entity).try_remove::<TargetTower>();
         }
     }
 }
@@ -295,8 +510,14 @@ pub struct FinalTarget;
     IsEnemy,
     Path,
     CollisionEventsEnabled,
-    CollisionLayers::new(GameLayer::Enemy, LayerMask::ALL),
-    AttackCooldown
+    CollisionLayers = GameLayer::enemy_layers(),
+    AttackCooldown,
+    PathSmoothing,
+    CrowdSeparation,
+    DeathDrop,
+    contact_damage::ContactDamage,
+    LevelScoped,
+    animation::EnemyAnimationState
 )]
 #[reflect(Component)]
 pub struct Enemy {
@@ -305,6 +526,126 @@ pub struct Enemy {
     pub attack_cooldown: f32,
 }
 
+/// Personal space of an enemy, configurable per prefab.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct CrowdSeparation {
+    /// Radius in which neighbors are pushed away.
+    pub radius: f32,
+    /// Strength of the lateral push relative to the movement speed.
+    pub strength: f32,
+}
+
+impl Default for CrowdSeparation {
+    fn default() -> Self {
+        Self {
+            radius: 1.0,
+            strength: 0.5,
+        }
+    }
+}
+
+/// Prefab dropped when the entity dies, nothing is dropped without it.
+/// Enemies drop a single corn by default.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct DeathDrop {
+    /// File name of the dropped prefab.
+    pub prefab: String,
+    pub count: u32,
+}
+
+impl Default for DeathDrop {
+    fn default() -> Self {
+        Self {
+            prefab: "corn".to_string(),
+            count: 1,
+        }
+    }
+}
+
+/// Amount of [`Currency`][crate::player::currency::Currency]
+/// awarded when the enemy dies.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Bounty(pub u32);
+
+/// Flat damage reduction against every projectile hit,
+/// damage never drops below zero.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct Armor(pub f32);
+
+impl Armor {
+    /// Damage taken from a hit of `damage`.
+    pub fn reduce(&self, damage: f32) -> f32 {
+        (damage - self.0).max(0.0)
+    }
+}
+
+/// Sound effects of an enemy prefab, relative to `audios/enemy/`.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct EnemySfx {
+    pub spawn: Option<String>,
+    pub death: Option<String>,
+    /// Played when a projectile hits the enemy.
+    #[reflect(default)]
+    pub hit: Option<String>,
+}
+
+/// Triggered on the [`Enemy`] entity right before it is
+/// despawned from running out of health.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyDied;
+
+/// Triggered on the [`Enemy`] entity whenever a projectile
+/// damages it, and once per attack cooldown by beams.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyHit;
+
+/// Global multipliers applied on top of every enemy's stats,
+/// for tests and special game modes.
+///
+/// Composes multiplicatively with the [`Difficulty`].
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource)]
+pub struct GlobalEnemyModifiers {
+    pub speed_mult: f32,
+    pub health_mult: f32,
+    /// Multiplier of the drops on death.
+    pub reward_mult: f32,
+}
+
+impl Default for GlobalEnemyModifiers {
+    fn default() -> Self {
+        Self {
+            speed_mult: 1.0,
+            health_mult: 1.0,
+            reward_mult: 1.0,
+        }
+    }
+}
+
+/// Temporary multiplier of the enemy's movement speed.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct Slow {
+    /// Multiplier of the movement speed, lower is slower.
+    pub factor: f32,
+    /// Seconds left before the slow wears off.
+    pub duration: f32,
+}
+
+impl Slow {
+    /// Apply another slow on top, keeping the strongest factor
+    /// and the longest duration instead of compounding them.
+    pub fn stack(&mut self, other: Slow) {
+        self.factor = self.factor.min(other.factor);
+        self.duration = self.duration.max(other.duration);
+    }
+}
+
 /// Tag component for enemy units.
 /// Will be propagated down the hierarchy.
 #[derive(Component, Default, Clone, Copy)]
@@ -312,19 +653,171 @@ pub struct IsEnemy;
 
 /// The current path of the enemy.
 #[derive(Component, Deref, Default)]
-#[require(PathIndex)]
+#[require(PathIndex, PathProgress)]
 #[component(immutable)]
 pub struct Path(Vec<IVec2>);
 
 impl Path {
+    pub fn new(path: Vec<IVec2>) -> Self {
+        Self(path)
+    }
+
+    /// Distance to a waypoint to be considered as reached.
+    pub const REACH_DISTANCE: f32 = 0.1;
+
     pub fn get_target(&self, index: &PathIndex) -> Option<Vec2> {
         self.0.get(index.0).map(TileMap::tile_coord_to_world_space)
     }
+
+    /// Fraction of the path travelled within `0.0..=1.0`,
+    /// measured along the segments between the waypoints.
+    pub fn progress(&self, index: &PathIndex, position: Vec2) -> f32 {
+        if self.0.is_empty() || index.0 == 0 {
+            return 0.0;
+        }
+        if index.0 >= self.0.len() {
+            return 1.0;
+        }
+
+        let waypoint =
+            |i: usize| TileMap::tile_coord_to_world_space(&self.0[i]);
+        let segment_length =
+            |i: usize| waypoint(i - 1).distance(waypoint(i));
+
+        let total =
+            (1..self.0.len()).map(segment_length).sum::<f32>();
+        if total <= 0.0 {
+            return 1.0;
+        }
+
+        // Projection onto the current segment.
+        let start = waypoint(index.0 - 1);
+        let segment = waypoint(index.0) - start;
+        let length_squared = segment.length_squared();
+        let segment_progress = if length_squared > 0.0 {
+            ((position - start).dot(segment) / length_squared)
+                .clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        let travelled = (1..index.0).map(segment_length).sum::<f32>()
+            + segment_progress * length_squared.sqrt();
+
+        (travelled / total).clamp(0.0, 1.0)
+    }
+
+    /// Get the position to steer towards, advancing the
+    /// [`PathIndex`] once the current waypoint is reached.
+    ///
+    /// With a non-zero `look_ahead`, the returned position is
+    /// `look_ahead` units further down the path from the closest point
+    /// on the current segment, allowing the enemy to curve through
+    /// waypoints instead of snapping its heading.
+    ///
+    /// Returns [`None`] when the end of the path has been reached.
+    pub fn steer_target(
+        &self,
+        index: &mut PathIndex,
+        position: Vec2,
+        look_ahead: f32,
+    ) -> Option<Vec2> {
+        let target = self.get_target(index)?;
+
+        // Use the enemy's own position as the start
+        // of the very first segment.
+        let start = index
+            .checked_sub(1)
+            .and_then(|i| self.0.get(i))
+            .map(TileMap::tile_coord_to_world_space)
+            .unwrap_or(position);
+
+        let segment = target - start;
+        let length_squared = segment.length_squared();
+        let progress = if length_squared > 0.0 {
+            (position - start).dot(segment) / length_squared
+        } else {
+            1.0
+        };
+
+        // A smoothed enemy might never get close to a corner
+        // as it cuts through, passing the segment counts as well.
+        if position.distance(target) < Self::REACH_DISTANCE
+            || (look_ahead > 0.0 && progress >= 1.0)
+        {
+            index.increment();
+            // Passing the final waypoint ends the path right away.
+            return self.steer_target(index, position, look_ahead);
+        }
+
+        if look_ahead <= 0.0 {
+            return Some(target);
+        }
+
+        // Walk down the path from the projected position.
+        let mut cursor = start + segment * progress.clamp(0.0, 1.0);
+        let mut remaining = look_ahead;
+
+        for waypoint in self.0[index.0..]
+            .iter()
+            .map(TileMap::tile_coord_to_world_space)
+        {
+            let distance = cursor.distance(waypoint);
+            if distance >= remaining && distance > 0.0 {
+                return Some(
+                    cursor
+                        + (waypoint - cursor) / distance * remaining,
+                );
+            }
+
+            remaining -= distance;
+            cursor = waypoint;
+        }
+
+        Some(cursor)
+    }
 }
 
 #[derive(Component, Deref, Default)]
 pub struct PathIndex(usize);
 
+/// Normalized progress along the [`Path`], `1.0` being the end.
+///
+/// Cheaper to compare than recomputing from the waypoints,
+/// see [`Path::progress`].
+#[derive(
+    Component, Deref, Default, Debug, Clone, Copy, PartialEq,
+)]
+pub struct PathProgress(pub f32);
+
+/// Smooth out the enemy's movement around the corners of its [`Path`].
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct PathSmoothing {
+    /// Smoothing strength within `0.0..=1.0`, where `0.0` follows
+    /// the waypoints exactly.
+    pub strength: f32,
+}
+
+impl PathSmoothing {
+    /// Look ahead distance at full strength.
+    ///
+    /// Kept within half a tile (tiles are 2 units wide) so that
+    /// corners are not cut too aggressively, which keeps the path
+    /// length roughly intact.
+    pub const MAX_LOOK_AHEAD: f32 = 1.0;
+
+    pub fn look_ahead(&self) -> f32 {
+        self.strength.clamp(0.0, 1.0) * Self::MAX_LOOK_AHEAD
+    }
+}
+
+impl Default for PathSmoothing {
+    fn default() -> Self {
+        Self { strength: 1.0 }
+    }
+}
+
 impl PathIndex {
     pub fn increment(&mut self) {
         self.0 += 1;
@@ -346,3 +839,248 @@ pub struct TargetTower {
     pub root: Entity,
     pub target: Entity,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app};
+
+    /// Simulate an enemy walking down the path and return
+    /// the largest heading change between 2 consecutive steps.
+    fn max_heading_change(look_ahead: f32) -> f32 {
+        const SPEED: f32 = 2.0;
+        const DT: f32 = 1.0 / 64.0;
+
+        let path = Path(vec![
+            IVec2::new(10, 10),
+            IVec2::new(10, 12),
+            IVec2::new(12, 12),
+            IVec2::new(13, 13),
+            IVec2::new(13, 15),
+        ]);
+        let mut index = PathIndex::default();
+        let mut position =
+            TileMap::tile_coord_to_world_space(&IVec2::new(10, 9));
+
+        let mut prev_heading = None;
+        let mut max_change = 0.0f32;
+
+        for _ in 0..10_000 {
+            let Some(target) =
+                path.steer_target(&mut index, position, look_ahead)
+            else {
+                break;
+            };
+
+            let heading = (target - position).normalize_or_zero();
+            if let Some(prev_heading) = prev_heading {
+                max_change = max_change
+                    .max(Vec2::angle_to(prev_heading, heading).abs());
+            }
+
+            prev_heading = Some(heading);
+            position += heading * SPEED * DT;
+        }
+
+        assert!(
+            path.get_target(&index).is_none(),
+            "Should reach the end of the path."
+        );
+
+        max_change
+    }
+
+    /// Distance travelled by an enemy in a single movement tick.
+    fn movement_per_tick(speed_mult: f32) -> f32 {
+        movement_per_tick_with(speed_mult, None)
+    }
+
+    fn movement_per_tick_with(
+        speed_mult: f32,
+        slow: Option<Slow>,
+    ) -> f32 {
+        let mut app = App::new();
+        app.init_resource::<Difficulty>()
+            .insert_resource(GlobalEnemyModifiers {
+                speed_mult,
+                ..default()
+            })
+            .add_systems(Update, enemy_movement);
+
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Enemy {
+                    movement_speed: 2.0,
+                    damage: 1.0,
+                    attack_cooldown: 1.0,
+                },
+                Path(vec![IVec2::new(10, 10)]),
+                Position::default(),
+                LinearVelocity::default(),
+            ))
+            .id();
+        if let Some(slow) = slow {
+            app.world_mut().entity_mut(enemy).insert(slow);
+        }
+
+        app.update();
+
+        app.world()
+            .get::<LinearVelocity>(enemy)
+            .expect("Should have velocity.")
+            .length()
+    }
+
+    #[test]
+    fn test_speed_mult_doubles_movement() {
+        let base = movement_per_tick(1.0);
+
+        assert!(base > 0.0);
+        assert!((movement_per_tick(2.0) - base * 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_slow_scales_movement() {
+        let base = movement_per_tick(1.0);
+        let slow = Slow {
+            factor: 0.5,
+            duration: 1.0,
+        };
+
+        assert!(
+            (movement_per_tick_with(1.0, Some(slow)) - base * 0.5)
+                .abs()
+                < 1e-4
+        );
+    }
+
+    #[test]
+    fn test_overlapped_enemies_separate() {
+        let mut app = combat_app();
+        app.add_systems(
+            FixedUpdate,
+            (enemy_movement, crowd_separation).chain(),
+        );
+
+        // Both heading straight towards the same point.
+        let enemies = [(); 2].map(|_| {
+            let enemy = app.spawn_enemy(10.0, Vec3::ZERO);
+            app.world_mut().entity_mut(enemy).insert((
+                Enemy {
+                    movement_speed: 2.0,
+                    damage: 1.0,
+                    attack_cooldown: 1.0,
+                },
+                RigidBody::Kinematic,
+                Path(vec![IVec2::new(20, 40)]),
+            ));
+            enemy
+        });
+
+        app.step(32);
+
+        let [a, b] = enemies
+            .map(|e| app.world().get::<Position>(e).unwrap().0);
+
+        assert!(a.z > 1.0, "Should still progress along the path.");
+        assert!(a.distance(b) > 0.1, "Should not remain overlapped.");
+    }
+
+    #[test]
+    fn test_unsmoothed_heading_snaps() {
+        assert!(max_heading_change(0.0) > 40f32.to_radians());
+    }
+
+    #[test]
+    fn test_smoothed_heading_is_gradual() {
+        let look_ahead = PathSmoothing::default().look_ahead();
+
+        assert!(max_heading_change(look_ahead) < 15f32.to_radians());
+    }
+
+    #[test]
+    fn test_final_waypoint_ends_path_on_the_same_frame() {
+        let path = Path(vec![IVec2::new(10, 10), IVec2::new(10, 12)]);
+        let end =
+            TileMap::tile_coord_to_world_space(&IVec2::new(10, 12));
+
+        for look_ahead in [0.0, PathSmoothing::default().look_ahead()]
+        {
+            let mut index = PathIndex(1);
+
+            assert_eq!(
+                path.steer_target(&mut index, end, look_ahead),
+                None
+            );
+            assert_eq!(index.0, 2);
+        }
+    }
+
+    #[test]
+    fn test_path_progress_increases_monotonically() {
+        const SPEED: f32 = 2.0;
+        const DT: f32 = 1.0 / 64.0;
+
+        let path = Path(vec![
+            IVec2::new(10, 10),
+            IVec2::new(10, 12),
+            IVec2::new(12, 12),
+            IVec2::new(13, 13),
+        ]);
+
+        for look_ahead in [0.0, PathSmoothing::default().look_ahead()]
+        {
+            let mut index = PathIndex::default();
+            let mut position = TileMap::tile_coord_to_world_space(
+                &IVec2::new(10, 9),
+            );
+            let mut prev_progress = 0.0;
+
+            while let Some(target) =
+                path.steer_target(&mut index, position, look_ahead)
+            {
+                position += (target - position).normalize_or_zero()
+                    * SPEED
+                    * DT;
+
+                let progress = path.progress(&index, position);
+                assert!(
+                    progress >= prev_progress,
+                    "Progress went back from {prev_progress} to {progress}."
+                );
+                prev_progress = progress;
+            }
+
+            assert_eq!(path.progress(&index, position), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_leaks_consume_shield_before_base_health() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<TileMap>()
+            .insert_resource(PlayerMark(10))
+            .insert_resource(BaseShield::new(2))
+            .add_systems(Update, target_reach_respond);
+
+        let leak = |app: &mut App, count: usize| {
+            for _ in 0..count {
+                app.world_mut().spawn((
+                    TargetType::Final,
+                    Path::default(),
+                    TargetReached,
+                ));
+            }
+            app.update();
+        };
+
+        leak(&mut app, 2);
+        assert_eq!(app.world().resource::<PlayerMark>().0, 10);
+        assert_eq!(app.world().resource::<BaseShield>().charges, 0);
+
+        leak(&mut app, 1);
+        assert_eq!(app.world().resource::<PlayerMark>().0, 9);
+    }
+}
@@ -0,0 +1,258 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::physics::GameLayer;
+use crate::tower::tower_attack::{Health, MaxHealth};
+
+use super::{Enemy, Path};
+
+pub(crate) struct EnemyAbilityPlugin;
+
+impl Plugin for EnemyAbilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, heal_aura)
+            .add_observer(inherit_split_path);
+
+        app.register_type::<SplitOnDeath>()
+            .register_type::<HealAura>();
+    }
+}
+
+/// Heal every other enemy within the [`HealAura`], up to their
+/// [`MaxHealth`].
+fn heal_aura(
+    q_healers: Query<(&GlobalTransform, &HealAura, Entity)>,
+    mut q_healths: Query<(&mut Health, &MaxHealth), With<Enemy>>,
+    q_collider_ofs: Query<&ColliderOf>,
+    spatial_query: SpatialQuery,
+    time: Res<Time>,
+) {
+    for (global_transform, aura, healer) in q_healers.iter() {
+        let heal_amount = aura.per_second * time.delta_secs();
+
+        for entity in spatial_query.shape_intersections(
+            &Collider::sphere(aura.radius),
+            global_transform.translation(),
+            Quat::IDENTITY,
+            &GameLayer::Enemy.query_filter(),
+        ) {
+            let entity = q_collider_ofs
+                .get(entity)
+                .map(|c| c.body)
+                .unwrap_or(entity);
+
+            if entity == healer {
+                continue;
+            }
+
+            let Ok((mut health, max_health)) =
+                q_healths.get_mut(entity)
+            else {
+                continue;
+            };
+
+            // Don't revive enemies that are about to be despawned.
+            if health.0 > 0.0 && health.0 < max_health.0 {
+                health.0 = (health.0 + heal_amount).min(max_health.0);
+            }
+        }
+    }
+}
+
+/// Hand the [`SplitOf`] path to the [`Enemy`] once the
+/// split prefab gets instantiated.
+fn inherit_split_path(
+    trigger: Trigger<OnAdd, Enemy>,
+    mut commands: Commands,
+    q_split_ofs: Query<&SplitOf>,
+    q_parents: Query<&ChildOf>,
+) {
+    let entity = trigger.target();
+
+    // The enemy could be anywhere within the prefab's hierarchy.
+    let Some(split_of) = core::iter::once(entity)
+        .chain(q_parents.iter_ancestors(entity))
+        .find_map(|e| q_split_ofs.get(e).ok())
+    else {
+        return;
+    };
+
+    commands.entity(entity).insert((
+        Path::new(split_of.path.clone()),
+        SplitDepth(split_of.depth),
+    ));
+}
+
+/// Spawns `count` smaller enemies when this enemy dies,
+/// following the rest of this enemy's [`Path`].
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct SplitOnDeath {
+    /// File name of the prefab to spawn.
+    pub child_prefab: String,
+    pub count: u32,
+}
+
+impl SplitOnDeath {
+    /// Enemies that are split this many times will not split again.
+    pub const MAX_DEPTH: u32 = 2;
+    /// Radius in which the split enemies are scattered.
+    pub const SCATTER_RADIUS: f32 = 0.5;
+}
+
+/// Number of splits that led to this enemy.
+#[derive(Component, Deref, Default, Debug, Clone, Copy)]
+pub struct SplitDepth(pub u32);
+
+/// Attached to the root of a split enemy's prefab until its
+/// [`Enemy`] is instantiated.
+#[derive(Component, Debug, Clone)]
+pub struct SplitOf {
+    /// The remaining path of the enemy that split.
+    pub path: Vec<IVec2>,
+    pub depth: u32,
+}
+
+/// Heals nearby enemies.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct HealAura {
+    pub radius: f32,
+    /// Health restored per second.
+    pub per_second: f32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::enemy::PathIndex;
+    use crate::test_util::{CombatTestAppExt, combat_app_with};
+
+    #[test]
+    fn test_splitter_spawns_children_on_same_path() {
+        let mut app = combat_app_with(EnemyAbilityPlugin);
+
+        let path = vec![
+            IVec2::new(0, 0),
+            IVec2::new(0, 1),
+            IVec2::new(1, 1),
+        ];
+        let splitter = app.spawn_enemy(10.0, Vec3::ZERO);
+        app.world_mut().entity_mut(splitter).insert((
+            SplitOnDeath {
+                child_prefab: "mouse_a".to_string(),
+                count: 3,
+            },
+            Path::new(path.clone()),
+            // Already walked past the first waypoint.
+            PathIndex(1),
+        ));
+        app.step(1);
+
+        app.world_mut().get_mut::<Health>(splitter).unwrap().0 = 0.0;
+        app.step(1);
+
+        let splits = app
+            .world_mut()
+            .query::<(&SplitOf, Entity)>()
+            .iter(app.world())
+            .map(|(split_of, entity)| (split_of.clone(), entity))
+            .collect::<Vec<_>>();
+
+        assert_eq!(splits.len(), 3);
+        for (split_of, _) in splits.iter() {
+            assert_eq!(split_of.path, path[1..]);
+            assert_eq!(split_of.depth, 1);
+        }
+
+        // The prefab gets instantiated with the enemy as a child.
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Enemy {
+                    movement_speed: 1.0,
+                    damage: 1.0,
+                    attack_cooldown: 1.0,
+                },
+                ChildOf(splits[0].1),
+            ))
+            .id();
+        app.world_mut().flush();
+
+        assert_eq!(
+            app.world().get::<Path>(enemy).unwrap().as_slice(),
+            &path[1..]
+        );
+        assert_eq!(
+            app.world().get::<SplitDepth>(enemy).unwrap().0,
+            1
+        );
+    }
+
+    #[test]
+    fn test_bad_split_prefab_still_despawns_every_enemy() {
+        let mut app = combat_app_with(EnemyAbilityPlugin);
+
+        let splitters = (0..4)
+            .map(|i| {
+                let splitter =
+                    app.spawn_enemy(10.0, Vec3::X * i as f32);
+                app.world_mut().entity_mut(splitter).insert((
+                    SplitOnDeath {
+                        child_prefab: "missing".to_string(),
+                        count: 2,
+                    },
+                    Path::new(vec![IVec2::ZERO]),
+                ));
+                splitter
+            })
+            .collect::<Vec<_>>();
+        app.step(1);
+
+        for &splitter in splitters.iter() {
+            app.world_mut().get_mut::<Health>(splitter).unwrap().0 =
+                0.0;
+        }
+        app.step(1);
+
+        for splitter in splitters {
+            assert!(app.world().get_entity(splitter).is_err());
+        }
+        let split_count = app
+            .world_mut()
+            .query::<&SplitOf>()
+            .iter(app.world())
+            .count();
+        assert_eq!(split_count, 0);
+    }
+
+    #[test]
+    fn test_heal_aura_restores_nearby_enemy() {
+        let mut app = combat_app_with(EnemyAbilityPlugin);
+
+        let healer = app.spawn_enemy(100.0, Vec3::ZERO);
+        app.world_mut().entity_mut(healer).insert(HealAura {
+            radius: 2.0,
+            per_second: 10.0,
+        });
+
+        let wounded = app.spawn_enemy(100.0, Vec3::X);
+        let far_away = app.spawn_enemy(100.0, Vec3::X * 10.0);
+        app.step(1);
+
+        for enemy in [wounded, far_away] {
+            app.world_mut().get_mut::<Health>(enemy).unwrap().0 =
+                50.0;
+        }
+
+        // One second.
+        app.step(64);
+
+        let health = app.world().get::<Health>(wounded).unwrap().0;
+        assert!((health - 60.0).abs() < 0.5, "Healed to {health}.");
+        assert_eq!(
+            app.world().get::<Health>(far_away).unwrap().0,
+            50.0
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use core::time::Duration;
 
-use bevy::animation::AnimationTarget;
+use avian3d::prelude::*;
+use bevy::animation::{ActiveAnimation, AnimationTarget};
 use bevy::prelude::*;
 
 use crate::asset_pipeline::animation_pipeline::{
@@ -16,63 +17,109 @@ impl Plugin for EnemyAnimationPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (setup_animation_graph, movement_animation)
-                .run_if(in_state(AssetState::Loaded)),
+            (
+                update_animation_state,
+                (setup_animation_graph, movement_animation)
+                    .run_if(in_state(AssetState::Loaded)),
+            )
+                .chain(),
         );
+
+        app.register_type::<EnemyAnimationState>();
     }
 }
 
-fn movement_animation(
-    q_enemies: Query<
-        (&NodeMap, &AnimationTarget, Has<TargetReached>),
+/// Select the [`EnemyAnimationState`] based on the actual
+/// movement speed, so slowed down or stunned enemies are accounted for.
+fn update_animation_state(
+    mut q_enemies: Query<
+        (
+            &LinearVelocity,
+            Has<TargetReached>,
+            &mut EnemyAnimationState,
+        ),
         With<Enemy>,
     >,
+) {
+    for (linear_velocity, reached_target, mut state) in
+        q_enemies.iter_mut()
+    {
+        let new_state = if reached_target {
+            EnemyAnimationState::Eat
+        } else if linear_velocity.length()
+            < EnemyAnimationState::WALK_SPEED_THRESHOLD
+        {
+            EnemyAnimationState::Idle
+        } else {
+            EnemyAnimationState::Walk
+        };
+
+        state.set_if_neq(new_state);
+    }
+}
+
+fn movement_animation(
+    q_enemies: Query<(
+        &NodeMap,
+        &AnimationTarget,
+        &EnemyAnimationState,
+        &LinearVelocity,
+        &Enemy,
+    )>,
     mut q_animation_players: Query<(
         &mut AnimationPlayer,
         &mut AnimationTransitions,
     )>,
 ) -> Result {
-    for (node_map, animation_target, reached_target) in
+    for (node_map, animation_target, state, linear_velocity, enemy) in
         q_enemies.iter()
     {
+        // Prefabs might not have every clip.
+        let Some(node) = node_map.get(state.clip_name()).copied()
+        else {
+            continue;
+        };
+
         let (mut anim_player, mut anim_transitions) =
             q_animation_players.get_mut(animation_target.player)?;
 
-        if reached_target {
-            let eat_node = *node_map
-                .get("Eat")
-                .ok_or("No idle animation found for enemy!")?;
-
-            if anim_player.is_playing_animation(eat_node) == false {
-                anim_transitions
-                    .play(
-                        &mut anim_player,
-                        eat_node,
-                        Duration::from_millis(200),
-                    )
-                    .repeat();
-            }
-        } else {
-            let walk_node = *node_map
-                .get("Walk")
-                .ok_or("No walking animation found for enemy!")?;
-
-            if anim_player.is_playing_animation(walk_node) == false {
-                anim_transitions
-                    .play(
-                        &mut anim_player,
-                        walk_node,
-                        Duration::from_millis(200),
-                    )
-                    .set_speed(1.5)
-                    .repeat();
-            }
+        if anim_player.is_playing_animation(node) == false {
+            cross_fade(&mut anim_player, &mut anim_transitions, node)
+                .repeat();
+        }
+
+        if *state != EnemyAnimationState::Walk
+            || enemy.movement_speed <= 0.0
+        {
+            continue;
+        }
+
+        if let Some(walk_anim) = anim_player.animation_mut(node) {
+            // Walk faster or slower based on the actual speed.
+            walk_anim.set_speed(
+                EnemyAnimationState::WALK_ANIMATION_SPEED
+                    * linear_velocity.length()
+                    / enemy.movement_speed,
+            );
         }
     }
 
     Ok(())
 }
 
+/// Blend into `node` from the currently playing animation.
+fn cross_fade<'p>(
+    anim_player: &'p mut AnimationPlayer,
+    anim_transitions: &mut AnimationTransitions,
+    node: AnimationNodeIndex,
+) -> &'p mut ActiveAnimation {
+    anim_transitions.play(
+        anim_player,
+        node,
+        Duration::from_millis(200),
+    )
+}
+
 fn setup_animation_graph(
     mut commands: Commands,
     q_enemies: Query<
@@ -97,3 +144,71 @@ fn setup_animation_graph(
 
     Ok(())
 }
+
+/// Animation state of an enemy, driven by its movement speed.
+#[derive(
+    Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[reflect(Component)]
+pub enum EnemyAnimationState {
+    #[default]
+    Idle,
+    Walk,
+    Eat,
+}
+
+impl EnemyAnimationState {
+    /// Minimum speed to be considered walking.
+    pub const WALK_SPEED_THRESHOLD: f32 = 0.1;
+    /// Playback speed of the walk clip at full movement speed.
+    pub const WALK_ANIMATION_SPEED: f32 = 1.5;
+
+    /// Name of the animation clip to play in this state.
+    pub fn clip_name(&self) -> &'static str {
+        match self {
+            EnemyAnimationState::Idle => "Idle",
+            EnemyAnimationState::Walk => "Walk",
+            EnemyAnimationState::Eat => "Eat",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stunned_enemy_idles() {
+        let mut app = App::new();
+        app.add_systems(Update, update_animation_state);
+
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Enemy {
+                    movement_speed: 2.0,
+                    damage: 1.0,
+                    attack_cooldown: 1.0,
+                },
+                LinearVelocity(Vec3::X * 2.0),
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<EnemyAnimationState>(enemy),
+            Some(&EnemyAnimationState::Walk)
+        );
+
+        // Stunned enemies stop moving entirely.
+        app.world_mut()
+            .entity_mut(enemy)
+            .insert(LinearVelocity::ZERO);
+
+        app.update();
+        assert_eq!(
+            app.world().get::<EnemyAnimationState>(enemy),
+            Some(&EnemyAnimationState::Idle)
+        );
+    }
+}
@@ -0,0 +1,379 @@
+use bevy::asset::{AssetLoader, io::Reader};
+use bevy::asset::{AsyncReadExt, LoadContext};
+use bevy::color::palettes::tailwind::*;
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::asset_pipeline::{
+    CurrentScene, LevelScoped, PrefabAssets, PrefabName,
+};
+use crate::tower::tower_attack::Invulnerable;
+
+use super::ability::{SplitOf, SplitOnDeath};
+use super::spawner::Formation;
+use super::{Enemy, Path, PathIndex};
+
+pub(super) struct BossPhasePlugin;
+
+impl Plugin for BossPhasePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<BossPhaseAsset>()
+            .init_asset_loader::<BossPhaseAssetLoader>()
+            .init_resource::<ShieldAssets>();
+
+        app.add_systems(PreStartup, load_boss_phases)
+            .add_systems(Update, drive_boss_phases)
+            .add_observer(link_summon)
+            .add_observer(spawn_shield)
+            .add_observer(despawn_shield);
+
+        app.register_type::<BossPhases>();
+    }
+}
+
+/// Startup system: load "bosses.boss_phase.ron" and insert as a resource.
+fn load_boss_phases(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(BossPhaseAssetHandle(
+        asset_server.load("bosses.boss_phase.ron"),
+    ));
+}
+
+/// Enter the current [`BossPhase`] of every boss and move on
+/// to the next one once its [`PhaseEnd`] is met.
+///
+/// The phases loop back to the first one after the last.
+fn drive_boss_phases(
+    mut commands: Commands,
+    mut q_bosses: Query<(
+        &BossPhases,
+        &mut PhaseState,
+        &GlobalTransform,
+        Option<(&Path, &PathIndex)>,
+        Option<&Summons>,
+        Entity,
+    )>,
+    q_pending_summons: Query<&SummonOf>,
+    boss_registry: BossPhaseRegistry,
+    prefabs: Option<Res<PrefabAssets>>,
+    gltfs: Res<Assets<Gltf>>,
+    current_scene: Res<CurrentScene>,
+    time: Res<Time>,
+) {
+    for (
+        boss_phases,
+        mut state,
+        global_transform,
+        path,
+        summons,
+        entity,
+    ) in q_bosses.iter_mut()
+    {
+        let Some(phases) = boss_registry.get_phases(&boss_phases.0)
+        else {
+            continue;
+        };
+        let Some(phase) = phases.get(state.index) else {
+            continue;
+        };
+
+        if state.entered == false {
+            state.entered = true;
+            state.elapsed = 0.0;
+
+            match phase.invulnerable {
+                true => {
+                    commands.entity(entity).insert(Invulnerable);
+                }
+                false => {
+                    commands.entity(entity).remove::<Invulnerable>();
+                }
+            }
+
+            if let (Some(summon), Some(prefabs)) =
+                (&phase.summon, &prefabs)
+            {
+                let prefab_name = summon.prefab.as_str();
+                // Keep driving the other bosses on a bad prefab.
+                let Some(scene) = prefabs
+                    .get_gltf(
+                        PrefabName::FileName(prefab_name),
+                        &gltfs,
+                    )
+                    .and_then(|gltf| gltf.default_scene.clone())
+                else {
+                    error!(
+                        "Can't find the default scene of {prefab_name} prefab!"
+                    );
+                    continue;
+                };
+
+                let remaining_path = path
+                    .and_then(|(path, path_index)| {
+                        path.get(**path_index..)
+                    })
+                    .unwrap_or_default()
+                    .to_vec();
+                let count = summon.count as usize;
+                let formation = Formation::Group {
+                    size: count,
+                    radius: BossPhase::SUMMON_RADIUS,
+                };
+
+                // Summons follow the rest of the boss's path.
+                for offset in formation.offsets(count) {
+                    let mut summoned = commands.spawn((
+                        SceneRoot(scene.clone()),
+                        SplitOf {
+                            path: remaining_path.clone(),
+                            // Summons never split.
+                            depth: SplitOnDeath::MAX_DEPTH,
+                        },
+                        SummonOf(entity),
+                        LevelScoped,
+                        Transform::from_translation(
+                            global_transform.translation() + offset,
+                        ),
+                    ));
+
+                    if let Some(current_scene) = current_scene.get() {
+                        summoned.insert(ChildOf(current_scene));
+                    }
+                }
+            }
+
+            continue;
+        }
+
+        state.elapsed += time.delta_secs();
+
+        let ended = match phase.until {
+            PhaseEnd::Timer(duration) => state.elapsed >= duration,
+            PhaseEnd::SummonsKilled => {
+                let pending = q_pending_summons
+                    .iter()
+                    .any(|summon_of| summon_of.0 == entity);
+                let alive =
+                    summons.is_some_and(|s| s.is_empty() == false);
+
+                pending == false && alive == false
+            }
+        };
+
+        if ended {
+            state.index = (state.index + 1) % phases.len();
+            state.entered = false;
+        }
+    }
+}
+
+/// Link a summoned [`Enemy`] to its boss once the
+/// summoned prefab gets instantiated.
+fn link_summon(
+    trigger: Trigger<OnAdd, Enemy>,
+    mut commands: Commands,
+    q_summon_ofs: Query<&SummonOf>,
+    q_parents: Query<&ChildOf>,
+) {
+    let entity = trigger.target();
+
+    // The enemy could be anywhere within the prefab's hierarchy.
+    let Some((root, summon_of)) = core::iter::once(entity)
+        .chain(q_parents.iter_ancestors(entity))
+        .find_map(|e| q_summon_ofs.get(e).ok().map(|s| (e, s)))
+    else {
+        return;
+    };
+
+    commands.entity(entity).insert(SummonedBy(summon_of.0));
+    commands.entity(root).remove::<SummonOf>();
+}
+
+/// Show a shield around [`Invulnerable`] enemies.
+fn spawn_shield(
+    trigger: Trigger<OnAdd, Invulnerable>,
+    mut commands: Commands,
+    q_enemies: Query<(), With<Enemy>>,
+    shield_assets: Res<ShieldAssets>,
+) {
+    let entity = trigger.target();
+    if q_enemies.contains(entity) == false {
+        return;
+    }
+
+    let shield = commands
+        .spawn((
+            Mesh3d(shield_assets.mesh.clone()),
+            MeshMaterial3d(shield_assets.material.clone()),
+            Transform::from_xyz(0.0, 0.5, 0.0),
+            ChildOf(entity),
+        ))
+        .id();
+
+    commands.entity(entity).insert(ShieldVfx(shield));
+}
+
+fn despawn_shield(
+    trigger: Trigger<OnRemove, Invulnerable>,
+    mut commands: Commands,
+    q_shields: Query<&ShieldVfx>,
+) {
+    let entity = trigger.target();
+    let Ok(shield) = q_shields.get(entity) else {
+        return;
+    };
+
+    commands.entity(shield.0).try_despawn();
+    commands.entity(entity).try_remove::<ShieldVfx>();
+}
+
+/// Makes this enemy a boss that cycles through the
+/// [`BossPhase`]s of the given entry in "bosses.boss_phase.ron".
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+#[require(PhaseState)]
+pub struct BossPhases(pub String);
+
+/// Progress of a [`BossPhases`] boss.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct PhaseState {
+    /// Index of the current [`BossPhase`].
+    pub index: usize,
+    /// Seconds since the current phase was entered.
+    pub elapsed: f32,
+    /// Whether the current phase has been entered.
+    pub entered: bool,
+}
+
+/// Attached to the root of a summoned prefab until its
+/// [`Enemy`] is instantiated.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SummonOf(pub Entity);
+
+/// An [`Enemy`] summoned by a boss.
+#[derive(Component, Deref, Debug)]
+#[relationship(relationship_target = Summons)]
+pub struct SummonedBy(pub Entity);
+
+/// The alive enemies summoned by this boss.
+#[derive(Component, Deref, Debug)]
+#[relationship_target(relationship = SummonedBy)]
+pub struct Summons(Vec<Entity>);
+
+/// The shield model of an [`Invulnerable`] enemy.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ShieldVfx(pub Entity);
+
+#[derive(Resource)]
+pub struct ShieldAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for ShieldAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Sphere::new(1.2));
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: CYAN_300.with_alpha(0.3).into(),
+                emissive: CYAN_500.into(),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            });
+
+        Self { mesh, material }
+    }
+}
+
+#[derive(Asset, TypePath, Deref, Debug, Clone, Deserialize)]
+pub struct BossPhaseAsset(HashMap<String, Vec<BossPhase>>);
+
+/// A phase of a boss - loaded from RON files.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BossPhase {
+    /// Whether the boss is [`Invulnerable`] during this phase.
+    #[serde(default)]
+    pub invulnerable: bool,
+    /// Enemies summoned when entering this phase.
+    #[serde(default)]
+    pub summon: Option<Summon>,
+    pub until: PhaseEnd,
+}
+
+impl BossPhase {
+    /// Radius in which the summoned enemies are scattered.
+    pub const SUMMON_RADIUS: f32 = 1.5;
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Summon {
+    /// File name of the prefab to spawn.
+    pub prefab: String,
+    pub count: u32,
+}
+
+/// Condition for a [`BossPhase`] to end.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum PhaseEnd {
+    /// After the given number of seconds.
+    Timer(f32),
+    /// Once every enemy summoned by the phase is dead.
+    SummonsKilled,
+}
+
+#[derive(Resource)]
+pub struct BossPhaseAssetHandle(pub Handle<BossPhaseAsset>);
+
+#[derive(SystemParam)]
+pub struct BossPhaseRegistry<'w> {
+    pub handle: Res<'w, BossPhaseAssetHandle>,
+    pub assets: Res<'w, Assets<BossPhaseAsset>>,
+}
+
+impl BossPhaseRegistry<'_> {
+    pub fn get(&self) -> Option<&BossPhaseAsset> {
+        self.assets.get(&self.handle.0)
+    }
+
+    pub fn get_phases(&self, boss: &str) -> Option<&[BossPhase]> {
+        self.get()?.get(boss).map(Vec::as_slice)
+    }
+}
+
+#[derive(Default)]
+pub struct BossPhaseAssetLoader;
+
+impl AssetLoader for BossPhaseAssetLoader {
+    type Asset = BossPhaseAsset;
+
+    type Settings = ();
+
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut ron_str = String::new();
+        reader.read_to_string(&mut ron_str).await?;
+
+        // Hot reloads keep the previous asset on a typo.
+        ron::from_str::<BossPhaseAsset>(&ron_str).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["boss_phase.ron"]
+    }
+}
@@ -0,0 +1,158 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::physics::GameLayer;
+use crate::tower::tower_attack::{
+    Health, Invulnerable, SpawnProtection,
+};
+
+use super::Enemy;
+
+pub(super) struct ContactDamagePlugin;
+
+impl Plugin for ContactDamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, contact_damage);
+
+        app.register_type::<ContactDamage>();
+    }
+}
+
+/// Damage players that are touching an enemy and knock both of
+/// them away from each other.
+///
+/// A player can only be hurt once every [`ContactCooldown::INTERVAL`],
+/// no matter how many enemies are touching it.
+fn contact_damage(
+    mut q_players: Query<
+        (&mut Position, &mut Health, &mut ContactCooldown),
+        (Without<Invulnerable>, Without<SpawnProtection>),
+    >,
+    mut q_enemies: Query<
+        (&mut Position, &ContactDamage),
+        (With<Enemy>, Without<ContactCooldown>),
+    >,
+    q_collider_ofs: Query<&ColliderOf>,
+    spatial_query: SpatialQuery,
+    time: Res<Time>,
+) {
+    for (mut player_position, mut health, mut cooldown) in
+        q_players.iter_mut()
+    {
+        cooldown.0 = (cooldown.0 - time.delta_secs()).max(0.0);
+        if cooldown.0 > 0.0 {
+            continue;
+        }
+
+        let Some(enemy) = spatial_query
+            .shape_intersections(
+                &Collider::sphere(ContactCooldown::RADIUS),
+                player_position.0,
+                Quat::IDENTITY,
+                &GameLayer::Enemy.query_filter(),
+            )
+            .into_iter()
+            .map(|entity| {
+                q_collider_ofs
+                    .get(entity)
+                    .map(|c| c.body)
+                    .unwrap_or(entity)
+            })
+            .find(|entity| q_enemies.contains(*entity))
+        else {
+            continue;
+        };
+        let Ok((mut enemy_position, contact)) =
+            q_enemies.get_mut(enemy)
+        else {
+            continue;
+        };
+
+        health.0 = (health.0 - contact.damage).max(0.0);
+        cooldown.0 = ContactCooldown::INTERVAL;
+
+        // Push both sides apart horizontally.
+        let direction = (player_position.0 - enemy_position.0)
+            .reject_from_normalized(Vec3::Y)
+            .normalize_or_zero();
+        player_position.0 += direction * contact.knockback;
+        enemy_position.0 -= direction * contact.knockback * 0.5;
+    }
+}
+
+/// Damage dealt to players that walk into the enemy.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct ContactDamage {
+    pub damage: f32,
+    /// Distance that the player gets knocked back,
+    /// the enemy is knocked back by half of it.
+    pub knockback: f32,
+}
+
+impl Default for ContactDamage {
+    fn default() -> Self {
+        Self {
+            damage: 1.0,
+            knockback: 0.5,
+        }
+    }
+}
+
+/// Remaining time before the entity can take [`ContactDamage`] again.
+#[derive(Component, Default, Debug)]
+pub struct ContactCooldown(pub f32);
+
+impl ContactCooldown {
+    /// Interval in seconds between each contact damage.
+    pub const INTERVAL: f32 = 0.5;
+    /// Radius around the entity in which enemies are touching it.
+    pub const RADIUS: f32 = 0.6;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{
+        CombatTestAppExt, TIMESTEP, combat_app_with,
+    };
+    use crate::tower::tower_attack::MaxHealth;
+
+    #[test]
+    fn test_contact_damage_on_interval() {
+        let mut app = combat_app_with(ContactDamagePlugin);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                MaxHealth(10.0),
+                ContactCooldown::default(),
+                RigidBody::Kinematic,
+                Transform::default(),
+            ))
+            .id();
+
+        let enemy = app.spawn_enemy(10.0, Vec3::new(0.3, 0.0, 0.0));
+        app.world_mut().entity_mut(enemy).insert(ContactDamage {
+            damage: 1.0,
+            knockback: 0.0,
+        });
+
+        let health =
+            |app: &App| app.world().get::<Health>(player).unwrap().0;
+
+        let interval_steps = (ContactCooldown::INTERVAL
+            / TIMESTEP.as_secs_f32())
+        .ceil() as usize;
+
+        app.step(2);
+        assert_eq!(health(&app), 9.0);
+
+        // Still on cooldown.
+        app.step(interval_steps / 2);
+        assert_eq!(health(&app), 9.0);
+
+        app.step(interval_steps);
+        assert_eq!(health(&app), 8.0);
+    }
+}
@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+use bevy::render::primitives::{Frustum, Sphere};
+use bevy::render::view::RenderLayers;
+
+use crate::camera_controller::split_screen::{
+    CameraType, QueryCameras,
+};
+use crate::camera_controller::{A_RENDER_LAYER, B_RENDER_LAYER};
+use crate::settings::Settings;
+
+use super::Enemy;
+
+pub(super) struct EnemyCullingPlugin;
+
+impl Plugin for EnemyCullingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (cull_enemies, apply_enemy_render_layers)
+                .chain()
+                .after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// Cull enemies that are too far from or outside
+/// the view of each player's camera.
+fn cull_enemies(
+    mut commands: Commands,
+    q_enemies: Query<
+        (&GlobalTransform, Option<&CameraCulled>, Entity),
+        With<Enemy>,
+    >,
+    q_cameras: QueryCameras<(&GlobalTransform, &Frustum)>,
+    settings: Res<Settings>,
+) {
+    let camera_a = q_cameras.get(CameraType::A).ok();
+    let camera_b = q_cameras.get(CameraType::B).ok();
+
+    let is_culled = |camera: Option<(&GlobalTransform, &Frustum)>,
+                     position: Vec3| {
+        let Some((camera_transform, frustum)) = camera else {
+            // Nothing to render for.
+            return false;
+        };
+
+        let sphere = Sphere {
+            center: position.into(),
+            radius: CameraCulled::MODEL_RADIUS,
+        };
+
+        camera_transform.translation().distance(position)
+            > settings.enemy_cull_distance
+            || frustum.intersects_sphere(&sphere, true) == false
+    };
+
+    for (global_transform, culled, entity) in q_enemies.iter() {
+        let position = global_transform.translation();
+        let new_culled = CameraCulled {
+            a: is_culled(camera_a, position),
+            b: is_culled(camera_b, position),
+        };
+
+        if culled != Some(&new_culled) {
+            commands.entity(entity).insert(new_culled);
+        }
+    }
+}
+
+/// Only render the enemy models on the cameras that they
+/// are not culled from.
+///
+/// Checked every frame as the models are instantiated
+/// after the [`Enemy`] itself.
+fn apply_enemy_render_layers(
+    mut commands: Commands,
+    q_enemies: Query<(&CameraCulled, Entity)>,
+    q_children: Query<&Children>,
+    q_meshes: Query<Option<&RenderLayers>, With<Mesh3d>>,
+) {
+    for (culled, entity) in q_enemies.iter() {
+        let render_layers = culled.render_layers();
+
+        for child in q_children.iter_descendants(entity) {
+            let Ok(current) = q_meshes.get(child) else {
+                continue;
+            };
+
+            if current != Some(&render_layers) {
+                commands.entity(child).insert(render_layers.clone());
+            }
+        }
+    }
+}
+
+/// Cameras that an [`Enemy`]'s model is not rendered on.
+/// The enemy keeps being simulated regardless.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CameraCulled {
+    pub a: bool,
+    pub b: bool,
+}
+
+impl CameraCulled {
+    /// Radius of the sphere bounding an enemy model.
+    pub const MODEL_RADIUS: f32 = 1.0;
+
+    /// [`RenderLayers`] of the cameras that are not culled,
+    /// directional lights render shadows on all of them.
+    pub fn render_layers(&self) -> RenderLayers {
+        let mut render_layers = RenderLayers::none();
+
+        if self.a == false {
+            render_layers = render_layers.union(&A_RENDER_LAYER);
+        }
+        if self.b == false {
+            render_layers = render_layers.union(&B_RENDER_LAYER);
+        }
+
+        render_layers
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::render::camera::CameraProjection;
+
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app_with};
+    use crate::tower::tower_attack::{Health, Tower};
+
+    #[test]
+    fn test_off_viewport_enemy_is_hidden_but_simulated() {
+        let mut app = combat_app_with(EnemyCullingPlugin);
+        app.init_resource::<Settings>();
+
+        // Camera A looks down -Z while camera B looks down +Z.
+        for (camera_type, looking_to) in
+            [(CameraType::A, Vec3::NEG_Z), (CameraType::B, Vec3::Z)]
+        {
+            let transform = Transform::from_xyz(0.0, 1.0, 0.0)
+                .looking_to(looking_to, Vec3::Y);
+            let projection = PerspectiveProjection::default();
+            let frustum = projection
+                .compute_frustum(&GlobalTransform::from(transform));
+
+            app.world_mut().spawn((
+                Camera::default(),
+                camera_type,
+                transform,
+                frustum,
+            ));
+        }
+
+        // In front of camera B, behind camera A.
+        let enemy = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+        let model = app
+            .world_mut()
+            .spawn((Mesh3d::default(), ChildOf(enemy)))
+            .id();
+
+        app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 10.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+
+        app.step(2);
+
+        assert_eq!(
+            app.world().get::<CameraCulled>(enemy),
+            Some(&CameraCulled { a: true, b: false })
+        );
+        assert_eq!(
+            app.world().get::<RenderLayers>(model),
+            Some(&B_RENDER_LAYER)
+        );
+
+        // Culling only affects rendering.
+        app.step(64);
+        let health = app.world().get::<Health>(enemy).unwrap().0;
+        assert!(health < 100.0, "Enemy should have been damaged.");
+    }
+}
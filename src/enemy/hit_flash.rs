@@ -0,0 +1,123 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use super::Enemy;
+
+pub(super) struct HitFlashPlugin;
+
+impl Plugin for HitFlashPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (start_hit_flash, end_hit_flash).chain(),
+        );
+    }
+}
+
+/// Swap the materials of a newly hit enemy to their flashing variant.
+///
+/// Flashing variants are cached per material so that many
+/// simultaneous hits share the same handful of materials.
+fn start_hit_flash(
+    mut commands: Commands,
+    q_enemies: Query<Entity, (Changed<HitFlash>, With<Enemy>)>,
+    q_children: Query<&Children>,
+    mut q_materials: Query<
+        &mut MeshMaterial3d<StandardMaterial>,
+        Without<HitFlashOriginal>,
+    >,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut flash_materials: Local<
+        HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>,
+    >,
+) {
+    for entity in q_enemies.iter() {
+        for child in q_children.iter_descendants(entity) {
+            let Ok(mut material) = q_materials.get_mut(child) else {
+                continue;
+            };
+
+            let original = material.0.clone();
+            let Some(flash_material) = flash_materials
+                .get(&original.id())
+                .cloned()
+                .or_else(|| {
+                    let mut flash_material =
+                        materials.get(&original)?.clone();
+                    flash_material.base_color = HitFlash::COLOR;
+                    flash_material.emissive = HitFlash::COLOR.into();
+
+                    let handle = materials.add(flash_material);
+                    flash_materials
+                        .insert(original.id(), handle.clone());
+                    Some(handle)
+                })
+            else {
+                continue;
+            };
+
+            material.0 = flash_material;
+            commands.entity(child).insert(HitFlashOriginal(original));
+        }
+    }
+}
+
+/// Restore the original materials once the flash is over.
+fn end_hit_flash(
+    mut commands: Commands,
+    mut q_enemies: Query<(&mut HitFlash, Entity)>,
+    q_children: Query<&Children>,
+    mut q_materials: Query<(
+        &mut MeshMaterial3d<StandardMaterial>,
+        &HitFlashOriginal,
+    )>,
+    time: Res<Time>,
+) {
+    for (mut hit_flash, entity) in q_enemies.iter_mut() {
+        // Prevent change detection from restarting the flash.
+        let hit_flash = hit_flash.bypass_change_detection();
+        hit_flash.remaining -= time.delta_secs();
+
+        if hit_flash.remaining > 0.0 {
+            continue;
+        }
+
+        for child in q_children.iter_descendants(entity) {
+            let Ok((mut material, original)) =
+                q_materials.get_mut(child)
+            else {
+                continue;
+            };
+
+            material.0 = original.0.clone();
+            commands.entity(child).remove::<HitFlashOriginal>();
+        }
+
+        commands.entity(entity).remove::<HitFlash>();
+    }
+}
+
+/// Briefly flash the enemy's model after taking damage.
+/// Re-inserting restarts the flash.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HitFlash {
+    /// Remaining duration of the flash in seconds.
+    pub remaining: f32,
+}
+
+impl HitFlash {
+    pub const DURATION: f32 = 0.1;
+    pub const COLOR: Color = Color::srgb(1.0, 0.3, 0.3);
+}
+
+impl Default for HitFlash {
+    fn default() -> Self {
+        Self {
+            remaining: Self::DURATION,
+        }
+    }
+}
+
+/// The material that was swapped out by the [`HitFlash`].
+#[derive(Component, Debug)]
+struct HitFlashOriginal(Handle<StandardMaterial>);
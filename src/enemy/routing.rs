@@ -0,0 +1,298 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+use crate::action::{PlayerAction, TargetAction};
+use crate::interaction::MarkerOf;
+use crate::rng::GameRng;
+use crate::tile::TileMap;
+
+use super::PathIndex;
+
+pub(super) struct RoutingPlugin;
+
+impl Plugin for RoutingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (toggle_branch_gates, track_route_progress),
+        );
+
+        app.register_type::<PathJunction>()
+            .register_type::<PathBranch>()
+            .register_type::<BranchGate>();
+    }
+}
+
+/// Open or close the [`PathBranch`] of a [`BranchGate`]
+/// when a player interacts with it.
+fn toggle_branch_gates(
+    q_players: Query<(&MarkerOf, &TargetAction)>,
+    q_actions: Query<&ActionState<PlayerAction>>,
+    q_gates: Query<&ChildOf, With<BranchGate>>,
+    mut q_branches: Query<&mut PathBranch>,
+) {
+    for (marked_item, target_action) in q_players.iter() {
+        let Ok(child_of) = q_gates.get(marked_item.entity()) else {
+            continue;
+        };
+
+        let Ok(action_state) = q_actions.get(target_action.get())
+        else {
+            continue;
+        };
+
+        if action_state.just_pressed(&PlayerAction::Interact) == false
+        {
+            continue;
+        }
+
+        if let Ok(mut branch) = q_branches.get_mut(child_of.parent())
+        {
+            branch.open = !branch.open;
+        }
+    }
+}
+
+/// Count the junctions that the enemies went past.
+fn track_route_progress(
+    mut q_routes: Query<
+        (&mut ChosenRoute, &PathIndex),
+        Changed<PathIndex>,
+    >,
+) {
+    for (mut route, path_index) in q_routes.iter_mut() {
+        while route
+            .reached
+            .first()
+            .is_some_and(|index| **path_index > *index)
+        {
+            route.reached.remove(0);
+            route.passed += 1;
+        }
+    }
+}
+
+/// Pick an index from the given weights, each index
+/// is picked proportionally to its weight.
+pub fn choose_weighted(
+    weights: &[f32],
+    rng: &mut GameRng,
+) -> Option<usize> {
+    let total = weights.iter().map(|w| w.max(0.0)).sum::<f32>();
+    if total <= 0.0 {
+        return None;
+    }
+
+    let mut pick = rng.f32() * total;
+    for (index, weight) in weights.iter().enumerate() {
+        let weight = weight.max(0.0);
+        if pick < weight {
+            return Some(index);
+        }
+        pick -= weight;
+    }
+
+    // Floating point leftovers.
+    weights.iter().rposition(|w| *w > 0.0)
+}
+
+/// Pathfinding through the [`PathJunction`]s of the level.
+#[derive(SystemParam)]
+pub struct Router<'w, 's> {
+    q_junctions:
+        Query<'w, 's, (&'static PathJunction, &'static Children)>,
+    q_branches: Query<
+        'w,
+        's,
+        (&'static PathBranch, &'static GlobalTransform, Entity),
+    >,
+    q_changed_branches: Query<'w, 's, (), Changed<PathBranch>>,
+    rng: ResMut<'w, GameRng>,
+}
+
+impl Router<'_, '_> {
+    /// Whether any branch has been opened or closed.
+    pub fn branches_changed(&self) -> bool {
+        self.q_changed_branches.is_empty() == false
+    }
+
+    /// Find a path to `end` going through one open [`PathBranch`]
+    /// of every junction that has not been passed yet,
+    /// any branch is taken when all of them are closed.
+    ///
+    /// Branches of the `previous` route are kept while they are
+    /// still open, levels without junctions get a direct path.
+    pub fn route(
+        &mut self,
+        tile_map: &TileMap,
+        start: Vec3,
+        end: Vec3,
+        previous: Option<&ChosenRoute>,
+    ) -> Option<(Vec<IVec2>, ChosenRoute)> {
+        let mut junctions =
+            self.q_junctions.iter().collect::<Vec<_>>();
+        junctions.sort_by_key(|(junction, _)| junction.order);
+
+        let passed = previous.map(|route| route.passed).unwrap_or(0);
+        let mut route = ChosenRoute {
+            passed,
+            ..default()
+        };
+        let mut waypoints = Vec::new();
+
+        for (index, (_, children)) in junctions.iter().enumerate() {
+            let previous_branch = previous
+                .and_then(|route| route.branches.get(index))
+                .copied();
+
+            // Passed junctions keep their branch.
+            if index < passed {
+                route.branches.extend(previous_branch);
+                continue;
+            }
+
+            let branches = children
+                .iter()
+                .filter_map(|child| self.q_branches.get(child).ok())
+                .collect::<Vec<_>>();
+            let mut open_branches = branches
+                .iter()
+                .filter(|(branch, ..)| branch.open)
+                .copied()
+                .collect::<Vec<_>>();
+
+            // Gates can't block every branch.
+            if open_branches.is_empty() {
+                open_branches = branches;
+            }
+
+            let branch = match open_branches
+                .iter()
+                .find(|(.., entity)| Some(*entity) == previous_branch)
+            {
+                Some(branch) => *branch,
+                None => {
+                    let weights = open_branches
+                        .iter()
+                        .map(|(branch, ..)| branch.weight)
+                        .collect::<Vec<_>>();
+                    let Some(chosen) =
+                        choose_weighted(&weights, &mut self.rng)
+                    else {
+                        continue;
+                    };
+                    open_branches[chosen]
+                }
+            };
+
+            let (_, global_transform, entity) = branch;
+            route.branches.push(entity);
+            waypoints.push(global_transform.translation());
+        }
+
+        let mut path = Vec::new();
+        let mut from = start;
+
+        for target in waypoints.into_iter().chain([end]) {
+            let segment =
+                tile_map.pathfind_to(&from, &target, false)?;
+
+            // Segments start where the previous one ended.
+            let skip = match path.is_empty() {
+                true => 0,
+                false => 1,
+            };
+            path.extend(segment.into_iter().skip(skip));
+            route.reached.push(path.len().saturating_sub(1));
+            from = target;
+        }
+
+        // The final target is not a junction.
+        route.reached.pop();
+
+        Some((path, route))
+    }
+}
+
+/// A point where the enemies split into one of the
+/// [`PathBranch`]es among its children.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct PathJunction {
+    /// Junctions are passed through in ascending order.
+    pub order: u32,
+}
+
+/// A waypoint that enemies go through after a [`PathJunction`].
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct PathBranch {
+    /// Relative chance of this branch being chosen.
+    pub weight: f32,
+    /// Closed branches are only chosen when every branch
+    /// of the junction is closed.
+    pub open: bool,
+}
+
+impl Default for PathBranch {
+    fn default() -> Self {
+        Self {
+            weight: 1.0,
+            open: true,
+        }
+    }
+}
+
+/// Interacting with this opens or closes its
+/// parent [`PathBranch`], redirecting the enemies.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct BranchGate;
+
+/// The [`PathBranch`]es an enemy goes through, one per junction.
+#[derive(Component, Default, Debug, Clone)]
+pub struct ChosenRoute {
+    pub branches: Vec<Entity>,
+    /// Number of junctions already passed.
+    pub passed: usize,
+    /// [`PathIndex`] at which each remaining branch is reached.
+    pub reached: Vec<usize>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_seeded_branches_split_by_weight() {
+        let mut rng = GameRng::new(42);
+        let weights = [3.0, 1.0, 0.0];
+
+        let mut counts = [0; 3];
+        for _ in 0..1000 {
+            let index = choose_weighted(&weights, &mut rng).unwrap();
+            counts[index] += 1;
+        }
+
+        // Deterministic with a fixed seed.
+        let mut rng = GameRng::new(42);
+        let mut replay = [0; 3];
+        for _ in 0..1000 {
+            replay[choose_weighted(&weights, &mut rng).unwrap()] += 1;
+        }
+        assert_eq!(counts, replay);
+
+        assert!(
+            (700..800).contains(&counts[0]),
+            "Expected ~75% on the first branch, got {counts:?}."
+        );
+        assert!((200..300).contains(&counts[1]));
+        assert_eq!(
+            counts[2], 0,
+            "Zero weight should never be chosen."
+        );
+
+        assert_eq!(choose_weighted(&[0.0, 0.0], &mut rng), None);
+    }
+}
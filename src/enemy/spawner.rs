@@ -1,20 +1,29 @@
 use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
 
+use crate::action::{PlayerAction, TargetAction};
 use crate::asset_pipeline::{CurrentScene, PrefabAssets, PrefabName};
+use crate::interaction::MarkerOf;
+use crate::settings::Settings;
 use crate::ui::Screen;
 
 use super::Enemy;
+use super::waves::{LevelWaves, WaveSpawns};
 
 pub(super) struct EnemySpawnerPlugin;
 
 impl Plugin for EnemySpawnerPlugin {
     fn build(&self, app: &mut App) {
-        app.register_type::<EnemySpawner>();
+        app.register_type::<EnemySpawner>()
+            .register_type::<Formation>()
+            .register_type::<WaveStartMode>()
+            .register_type::<WaveStartBell>();
 
         app.add_sub_state::<SpawnWave>()
             .init_resource::<WaveCountdown>()
             .init_resource::<SpawnCount>()
             .init_resource::<SpawnTimer>()
+            .init_resource::<SpawnSchedule>()
             .add_systems(
                 Update,
                 (
@@ -26,7 +35,14 @@ impl Plugin for EnemySpawnerPlugin {
                     .chain()
                     .run_if(in_state(Screen::EnterLevel)),
             )
-            .add_observer(on_add_spawner);
+            .add_systems(
+                Update,
+                ring_wave_start_bell
+                    .run_if(in_state(Screen::EnterLevel)),
+            )
+            .add_observer(on_add_spawner)
+            .add_observer(start_wave)
+            .add_observer(clear_pending_spawn);
     }
 }
 
@@ -40,10 +56,15 @@ fn on_add_spawner(
 
 fn spawn_enemy(
     mut commands: Commands,
-    q_spawner: Query<&GlobalTransform, With<EnemySpawner>>,
+    q_spawner: Query<(&GlobalTransform, &EnemySpawner)>,
     q_enemies: Query<(), With<Enemy>>,
+    q_occupants: Query<
+        &GlobalTransform,
+        Or<(With<Enemy>, With<PendingSpawn>)>,
+    >,
     countdown: Res<WaveCountdown>,
     timer: Res<SpawnTimer>,
+    mut schedule: ResMut<SpawnSchedule>,
     mut spawn_count: ResMut<SpawnCount>,
     current_scene: Res<CurrentScene>,
     prefabs: Res<PrefabAssets>,
@@ -51,21 +72,24 @@ fn spawn_enemy(
     curr_wave: Res<State<SpawnWave>>,
     mut next_wave: ResMut<NextState<SpawnWave>>,
     mut next_screen: ResMut<NextState<Screen>>,
-) -> Result {
-    let Ok(transform) = q_spawner.single() else {
-        return Ok(());
+    settings: Res<Settings>,
+) {
+    let Ok((transform, spawner)) = q_spawner.single() else {
+        return;
     };
 
     let Some(current_scene) = current_scene.get() else {
-        return Ok(());
+        return;
     };
 
     if countdown.finished() == false {
-        return Ok(());
+        return;
     }
 
-    if timer.just_finished() == false {
-        return Ok(());
+    // Scheduled spawns are checked every frame.
+    if schedule.is_active() == false && timer.just_finished() == false
+    {
+        return;
     }
 
     if spawn_count.0 == 0 {
@@ -87,36 +111,113 @@ fn spawn_enemy(
             }
             SpawnWave::None => {}
         }
-        return Ok(());
+        return;
     }
 
-    spawn_count.0 -= 1;
+    let formation = spawner
+        .wave(curr_wave.get())
+        .map(|wave| wave.formation)
+        .unwrap_or_default();
+    // Hold back the rest of the wave until enough enemies died.
+    let capacity = settings
+        .max_alive_enemies
+        .saturating_sub(q_enemies.iter().len());
+    let spawn_size = match schedule.is_active() {
+        true => schedule.due(),
+        false => formation.size().min(spawn_count.0),
+    }
+    .min(capacity);
+    if spawn_size == 0 {
+        return;
+    }
+
+    // Hold back the spots that are still occupied
+    // until the enemies in there walk away.
+    let spawner_position = transform.translation();
+    let mut occupied = q_occupants
+        .iter()
+        .map(|t| t.translation())
+        .collect::<Vec<_>>();
+    let mut positions = Vec::with_capacity(spawn_size);
+    for offset in formation.offsets(spawn_size) {
+        let position = spawner_position + offset;
+        let clear = occupied.iter().all(|occupant| {
+            occupant.distance(position) >= spawner.spawn_spacing
+        });
+
+        if clear {
+            occupied.push(position);
+            positions.push(position);
+        }
+    }
+
+    for position in positions {
+        let prefab_name = match schedule.is_active() {
+            true => schedule.pop().unwrap_or_default(),
+            false => "mouse_a".to_string(),
+        };
+        // Counted even when skipped, the count must never
+        // outlive the schedule it was taken from.
+        spawn_count.0 = spawn_count.0.saturating_sub(1);
+
+        let Some(scene) = prefabs
+            .get_gltf(PrefabName::FileName(&prefab_name), &gltfs)
+            .and_then(|gltf| gltf.default_scene.clone())
+        else {
+            warn!(
+                "Can't find the default scene of {prefab_name} prefab, skipping the spawn."
+            );
+            continue;
+        };
+
+        let mut transform = transform.compute_transform();
+        transform.translation = position;
+
+        commands.spawn((
+            SceneRoot(scene),
+            PendingSpawn,
+            transform,
+            ChildOf(current_scene),
+        ));
+    }
+}
 
-    commands.spawn((
-        SceneRoot(
-            prefabs
-                .get_gltf(PrefabName::FileName("mouse_a"), &gltfs)
-                .ok_or("Can't find mouse prefab!")?
-                .default_scene
-                .clone()
-                .ok_or("Mouse prefab should have a default scene.")?,
-        ),
-        transform.compute_transform(),
-        ChildOf(current_scene),
-    ));
+/// The spawned enemy prefab has been instantiated,
+/// it no longer occupies its spawn point.
+fn clear_pending_spawn(
+    trigger: Trigger<OnAdd, Enemy>,
+    mut commands: Commands,
+    q_pending: Query<(), With<PendingSpawn>>,
+    q_parents: Query<&ChildOf>,
+) {
+    let entity = trigger.target();
 
-    Ok(())
+    // The enemy could be anywhere within the prefab's hierarchy.
+    if let Some(root) = core::iter::once(entity)
+        .chain(q_parents.iter_ancestors(entity))
+        .find(|e| q_pending.contains(*e))
+    {
+        commands.entity(root).remove::<PendingSpawn>();
+    }
 }
 
 fn set_wave_countdown(
     current_wave: Res<State<SpawnWave>>,
     mut countdown: ResMut<WaveCountdown>,
     q_spawner: Query<&EnemySpawner>,
+    level_waves: LevelWaves,
 ) {
     let Ok(spawner) = q_spawner.single() else {
         return;
     };
 
+    if let Some(wave) = level_waves.get(current_wave.get()) {
+        info!("Setting {:?} countdown from RON.", current_wave.get());
+        countdown.0 =
+            Timer::from_seconds(wave.countdown, TimerMode::Once);
+        return;
+    }
+
     let countdown_time = match current_wave.get() {
         SpawnWave::One => {
             info!("Setting wave 1 countdown.");
@@ -142,13 +243,23 @@ fn set_wave_countdown(
 fn set_spawn_count_and_timer(
     q_spawner: Query<&EnemySpawner>,
     current_wave: Res<State<SpawnWave>>,
+    level_waves: LevelWaves,
     mut timer: ResMut<SpawnTimer>,
+    mut schedule: ResMut<SpawnSchedule>,
     mut spawn_count: ResMut<SpawnCount>,
 ) {
     let Ok(spawner) = q_spawner.single() else {
         return;
     };
 
+    if let Some(wave) = level_waves.get(current_wave.get()) {
+        info!("Setting {:?} spawns from RON.", current_wave.get());
+        *schedule = SpawnSchedule::new(wave);
+        spawn_count.0 = schedule.remaining();
+        return;
+    }
+    *schedule = SpawnSchedule::default();
+
     let (interval, count) = match current_wave.get() {
         SpawnWave::One => {
             info!("Setting wave 1 interval and count.");
@@ -180,24 +291,98 @@ fn set_spawn_count_and_timer(
     spawn_count.0 = count;
 }
 
-/// Tick every frame.
+/// Tick every frame, triggers [`WaveStarted`] once it finishes.
+///
+/// Held while waiting for a [`WaveStartMode::Manual`] start.
 fn wave_countdown(
+    mut commands: Commands,
     mut countdown: ResMut<WaveCountdown>,
+    current_wave: Res<State<SpawnWave>>,
+    q_spawner: Query<&EnemySpawner>,
     time: Res<Time>,
 ) {
-    if countdown.finished() == false {
-        countdown.tick(time.delta());
+    if countdown.finished() {
+        return;
+    }
+
+    if q_spawner
+        .single()
+        .is_ok_and(|s| s.start_mode == WaveStartMode::Manual)
+    {
+        return;
+    }
+
+    countdown.tick(time.delta());
+
+    if countdown.just_finished()
+        && *current_wave.get() != SpawnWave::None
+    {
+        commands.trigger(WaveStarted(*current_wave.get()));
+    }
+}
+
+/// Skip the rest of the countdown of a
+/// [`WaveStartMode::Manual`] wave and start it right away.
+fn start_wave(
+    _: Trigger<StartWave>,
+    mut commands: Commands,
+    q_spawner: Query<&EnemySpawner>,
+    mut countdown: ResMut<WaveCountdown>,
+    current_wave: Option<Res<State<SpawnWave>>>,
+) {
+    let Ok(spawner) = q_spawner.single() else {
+        return;
+    };
+    let Some(current_wave) = current_wave else {
+        return;
+    };
+
+    if spawner.start_mode != WaveStartMode::Manual
+        || countdown.finished()
+        || *current_wave.get() == SpawnWave::None
+    {
+        return;
+    }
+
+    let remaining = countdown.remaining();
+    countdown.tick(remaining);
+    commands.trigger(WaveStarted(*current_wave.get()));
+}
+
+/// Trigger [`StartWave`] when a player interacts
+/// with a [`WaveStartBell`].
+fn ring_wave_start_bell(
+    mut commands: Commands,
+    q_players: Query<(&MarkerOf, &TargetAction)>,
+    q_actions: Query<&ActionState<PlayerAction>>,
+    q_bells: Query<(), With<WaveStartBell>>,
+) {
+    for (marked_item, target_action) in q_players.iter() {
+        if q_bells.contains(marked_item.entity()) == false {
+            continue;
+        }
+
+        let Ok(action_state) = q_actions.get(target_action.get())
+        else {
+            continue;
+        };
+
+        if action_state.just_pressed(&PlayerAction::Interact) {
+            commands.trigger(StartWave);
+        }
     }
 }
 
 fn spawn_timer(
     countdown: Res<WaveCountdown>,
     mut timer: ResMut<SpawnTimer>,
+    mut schedule: ResMut<SpawnSchedule>,
     time: Res<Time>,
 ) {
     // Only tick after countdown is reached.
     if countdown.finished() {
         timer.tick(time.delta());
+        schedule.elapsed += time.delta_secs();
     }
 }
 
@@ -207,14 +392,129 @@ pub struct EnemySpawner {
     pub wave_1: WaveConfig,
     pub wave_2: WaveConfig,
     pub wave_3: WaveConfig,
+    #[reflect(default)]
+    pub start_mode: WaveStartMode,
+    /// Min distance between a new enemy and any other enemy,
+    /// spawns are held back until their spot clears.
+    #[reflect(default = "EnemySpawner::default_spawn_spacing")]
+    pub spawn_spacing: f32,
+}
+
+impl EnemySpawner {
+    pub const DEFAULT_SPAWN_SPACING: f32 = 0.6;
+
+    fn default_spawn_spacing() -> f32 {
+        Self::DEFAULT_SPAWN_SPACING
+    }
+
+    pub fn wave(&self, wave: &SpawnWave) -> Option<&WaveConfig> {
+        match wave {
+            SpawnWave::None => None,
+            SpawnWave::One => Some(&self.wave_1),
+            SpawnWave::Two => Some(&self.wave_2),
+            SpawnWave::Three => Some(&self.wave_3),
+        }
+    }
 }
 
+/// How the waves of an [`EnemySpawner`] are started.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveStartMode {
+    /// Start once the countdown finishes.
+    #[default]
+    Auto,
+    /// Hold the countdown until [`StartWave`] is triggered.
+    Manual,
+}
+
+/// Interacting with this starts a [`WaveStartMode::Manual`] wave.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct WaveStartBell;
+
 #[derive(Reflect)]
 pub struct WaveConfig {
     /// How long before the wave starts.
     pub countdown: f32,
     pub enemy_count: usize,
     pub spawn_interval: f32,
+    #[reflect(default)]
+    pub formation: Formation,
+}
+
+impl WaveConfig {
+    /// Max [`WaveConfig::danger_level`].
+    pub const MAX_DANGER_LEVEL: u8 = 5;
+    /// [`WaveConfig::danger`] needed for each level above 1.
+    const DANGER_THRESHOLDS: [f32; 4] = [15.0, 30.0, 60.0, 120.0];
+
+    /// Enemy health the wave sends at the players,
+    /// weighted by how fast the enemies come.
+    pub fn danger(&self, health_multiplier: f32) -> f32 {
+        let spawn_rate = self.formation.size() as f32
+            / self.spawn_interval.max(0.1);
+
+        self.enemy_count as f32
+            * health_multiplier
+            * spawn_rate.sqrt()
+    }
+
+    /// [`WaveConfig::danger`] from 1 to
+    /// [`WaveConfig::MAX_DANGER_LEVEL`].
+    pub fn danger_level(&self, health_multiplier: f32) -> u8 {
+        Self::level_of_danger(self.danger(health_multiplier))
+    }
+
+    /// Level of any wave's danger, from 1 to
+    /// [`WaveConfig::MAX_DANGER_LEVEL`].
+    pub fn level_of_danger(danger: f32) -> u8 {
+        1 + Self::DANGER_THRESHOLDS
+            .iter()
+            .filter(|threshold| danger >= **threshold)
+            .count() as u8
+    }
+}
+
+/// How enemies are emitted on every spawn interval.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq)]
+pub enum Formation {
+    /// One enemy at a time.
+    #[default]
+    Trickle,
+    /// A squad of `size` enemies at once,
+    /// scattered within `radius` so they don't stack on one collider.
+    Group { size: usize, radius: f32 },
+}
+
+impl Formation {
+    /// Number of enemies to spawn at once.
+    pub fn size(&self) -> usize {
+        match self {
+            Formation::Trickle => 1,
+            Formation::Group { size, .. } => (*size).max(1),
+        }
+    }
+
+    /// Spawn offsets of `count` enemies from the spawner.
+    pub fn offsets(
+        &self,
+        count: usize,
+    ) -> impl Iterator<Item = Vec3> {
+        let radius = match self {
+            Formation::Trickle => 0.0,
+            Formation::Group { radius, .. } => *radius,
+        };
+
+        // Sunflower pattern, evenly spread within a disc.
+        const GOLDEN_ANGLE: f32 = 2.399_963;
+        (0..count).map(move |i| {
+            let distance =
+                radius * ((i as f32 + 0.5) / count as f32).sqrt();
+            let (sin, cos) = (i as f32 * GOLDEN_ANGLE).sin_cos();
+
+            Vec3::new(cos, 0.0, sin) * distance
+        })
+    }
 }
 
 #[derive(
@@ -229,10 +529,37 @@ pub enum SpawnWave {
     Three,
 }
 
+impl SpawnWave {
+    /// Zero based index of the wave.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            SpawnWave::None => None,
+            SpawnWave::One => Some(0),
+            SpawnWave::Two => Some(1),
+            SpawnWave::Three => Some(2),
+        }
+    }
+}
+
+/// Triggered when the countdown of a wave finishes
+/// and its enemies start to spawn.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WaveStarted(pub SpawnWave);
+
+/// Trigger to start a [`WaveStartMode::Manual`] wave
+/// that is still counting down.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct StartWave;
+
 /// Countdown timer until enemies start to spawn.
 #[derive(Resource, Deref, DerefMut, Default)]
 pub struct WaveCountdown(Timer);
 
+/// Root of a spawned enemy prefab that has not been
+/// instantiated yet, occupying its spawn point.
+#[derive(Component, Debug)]
+pub struct PendingSpawn;
+
 /// Number of enemies to spawn left.
 #[derive(Resource, Deref, DerefMut, Default)]
 pub struct SpawnCount(usize);
@@ -240,3 +567,393 @@ pub struct SpawnCount(usize);
 /// Time left before the next spawn.
 #[derive(Resource, Deref, DerefMut, Default)]
 pub struct SpawnTimer(Timer);
+
+/// Spawns of a wave loaded from RON, takes over from the
+/// [`SpawnTimer`] and [`EnemySpawner`] prefab while active.
+#[derive(Resource, Default, Debug)]
+pub struct SpawnSchedule {
+    /// Spawn time and prefab name, the latest spawn first.
+    pending: Vec<(f32, String)>,
+    /// Time since the wave started.
+    elapsed: f32,
+    active: bool,
+}
+
+impl SpawnSchedule {
+    pub fn new(wave: &WaveSpawns) -> Self {
+        let mut pending = wave
+            .groups
+            .iter()
+            .flat_map(|group| {
+                group
+                    .spawn_times()
+                    .map(|time| (time, group.prefab.clone()))
+            })
+            .collect::<Vec<_>>();
+        pending.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+        Self {
+            pending,
+            elapsed: 0.0,
+            active: true,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Number of spawns left.
+    pub fn remaining(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Number of spawns that are due.
+    pub fn due(&self) -> usize {
+        self.pending
+            .iter()
+            .rev()
+            .take_while(|(time, _)| *time <= self.elapsed)
+            .count()
+    }
+
+    /// Take the prefab name of the earliest spawn.
+    fn pop(&mut self) -> Option<String> {
+        self.pending.pop().map(|(_, prefab)| prefab)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app};
+
+    /// Add [`spawn_enemy`] with a spawn timer that is always
+    /// finished and a wave that emits `size` enemies at once.
+    fn spawner_app(size: usize, count: usize, radius: f32) -> App {
+        let mut app = combat_app();
+        app.insert_resource(State::new(SpawnWave::One))
+            .init_resource::<NextState<SpawnWave>>()
+            .init_resource::<NextState<Screen>>()
+            .init_resource::<Settings>()
+            .insert_resource(SpawnCount(count))
+            .init_resource::<SpawnSchedule>()
+            .add_systems(Update, spawn_enemy);
+
+        let mut countdown = Timer::from_seconds(0.0, TimerMode::Once);
+        countdown.tick(Duration::ZERO);
+        app.insert_resource(WaveCountdown(countdown));
+
+        // Ticked once, and never again as `spawn_timer` is not added.
+        let mut timer =
+            Timer::from_seconds(1.0, TimerMode::Repeating);
+        timer.tick(Duration::from_secs(1));
+        app.insert_resource(SpawnTimer(timer));
+
+        let wave = || WaveConfig {
+            countdown: 0.0,
+            enemy_count: count,
+            spawn_interval: 1.0,
+            formation: Formation::Group { size, radius },
+        };
+        app.world_mut().spawn((
+            EnemySpawner {
+                wave_1: wave(),
+                wave_2: wave(),
+                wave_3: wave(),
+                start_mode: WaveStartMode::Auto,
+                spawn_spacing: EnemySpawner::DEFAULT_SPAWN_SPACING,
+            },
+            Transform::default(),
+        ));
+
+        app
+    }
+
+    fn spawned_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<(), With<SceneRoot>>()
+            .iter(app.world())
+            .len()
+    }
+
+    #[test]
+    fn test_spawn_cap_holds_back_wave() {
+        const CAP: usize = 4;
+
+        let mut app = spawner_app(10, 100, 1.0);
+        app.world_mut()
+            .resource_mut::<Settings>()
+            .max_alive_enemies = CAP;
+
+        // Already at the cap.
+        let alive = (0..CAP)
+            .map(|i| app.spawn_enemy(1.0, Vec3::X * i as f32))
+            .collect::<Vec<_>>();
+
+        app.update();
+        assert_eq!(spawned_count(&mut app), 0);
+        assert_eq!(app.world().resource::<SpawnCount>().0, 100);
+
+        // Free up some room.
+        app.world_mut().despawn(alive[0]);
+        app.world_mut().despawn(alive[1]);
+
+        app.update();
+        assert_eq!(spawned_count(&mut app), 2);
+        assert_eq!(app.world().resource::<SpawnCount>().0, 98);
+    }
+
+    #[test]
+    fn test_group_spawns_at_once() {
+        const SIZE: usize = 5;
+        const RADIUS: f32 = 1.5;
+
+        let mut app = spawner_app(SIZE, SIZE * 2, RADIUS);
+
+        app.update();
+
+        let translations = app
+            .world_mut()
+            .query_filtered::<&Transform, With<SceneRoot>>()
+            .iter(app.world())
+            .map(|transform| transform.translation)
+            .collect::<Vec<_>>();
+
+        assert_eq!(translations.len(), SIZE);
+        assert_eq!(app.world().resource::<SpawnCount>().0, SIZE);
+
+        for (i, a) in translations.iter().enumerate() {
+            assert!(a.length() <= RADIUS);
+
+            for b in translations.iter().skip(i + 1) {
+                assert!(a.distance(*b) > 0.1, "Should not stack.");
+            }
+        }
+    }
+
+    #[test]
+    fn test_manual_start_holds_wave_until_triggered() {
+        let mut app = spawner_app(1, 10, 0.0);
+        app.insert_resource(WaveCountdown(Timer::from_seconds(
+            1.0,
+            TimerMode::Once,
+        )))
+        .add_systems(Update, wave_countdown.before(spawn_enemy))
+        .add_observer(start_wave);
+
+        app.world_mut()
+            .query::<&mut EnemySpawner>()
+            .single_mut(app.world_mut())
+            .unwrap()
+            .start_mode = WaveStartMode::Manual;
+
+        // Well past the countdown.
+        app.step(128);
+        assert_eq!(spawned_count(&mut app), 0);
+        assert!(
+            app.world().resource::<WaveCountdown>().finished()
+                == false
+        );
+
+        app.world_mut().trigger(StartWave);
+        app.update();
+        assert_eq!(spawned_count(&mut app), 1);
+    }
+
+    #[test]
+    fn test_rapid_spawns_do_not_overlap() {
+        // A single enemy at the portal on every update.
+        let mut app = spawner_app(1, 10, 0.0);
+
+        app.step(8);
+        assert_eq!(spawned_count(&mut app), 1, "Portal is occupied.");
+
+        // The first enemy walks away.
+        let first = app
+            .world_mut()
+            .query_filtered::<Entity, With<SceneRoot>>()
+            .single(app.world())
+            .unwrap();
+        app.world_mut()
+            .entity_mut(first)
+            .insert(Transform::from_xyz(2.0, 0.0, 0.0));
+
+        app.step(8);
+        assert_eq!(spawned_count(&mut app), 2);
+
+        let positions = app
+            .world_mut()
+            .query_filtered::<&GlobalTransform, With<SceneRoot>>()
+            .iter(app.world())
+            .map(|t| t.translation())
+            .collect::<Vec<_>>();
+        assert!(
+            positions[0].distance(positions[1])
+                >= EnemySpawner::DEFAULT_SPAWN_SPACING
+        );
+    }
+
+    #[test]
+    fn test_scheduled_wave_spawns_prefabs_in_order() {
+        const WAVE: &str = r#"(
+            countdown: 10.0,
+            groups: [
+                (prefab: "mouse_a", count: 2, interval: 1.0),
+                (prefab: "popcorn", count: 1, interval: 0.0, delay: 0.5),
+            ],
+        )"#;
+
+        let mut app = spawner_app(1, 0, 0.0);
+        let schedule =
+            SpawnSchedule::new(&ron::from_str(WAVE).unwrap());
+        app.insert_resource(SpawnCount(schedule.remaining()))
+            .insert_resource(schedule)
+            .add_systems(Update, spawn_timer.before(spawn_enemy));
+
+        let scene_of = |app: &App, prefab_name| {
+            app.world()
+                .resource::<PrefabAssets>()
+                .get_gltf(
+                    PrefabName::FileName(prefab_name),
+                    app.world().resource::<Assets<Gltf>>(),
+                )
+                .and_then(|gltf| gltf.default_scene.clone())
+                .unwrap()
+        };
+        // Clear the portal for the next spawn.
+        let take_spawned = |app: &mut App| {
+            let spawned = app
+                .world_mut()
+                .query::<(&SceneRoot, Entity)>()
+                .iter(app.world())
+                .map(|(root, entity)| (root.0.clone(), entity))
+                .collect::<Vec<_>>();
+
+            spawned
+                .into_iter()
+                .map(|(scene, entity)| {
+                    app.world_mut().despawn(entity);
+                    scene
+                })
+                .collect::<Vec<_>>()
+        };
+
+        app.update();
+        assert_eq!(
+            take_spawned(&mut app),
+            [scene_of(&app, "mouse_a")]
+        );
+
+        // Half a second in.
+        app.step(31);
+        assert_eq!(
+            take_spawned(&mut app),
+            [scene_of(&app, "popcorn")]
+        );
+
+        app.step(32);
+        assert_eq!(
+            take_spawned(&mut app),
+            [scene_of(&app, "mouse_a")]
+        );
+        assert_eq!(app.world().resource::<SpawnCount>().0, 0);
+    }
+
+    #[test]
+    fn test_unknown_scheduled_prefab_is_skipped() {
+        const WAVE: &str = r#"(
+            countdown: 10.0,
+            groups: [
+                (prefab: "mouse_b", count: 1, interval: 0.0),
+                (prefab: "mouse_a", count: 1, interval: 0.0, delay: 0.5),
+            ],
+        )"#;
+
+        let mut app = spawner_app(1, 0, 0.0);
+        let schedule =
+            SpawnSchedule::new(&ron::from_str(WAVE).unwrap());
+        app.insert_resource(SpawnCount(schedule.remaining()))
+            .insert_resource(schedule)
+            .add_systems(Update, spawn_timer.before(spawn_enemy));
+
+        // Never counts more than what is left to spawn.
+        let in_sync = |app: &App| {
+            app.world().resource::<SpawnCount>().0
+                == app.world().resource::<SpawnSchedule>().remaining()
+        };
+
+        app.update();
+        assert_eq!(spawned_count(&mut app), 0);
+        assert!(in_sync(&app));
+
+        app.step(32);
+        assert_eq!(spawned_count(&mut app), 1);
+        assert!(in_sync(&app));
+        assert_eq!(app.world().resource::<SpawnCount>().0, 0);
+    }
+
+    #[test]
+    fn test_scheduled_wave_danger_matches_its_spawner_wave() {
+        const WAVE: &str = r#"(
+            countdown: 20.0,
+            groups: [(prefab: "mouse_a", count: 10, interval: 1.0)],
+        )"#;
+        const HEAVY_WAVE: &str = r#"(
+            countdown: 20.0,
+            groups: [
+                (prefab: "mouse_a", count: 30, interval: 0.5),
+                (prefab: "mouse_b", count: 30, interval: 0.5),
+            ],
+        )"#;
+
+        let wave = ron::from_str::<WaveSpawns>(WAVE).unwrap();
+        let heavy_wave =
+            ron::from_str::<WaveSpawns>(HEAVY_WAVE).unwrap();
+        let config = WaveConfig {
+            countdown: 20.0,
+            enemy_count: 10,
+            spawn_interval: 1.0,
+            formation: Formation::Trickle,
+        };
+
+        assert_eq!(wave.danger(1.0), config.danger(1.0));
+        assert!(
+            heavy_wave.danger_level(1.0) > wave.danger_level(1.0)
+        );
+    }
+
+    #[test]
+    fn test_heavier_wave_is_more_dangerous() {
+        let light = WaveConfig {
+            countdown: 20.0,
+            enemy_count: 10,
+            spawn_interval: 1.0,
+            formation: Formation::Trickle,
+        };
+        let heavy = WaveConfig {
+            countdown: 60.0,
+            enemy_count: 60,
+            spawn_interval: 0.5,
+            formation: Formation::Trickle,
+        };
+
+        let light_level = light.danger_level(1.0);
+        let heavy_level = heavy.danger_level(1.0);
+        assert!(
+            heavy_level > light_level,
+            "Expected {heavy_level} > {light_level}."
+        );
+        assert!(
+            (1..=WaveConfig::MAX_DANGER_LEVEL).contains(&heavy_level)
+        );
+
+        // Tougher enemies raise the danger of the same wave.
+        assert!(light.danger_level(2.0) > light_level);
+        assert!(
+            heavy.danger_level(100.0) == WaveConfig::MAX_DANGER_LEVEL
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use bevy::asset::{AssetLoader, io::Reader};
+use bevy::asset::{AsyncReadExt, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::asset_pipeline::{CurrentScene, LevelId};
+
+use super::spawner::{SpawnWave, WaveConfig};
+
+/// Plugin to load the waves of each level
+/// from "levels.waves.ron".
+pub(super) struct WavesPlugin;
+
+impl Plugin for WavesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<WavesAsset>()
+            .init_asset_loader::<WavesAssetLoader>();
+
+        app.add_systems(PreStartup, load_waves);
+    }
+}
+
+/// Startup system: load "levels.waves.ron".
+fn load_waves(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(WavesAssetHandle(
+        asset_server.load("levels.waves.ron"),
+    ));
+}
+
+/// Map of level id to its waves, the first entry is
+/// [`SpawnWave::One`].
+#[derive(Asset, TypePath, Deref, Debug, Clone, Deserialize)]
+pub struct WavesAsset(HashMap<String, Vec<WaveSpawns>>);
+
+/// Timed spawns of a single wave - loaded from RON files.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WaveSpawns {
+    /// How long before the wave starts.
+    pub countdown: f32,
+    pub groups: Vec<SpawnGroup>,
+}
+
+impl WaveSpawns {
+    /// Enemy health the wave sends at the players, weighted by
+    /// how fast the enemies come, see [`WaveConfig::danger`].
+    pub fn danger(&self, health_multiplier: f32) -> f32 {
+        let enemy_count = self
+            .groups
+            .iter()
+            .map(|group| group.count)
+            .sum::<usize>();
+        // Groups spawn alongside each other.
+        let spawn_rate = self
+            .groups
+            .iter()
+            .map(|group| 1.0 / group.interval.max(0.1))
+            .sum::<f32>();
+
+        enemy_count as f32 * health_multiplier * spawn_rate.sqrt()
+    }
+
+    /// [`WaveSpawns::danger`] from 1 to
+    /// [`WaveConfig::MAX_DANGER_LEVEL`].
+    pub fn danger_level(&self, health_multiplier: f32) -> u8 {
+        WaveConfig::level_of_danger(self.danger(health_multiplier))
+    }
+}
+
+/// A batch of the same enemy within a [`WaveSpawns`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpawnGroup {
+    /// File name of the enemy prefab.
+    pub prefab: String,
+    pub count: usize,
+    /// Time in between spawns of the group.
+    pub interval: f32,
+    /// Time after the wave started before the first spawn.
+    #[serde(default)]
+    pub delay: f32,
+}
+
+impl SpawnGroup {
+    /// Time of every spawn, relative to the start of the wave.
+    pub fn spawn_times(&self) -> impl Iterator<Item = f32> {
+        (0..self.count).map(|i| self.delay + self.interval * i as f32)
+    }
+}
+
+#[derive(Resource)]
+pub struct WavesAssetHandle(pub Handle<WavesAsset>);
+
+#[derive(SystemParam)]
+pub struct WavesRegistry<'w> {
+    pub handle: Res<'w, WavesAssetHandle>,
+    pub assets: Res<'w, Assets<WavesAsset>>,
+}
+
+impl WavesRegistry<'_> {
+    pub fn get(&self) -> Option<&WavesAsset> {
+        self.assets.get(&self.handle.0)
+    }
+
+    pub fn get_wave(
+        &self,
+        level_id: &str,
+        wave: &SpawnWave,
+    ) -> Option<&WaveSpawns> {
+        self.get()?.get(level_id)?.get(wave.index()?)
+    }
+}
+
+/// The [`WaveSpawns`] of the current level.
+#[derive(SystemParam)]
+pub struct LevelWaves<'w, 's> {
+    registry: WavesRegistry<'w>,
+    current_scene: Res<'w, CurrentScene>,
+    q_level_ids: Query<'w, 's, &'static LevelId>,
+}
+
+impl LevelWaves<'_, '_> {
+    /// Levels without an entry fall back to the waves
+    /// of their [`EnemySpawner`][super::spawner::EnemySpawner].
+    pub fn get(&self, wave: &SpawnWave) -> Option<&WaveSpawns> {
+        let level_id =
+            self.q_level_ids.get(self.current_scene.get()?).ok()?;
+
+        self.registry.get_wave(level_id.0, wave)
+    }
+}
+
+#[derive(Default)]
+pub struct WavesAssetLoader;
+
+impl AssetLoader for WavesAssetLoader {
+    type Asset = WavesAsset;
+
+    type Settings = ();
+
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut ron_str = String::new();
+        reader.read_to_string(&mut ron_str).await?;
+
+        // Hot reloads keep the previous asset on a typo.
+        ron::from_str::<WavesAsset>(&ron_str).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["waves.ron"]
+    }
+}
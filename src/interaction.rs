@@ -7,7 +7,12 @@ use bevy_mod_outline::{
 
 mod grab;
 
+use crate::camera_controller::split_screen::{
+    CameraType, QueryCameras,
+};
 use crate::physics::GameLayer;
+use crate::player::PlayerType;
+use crate::settings::Settings;
 
 const MARK_COLOR: Color = Color::Srgba(SKY_300);
 // const GRABBED_COLOR: Color = Color::Srgba(EMERALD_500);
@@ -33,17 +38,23 @@ impl Plugin for InteractionPlugin {
     }
 }
 
+/// Mark the interactable that the player is aiming at through the
+/// center of their camera, falling back to the nearest one in range.
+///
+/// Runs every frame as the camera can turn while the player stands still.
 fn detect_interactables(
     mut commands: Commands,
     mut q_players: Query<
-        (&InteractionPlayer, Entity),
-        (Changed<GlobalTransform>, Without<Occupied>),
+        (&InteractionPlayer, Option<&PlayerType>, Entity),
+        Without<Occupied>,
     >,
     q_global_transforms: Query<&GlobalTransform>,
     q_collider_ofs: Query<&ColliderOf>,
+    q_cameras: QueryCameras<&GlobalTransform, ()>,
     spatial_query: SpatialQuery,
+    settings: Res<Settings>,
 ) -> Result {
-    for (player, entity) in q_players.iter_mut() {
+    for (player, player_type, entity) in q_players.iter_mut() {
         let player_transform =
             q_global_transforms.get(entity).map_err(|_|
                 "`InteractionPlayer` should have a global transform!",
@@ -55,7 +66,7 @@ fn detect_interactables(
             &Collider::sphere(player.range),
             player_translation,
             Quat::IDENTITY,
-            &SpatialQueryFilter::from_mask(GameLayer::Interactable),
+            &GameLayer::Interactable.query_filter(),
         );
 
         // No items around.
@@ -111,7 +122,37 @@ fn detect_interactables(
             }
         }
 
-        let mut marked_entity = item_entities[closest_idx];
+        let aimed_entity = player_type
+            .filter(|_| settings.interaction_camera_ray)
+            .and_then(|player_type| {
+                let camera_type = match player_type {
+                    PlayerType::A => CameraType::A,
+                    PlayerType::B => CameraType::B,
+                };
+                q_cameras.get(camera_type).ok()
+            })
+            .and_then(|camera_transform| {
+                // The crosshair sits at the center of the viewport,
+                // which is along the camera's forward axis.
+                let origin = camera_transform.translation();
+                let max_distance = origin
+                    .distance(player_translation)
+                    + player.range;
+
+                spatial_query.cast_ray(
+                    origin,
+                    camera_transform.forward(),
+                    max_distance,
+                    true,
+                    &GameLayer::Interactable.query_filter(),
+                )
+            })
+            .map(|hit| hit.entity)
+            // Must still be within the interaction range.
+            .filter(|hit_entity| item_entities.contains(hit_entity));
+
+        let mut marked_entity =
+            aimed_entity.unwrap_or(item_entities[closest_idx]);
         // Use the rigidbody's entity as the reference point.
         marked_entity = q_collider_ofs
             .get(marked_entity)
@@ -217,3 +258,88 @@ pub struct InteractionPlayer {
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct Occupied;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::combat_app;
+
+    fn interaction_app() -> (App, Entity) {
+        let mut app = combat_app();
+        app.init_resource::<Settings>()
+            .add_systems(Update, detect_interactables);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                InteractionPlayer {
+                    range: 3.0,
+                    boundary_range: 0.5,
+                },
+                PlayerType::A,
+                Transform::default(),
+            ))
+            .id();
+
+        (app, player)
+    }
+
+    fn spawn_interactable(
+        app: &mut App,
+        translation: Vec3,
+    ) -> Entity {
+        app.world_mut()
+            .spawn((
+                Interactable,
+                RigidBody::Static,
+                Collider::sphere(0.3),
+                Transform::from_translation(translation),
+            ))
+            .id()
+    }
+
+    fn marked(app: &App, player: Entity) -> Option<Entity> {
+        app.world().get::<MarkerOf>(player).map(|marker| **marker)
+    }
+
+    #[test]
+    fn test_camera_ray_selects_aimed_interactable() {
+        let (mut app, player) = interaction_app();
+
+        let closer = spawn_interactable(&mut app, Vec3::X);
+        let aimed = spawn_interactable(&mut app, Vec3::NEG_Z * 2.0);
+
+        // Behind the player, looking forward.
+        app.world_mut().spawn((
+            CameraType::A,
+            Transform::from_xyz(0.0, 0.0, 3.0)
+                .looking_to(Vec3::NEG_Z, Vec3::Y),
+        ));
+
+        app.update();
+        app.update();
+
+        assert_ne!(marked(&app, player), Some(closer));
+        assert_eq!(marked(&app, player), Some(aimed));
+    }
+
+    #[test]
+    fn test_falls_back_to_nearest_without_aim() {
+        let (mut app, player) = interaction_app();
+
+        let closer = spawn_interactable(&mut app, Vec3::X);
+        spawn_interactable(&mut app, Vec3::NEG_Z * 2.0);
+
+        // Looking up at the sky.
+        app.world_mut().spawn((
+            CameraType::A,
+            Transform::from_xyz(0.0, 0.0, 3.0)
+                .looking_to(Vec3::Y, Vec3::Z),
+        ));
+
+        app.update();
+        app.update();
+
+        assert_eq!(marked(&app, player), Some(closer));
+    }
+}
@@ -1,4 +1,6 @@
 use crate::physics::GameLayer;
+use crate::player::currency::Currency;
+use crate::player::economy::EconomyConfig;
 use crate::{
     character_controller::CharacterController,
     machine::recipe::RecipeMeta,
@@ -9,6 +11,8 @@ use item::{ItemRegistry, ItemType};
 
 mod inventory_input;
 pub mod item;
+pub mod loadout;
+pub mod starting_inventory;
 
 pub(super) struct InventoryPlugin;
 
@@ -17,6 +21,8 @@ impl Plugin for InventoryPlugin {
         app.add_plugins((
             inventory_input::InventoryInputPlugin,
             item::ItemPlugin,
+            loadout::LoadoutPlugin,
+            starting_inventory::StartingInventoryPlugin,
         ))
         .add_observer(handle_item_collection)
         .add_systems(Update, detect_item_collisions);
@@ -98,6 +104,8 @@ fn handle_item_collection(
     q_items: Query<&Item>,
     q_players: Query<Entity, With<CharacterController>>,
     item_registry: ItemRegistry,
+    economy: Res<EconomyConfig>,
+    mut currency: ResMut<Currency>,
 ) {
     let Some(item_meta_asset) = item_registry.get() else {
         return;
@@ -180,6 +188,16 @@ fn handle_item_collection(
         );
 
         // Remove the item from the world
+        commands.entity(item_entity).despawn();
+    } else if item_meta.item_type == ItemType::Ingredient {
+        // Surplus ingredients are converted into currency.
+        let amount = economy.corn_currency(collected_quantity);
+        currency.0 += amount;
+        info!(
+            "Player {:?} converted {}x {} into {} currency",
+            player_entity, collected_quantity, item_id, amount
+        );
+
         commands.entity(item_entity).despawn();
     } else {
         // TODO: Handle stack overflow
@@ -206,6 +224,9 @@ pub struct Inventory {
     ingredients: HashMap<String, u32>,
     /// Currently selected tower for placement (if any)
     pub selected_tower: Option<String>,
+    /// Tower ids shown first and in this order, see
+    /// [`loadout::Loadout`].
+    pub hotbar: Vec<String>,
 }
 
 impl Inventory {
@@ -322,6 +343,36 @@ impl Inventory {
     pub fn towers(&self) -> &HashMap<String, u32> {
         &self.towers
     }
+
+    /// Ids of the available towers, the [`Self::hotbar`] slots
+    /// first followed by the rest sorted alphabetically.
+    pub fn tower_order(&self) -> Vec<String> {
+        let is_available = |tower_id: &String| {
+            self.towers.get(tower_id).is_some_and(|count| *count > 0)
+        };
+
+        let mut order = Vec::new();
+        for tower_id in self.hotbar.iter() {
+            if is_available(tower_id)
+                && order.contains(tower_id) == false
+            {
+                order.push(tower_id.clone());
+            }
+        }
+
+        let mut rest = self
+            .towers
+            .keys()
+            .filter(|id| {
+                is_available(id) && order.contains(id) == false
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        rest.sort();
+
+        order.extend(rest);
+        order
+    }
 }
 
 /// Core data for any item (both towers and ingredients).
@@ -39,13 +39,7 @@ fn cycle_tower_selection_for_player(
     inventory: &mut Inventory,
 ) {
     // Get available towers
-    let mut available_towers: Vec<String> = inventory
-        .towers
-        .iter()
-        .filter(|(_, count)| **count > 0)
-        .map(|(id, _)| id.clone())
-        .collect();
-    available_towers.sort();
+    let available_towers = inventory.tower_order();
 
     // No towers available will clear selection
     if available_towers.is_empty() {
@@ -62,7 +62,7 @@ impl ItemMeta {
 }
 
 #[derive(Resource)]
-pub struct ItemMetaAssetHandle(Handle<ItemMetaAsset>);
+pub struct ItemMetaAssetHandle(pub Handle<ItemMetaAsset>);
 
 #[derive(SystemParam)]
 pub struct ItemRegistry<'w> {
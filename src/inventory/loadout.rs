@@ -0,0 +1,295 @@
+use std::collections::BTreeMap;
+
+use bevy::asset::{AssetLoader, io::Reader};
+use bevy::asset::{AsyncReadExt, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::asset_pipeline::LevelId;
+
+use super::Inventory;
+use super::item::{ItemMetaAsset, ItemRegistry, ItemType};
+
+/// Plugin to handle the tower loadout presets of the hotbar.
+pub(super) struct LoadoutPlugin;
+
+impl Plugin for LoadoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<LoadoutsAsset>()
+            .init_asset_loader::<LoadoutsAssetLoader>()
+            .init_resource::<SelectedLoadout>();
+
+        app.add_systems(PreStartup, load_loadouts)
+            .add_systems(Update, apply_loadout);
+    }
+}
+
+/// Path of the loadouts file, relative to the assets folder.
+pub const LOADOUTS_PATH: &str = "player.loadouts.ron";
+
+/// Startup system: load "player.loadouts.ron" and insert as a resource.
+fn load_loadouts(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(LoadoutsAssetHandle(
+        asset_server.load(LOADOUTS_PATH),
+    ));
+}
+
+/// Fill the hotbar of every player within a loaded level
+/// with the towers of the [`SelectedLoadout`].
+///
+/// Waits until the scene has been instantiated and both registries
+/// have been loaded, and only applies once per level.
+fn apply_loadout(
+    mut commands: Commands,
+    q_levels: Query<Entity, (With<LevelId>, Without<LoadoutApplied>)>,
+    q_children: Query<&Children>,
+    mut q_inventories: Query<&mut Inventory>,
+    loadout_registry: LoadoutRegistry,
+    item_registry: ItemRegistry,
+    selected_loadout: Res<SelectedLoadout>,
+) {
+    if loadout_registry.get().is_none() {
+        return;
+    }
+    let Some(items) = item_registry.get() else {
+        return;
+    };
+
+    for entity in q_levels.iter() {
+        let players = q_children
+            .iter_descendants(entity)
+            .filter(|e| q_inventories.contains(*e))
+            .collect::<Vec<_>>();
+
+        // Scene has not been instantiated yet.
+        if players.is_empty() {
+            continue;
+        }
+
+        commands.entity(entity).insert(LoadoutApplied);
+
+        let Some(loadout) = selected_loadout
+            .0
+            .as_ref()
+            .and_then(|name| loadout_registry.get_loadout(name))
+        else {
+            continue;
+        };
+
+        let hotbar = loadout.valid_towers(items);
+
+        for player in players {
+            if let Ok(mut inventory) = q_inventories.get_mut(player) {
+                inventory.hotbar = hotbar.clone();
+            }
+        }
+    }
+}
+
+/// Marks a level whose loadout has been applied to the hotbars.
+#[derive(Component, Debug)]
+struct LoadoutApplied;
+
+/// Name of the loadout applied on level start, if any.
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SelectedLoadout(pub Option<String>);
+
+/// Map of loadout name to the [`Loadout`].
+#[derive(
+    Asset,
+    TypePath,
+    Deref,
+    DerefMut,
+    Default,
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+)]
+pub struct LoadoutsAsset(pub BTreeMap<String, Loadout>);
+
+impl LoadoutsAsset {
+    /// Insert a loadout under the first free "Loadout <n>" name
+    /// and return the name.
+    pub fn insert_new(&mut self, loadout: Loadout) -> String {
+        let name = (1..)
+            .map(|n| format!("Loadout {n}"))
+            .find(|name| self.contains_key(name) == false)
+            .unwrap();
+
+        self.insert(name.clone(), loadout);
+        name
+    }
+
+    /// Write the loadouts back to [`LOADOUTS_PATH`].
+    ///
+    /// Loadouts only live for the session on the web.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self) -> Result {
+        let ron_str = ron::ser::to_string_pretty(
+            self,
+            ron::ser::PrettyConfig::default(),
+        )?;
+        std::fs::write(format!("assets/{LOADOUTS_PATH}"), ron_str)?;
+
+        Ok(())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self) -> Result {
+        Ok(())
+    }
+}
+
+/// A preset of towers that fills the hotbar slots in order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Loadout {
+    /// Tower item ids, must exist in "items.item_meta.ron".
+    pub towers: Vec<String>,
+}
+
+impl Loadout {
+    /// The towers of this loadout that are valid tower items,
+    /// in order.
+    pub fn valid_towers(&self, items: &ItemMetaAsset) -> Vec<String> {
+        self.towers
+            .iter()
+            .filter(|tower_id| {
+                let is_tower = items.get(*tower_id).is_some_and(
+                    |item_meta| item_meta.item_type == ItemType::Tower,
+                );
+
+                if is_tower == false {
+                    error!(
+                        "Loadout tower '{}' not found in item registry!",
+                        tower_id
+                    );
+                }
+
+                is_tower
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+#[derive(Resource)]
+pub struct LoadoutsAssetHandle(pub Handle<LoadoutsAsset>);
+
+#[derive(SystemParam)]
+pub struct LoadoutRegistry<'w> {
+    pub handle: Res<'w, LoadoutsAssetHandle>,
+    pub assets: Res<'w, Assets<LoadoutsAsset>>,
+}
+
+impl LoadoutRegistry<'_> {
+    pub fn get(&self) -> Option<&LoadoutsAsset> {
+        self.assets.get(&self.handle.0)
+    }
+
+    pub fn get_loadout(&self, name: &str) -> Option<&Loadout> {
+        self.get()?.get(name)
+    }
+}
+
+#[derive(Default)]
+pub struct LoadoutsAssetLoader;
+
+impl AssetLoader for LoadoutsAssetLoader {
+    type Asset = LoadoutsAsset;
+
+    type Settings = ();
+
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut ron_str = String::new();
+        reader.read_to_string(&mut ron_str).await?;
+
+        // Hot reloads keep the previous asset on a typo.
+        ron::from_str::<LoadoutsAsset>(&ron_str).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["loadouts.ron"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::inventory::item::ItemMetaAssetHandle;
+
+    const ITEMS: &str = r#"({
+        "corn": (
+            icon_path: "icons/corn.png",
+            prefab_name: "corn",
+            max_stack_size: 30,
+            item_type: ingredient,
+        ),
+        "gun_tower": (
+            icon_path: "icons/gun_tower.png",
+            prefab_name: "gun_tower",
+            max_stack_size: 10,
+            item_type: tower,
+        ),
+        "cannon_tower": (
+            icon_path: "icons/cannon_tower.png",
+            prefab_name: "cannon_tower",
+            max_stack_size: 10,
+            item_type: tower,
+        ),
+    })"#;
+
+    const LOADOUTS: &str = r#"({
+        "Defense": (
+            towers: ["cannon_tower", "corn", "missing", "gun_tower"],
+        ),
+    })"#;
+
+    #[test]
+    fn test_loadout_populates_hotbar_in_order() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<ItemMetaAsset>()
+            .init_asset::<LoadoutsAsset>()
+            .insert_resource(SelectedLoadout(Some(
+                "Defense".to_string(),
+            )))
+            .add_systems(Update, apply_loadout);
+
+        let items = app
+            .world_mut()
+            .resource_mut::<Assets<ItemMetaAsset>>()
+            .add(ron::from_str::<ItemMetaAsset>(ITEMS).unwrap());
+        let loadouts = app
+            .world_mut()
+            .resource_mut::<Assets<LoadoutsAsset>>()
+            .add(ron::from_str::<LoadoutsAsset>(LOADOUTS).unwrap());
+        app.insert_resource(ItemMetaAssetHandle(items))
+            .insert_resource(LoadoutsAssetHandle(loadouts));
+
+        let level = app.world_mut().spawn(LevelId("level1")).id();
+        let player = app
+            .world_mut()
+            .spawn((Inventory::default(), ChildOf(level)))
+            .id();
+
+        app.update();
+
+        let inventory = app.world().get::<Inventory>(player).unwrap();
+        // Invalid entries are skipped, the order is kept.
+        assert_eq!(inventory.hotbar, ["cannon_tower", "gun_tower"]);
+    }
+}
@@ -0,0 +1,269 @@
+use bevy::asset::{AssetLoader, io::Reader};
+use bevy::asset::{AsyncReadExt, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::asset_pipeline::LevelId;
+
+use super::Inventory;
+use super::item::{ItemRegistry, ItemType};
+
+/// Plugin to handle level metadata loading and applying the
+/// starting inventory of each level.
+pub(super) struct StartingInventoryPlugin;
+
+impl Plugin for StartingInventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<LevelMetaAsset>()
+            .init_asset_loader::<LevelMetaAssetLoader>();
+
+        app.add_systems(PreStartup, load_level_registry)
+            .add_systems(Update, apply_starting_inventory);
+    }
+}
+
+/// Startup system: load "levels.level_meta.ron" and insert as a resource.
+fn load_level_registry(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(LevelMetaAssetHandle(
+        asset_server.load("levels.level_meta.ron"),
+    ));
+}
+
+/// Fill the inventory of every player within a loaded level
+/// with the level's starting inventory.
+///
+/// Waits until the scene has been instantiated and both registries
+/// have been loaded, and only applies once per level.
+fn apply_starting_inventory(
+    mut commands: Commands,
+    q_levels: Query<
+        (&LevelId, Entity),
+        Without<StartingInventoryApplied>,
+    >,
+    q_children: Query<&Children>,
+    mut q_inventories: Query<&mut Inventory>,
+    level_registry: LevelRegistry,
+    item_registry: ItemRegistry,
+) {
+    if level_registry.get().is_none() {
+        return;
+    }
+    let Some(items) = item_registry.get() else {
+        return;
+    };
+
+    for (level_id, entity) in q_levels.iter() {
+        let players = q_children
+            .iter_descendants(entity)
+            .filter(|e| q_inventories.contains(*e))
+            .collect::<Vec<_>>();
+
+        // Scene has not been instantiated yet.
+        if players.is_empty() {
+            continue;
+        }
+
+        commands.entity(entity).insert(StartingInventoryApplied);
+
+        // Levels without an entry start empty.
+        let Some(level_meta) = level_registry.get_level(level_id)
+        else {
+            continue;
+        };
+
+        for (item_id, &quantity) in
+            level_meta.starting_inventory.iter()
+        {
+            let Some(item_meta) = items.get(item_id) else {
+                error!(
+                    "Starting item '{}' of level '{}' not found in item registry!",
+                    item_id, **level_id
+                );
+                continue;
+            };
+
+            for &player in players.iter() {
+                let Ok(mut inventory) = q_inventories.get_mut(player)
+                else {
+                    continue;
+                };
+
+                let success = match item_meta.item_type {
+                    ItemType::Ingredient => inventory.add_ingredient(
+                        item_id.clone(),
+                        quantity,
+                        item_meta.max_stack_size,
+                    ),
+                    ItemType::Tower => inventory.add_tower(
+                        item_id.clone(),
+                        quantity,
+                        item_meta.max_stack_size,
+                    ),
+                };
+
+                if success == false {
+                    warn!(
+                        "Could not start with {}x {}: would exceed max stack size ({})",
+                        quantity, item_id, item_meta.max_stack_size
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Marks a level whose starting inventory has been handed out.
+#[derive(Component, Debug)]
+struct StartingInventoryApplied;
+
+#[derive(Asset, TypePath, Deref, Debug, Clone, Deserialize)]
+pub struct LevelMetaAsset(HashMap<String, LevelMeta>);
+
+/// Metadata for each level - loaded from RON files.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LevelMeta {
+    /// Map of item ID to the quantity every player starts with.
+    #[serde(default)]
+    pub starting_inventory: HashMap<String, u32>,
+}
+
+#[derive(Resource)]
+pub struct LevelMetaAssetHandle(pub Handle<LevelMetaAsset>);
+
+#[derive(SystemParam)]
+pub struct LevelRegistry<'w> {
+    pub handle: Res<'w, LevelMetaAssetHandle>,
+    pub assets: Res<'w, Assets<LevelMetaAsset>>,
+}
+
+impl LevelRegistry<'_> {
+    pub fn get(&self) -> Option<&LevelMetaAsset> {
+        self.assets.get(&self.handle.0)
+    }
+
+    pub fn get_level(&self, level_id: &str) -> Option<&LevelMeta> {
+        self.get()?.get(level_id)
+    }
+}
+
+#[derive(Default)]
+pub struct LevelMetaAssetLoader;
+
+impl AssetLoader for LevelMetaAssetLoader {
+    type Asset = LevelMetaAsset;
+
+    type Settings = ();
+
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut ron_str = String::new();
+        reader.read_to_string(&mut ron_str).await?;
+
+        // Hot reloads keep the previous asset on a typo.
+        ron::from_str::<LevelMetaAsset>(&ron_str).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["level_meta.ron"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::inventory::item::{
+        ItemMetaAsset, ItemMetaAssetHandle,
+    };
+
+    const ITEMS: &str = r#"({
+        "corn": (
+            icon_path: "icons/corn.png",
+            prefab_name: "corn",
+            max_stack_size: 30,
+            item_type: ingredient,
+        ),
+        "gun_tower": (
+            icon_path: "icons/gun_tower.png",
+            prefab_name: "gun_tower",
+            max_stack_size: 10,
+            item_type: tower,
+        ),
+    })"#;
+
+    const LEVELS: &str = r#"({
+        "level1": (
+            starting_inventory: {
+                "gun_tower": 2,
+                "corn": 5,
+                "missing": 1,
+            },
+        ),
+        "level2": (),
+    })"#;
+
+    #[test]
+    fn test_level_starting_inventory_populates_players() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<ItemMetaAsset>()
+            .init_asset::<LevelMetaAsset>()
+            .add_systems(Update, apply_starting_inventory);
+
+        let items = app
+            .world_mut()
+            .resource_mut::<Assets<ItemMetaAsset>>()
+            .add(ron::from_str::<ItemMetaAsset>(ITEMS).unwrap());
+        let levels = app
+            .world_mut()
+            .resource_mut::<Assets<LevelMetaAsset>>()
+            .add(ron::from_str::<LevelMetaAsset>(LEVELS).unwrap());
+        app.insert_resource(ItemMetaAssetHandle(items))
+            .insert_resource(LevelMetaAssetHandle(levels));
+
+        let level1 = app.world_mut().spawn(LevelId("level1")).id();
+        // Not instantiated yet, nothing to apply to.
+        app.update();
+
+        let players = [(); 2].map(|_| {
+            app.world_mut()
+                .spawn((Inventory::default(), ChildOf(level1)))
+                .id()
+        });
+        let level2 = app.world_mut().spawn(LevelId("level2")).id();
+        let empty_player = app
+            .world_mut()
+            .spawn((Inventory::default(), ChildOf(level2)))
+            .id();
+
+        app.update();
+        // Applied only once.
+        app.update();
+
+        for player in players {
+            let inventory =
+                app.world().get::<Inventory>(player).unwrap();
+            assert_eq!(inventory.towers().get("gun_tower"), Some(&2));
+            assert_eq!(inventory.ingredients().get("corn"), Some(&5));
+            assert!(inventory.towers().get("missing").is_none());
+            assert!(inventory.ingredients().get("missing").is_none());
+        }
+
+        let inventory =
+            app.world().get::<Inventory>(empty_player).unwrap();
+        assert!(inventory.towers().is_empty());
+        assert!(inventory.ingredients().is_empty());
+    }
+}
@@ -5,21 +5,58 @@ mod asset_pipeline;
 mod audio;
 mod camera_controller;
 mod character_controller;
+pub mod difficulty;
 mod enemy;
 mod interaction;
 mod inventory;
 mod machine;
 mod physics;
 mod player;
+pub mod rng;
+pub mod settings;
+#[cfg(test)]
+mod test_util;
 mod tile;
 mod tower;
+mod tutorial;
 pub mod ui;
 mod util;
 
-pub struct AppPlugin;
+pub use player::PossessorType;
+// Used by the gun tower animation example.
+#[cfg(feature = "dev")]
+pub use asset_pipeline::animation_pipeline::AnimationGraphMap;
+#[cfg(feature = "dev")]
+pub use tower::animation::shoot_animation;
+#[cfg(feature = "dev")]
+pub use tower::tower_attack::{Tower, TowerFired};
+
+use difficulty::Difficulty;
+use rng::GameRng;
+use settings::Settings;
+use ui::Screen;
+
+#[derive(Default)]
+pub struct AppPlugin {
+    pub config: AppPluginConfig,
+}
+
+impl AppPlugin {
+    pub fn new(config: AppPluginConfig) -> Self {
+        Self { config }
+    }
+}
 
 impl Plugin for AppPlugin {
     fn build(&self, app: &mut App) {
+        // Must be inserted before the sub plugins initialize their states.
+        app.insert_resource(self.config.clone())
+            .insert_resource(self.config.difficulty)
+            .register_type::<Difficulty>()
+            .init_resource::<GameRng>()
+            .init_resource::<Settings>()
+            .register_type::<Settings>();
+
         app.add_plugins((
             bevy_framepace::FramepacePlugin,
             bevy_skein::SkeinPlugin::default(),
@@ -39,14 +76,71 @@ impl Plugin for AppPlugin {
             tower::TowerPlugin,
             tile::TilePlugin,
             enemy::EnemyPlugin,
+            tutorial::TutorialPlugin,
         ));
 
         #[cfg(feature = "dev")]
-        app.add_plugins((
-            bevy_inspector_egui::bevy_egui::EguiPlugin {
-                enable_multipass_for_primary_context: true,
-            },
-            bevy_inspector_egui::quick::WorldInspectorPlugin::new(),
-        ));
+        if self.config.dev_tools {
+            app.add_plugins((
+                bevy_inspector_egui::bevy_egui::EguiPlugin {
+                    enable_multipass_for_primary_context: true,
+                },
+                bevy_inspector_egui::quick::WorldInspectorPlugin::new(
+                ),
+                tower::targeting_inspector::TargetingInspectorPlugin,
+            ));
+        }
+    }
+}
+
+/// Configuration of the [`AppPlugin`], allowing the game to be
+/// launched in a specific state (e.g. straight into a level).
+#[derive(Resource, Debug, Clone)]
+pub struct AppPluginConfig {
+    /// Possessors of player A and B respectively.
+    ///
+    /// The possession screen will be skipped if this is set.
+    pub possessors: Option<[PossessorType; 2]>,
+    pub difficulty: Difficulty,
+    /// The [`Screen`] to enter once all assets are loaded.
+    pub start_screen: Screen,
+    /// Enable the world inspector (requires the `dev` feature).
+    pub dev_tools: bool,
+}
+
+impl AppPluginConfig {
+    pub fn with_possessors(
+        mut self,
+        player_a: PossessorType,
+        player_b: PossessorType,
+    ) -> Self {
+        self.possessors = Some([player_a, player_b]);
+        self
+    }
+
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    pub fn with_start_screen(mut self, start_screen: Screen) -> Self {
+        self.start_screen = start_screen;
+        self
+    }
+
+    pub fn with_dev_tools(mut self, dev_tools: bool) -> Self {
+        self.dev_tools = dev_tools;
+        self
+    }
+}
+
+impl Default for AppPluginConfig {
+    fn default() -> Self {
+        Self {
+            possessors: None,
+            difficulty: Difficulty::default(),
+            start_screen: Screen::default(),
+            dev_tools: cfg!(feature = "dev"),
+        }
     }
 }
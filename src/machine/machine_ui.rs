@@ -1,12 +1,10 @@
 use bevy::color::palettes::tailwind::*;
 use bevy::prelude::*;
 
-use crate::camera_controller::split_screen::{
-    CameraType, QueryCameras,
-};
 use crate::interaction::MarkerPlayers;
 use crate::inventory::item::ItemRegistry;
 use crate::player::PlayerType;
+use crate::ui::player_ui::PlayerUi;
 use crate::ui::widgets::progress_bar::ProgressBar;
 use crate::ui::world_space::WorldUi;
 
@@ -30,13 +28,9 @@ impl Plugin for MachineUiPlugin {
 fn setup_machine_ui(
     trigger: Trigger<OnAdd, Machine>,
     mut commands: Commands,
-    q_cameras: QueryCameras<Entity>,
-) -> Result {
+) {
     let entity = trigger.target();
 
-    let camera_a = q_cameras.get(CameraType::A)?;
-    let camera_b = q_cameras.get(CameraType::B)?;
-
     fn ui_bundle(machine_entity: Entity) -> impl Bundle {
         (
             WorldUi::new(machine_entity)
@@ -64,12 +58,10 @@ fn setup_machine_ui(
         )
     }
 
-    // Create UI for both cameras
-    commands.spawn((ui_bundle(entity), UiTargetCamera(camera_a)));
-
-    commands.spawn((ui_bundle(entity), UiTargetCamera(camera_b)));
+    // Create UI for both players.
+    commands.spawn((ui_bundle(entity), PlayerUi(PlayerType::A)));
 
-    Ok(())
+    commands.spawn((ui_bundle(entity), PlayerUi(PlayerType::B)));
 }
 
 /// Set visibility of machine ui based on whether it is marked
@@ -79,8 +71,7 @@ fn machine_ui_visibility(
         (Option<&MarkerPlayers>, &MachineUis),
         With<Machine>,
     >,
-    q_target_cameras: Query<&UiTargetCamera>,
-    q_camera_types: Query<&CameraType>,
+    q_player_uis: Query<&PlayerUi>,
     q_player_types: Query<&PlayerType>,
     mut q_viz: Query<&mut Visibility>,
 ) -> Result {
@@ -94,15 +85,7 @@ fn machine_ui_visibility(
         }
 
         for ui in uis.iter() {
-            let camera_type = q_target_cameras
-                .get(ui)
-                .and_then(|t| q_camera_types.get(t.entity()))?;
-
-            let player_type = match camera_type {
-                CameraType::A => PlayerType::A,
-                CameraType::B => PlayerType::B,
-                CameraType::Full => unreachable!(),
-            };
+            let player_type = **q_player_uis.get(ui)?;
 
             // Set node visibility based on who marked the machine.
             let mut viz = q_viz.get_mut(ui)?;
@@ -37,6 +37,6 @@ fn main() {
                     ..default()
                 }),
         )
-        .add_plugins(recipe_game::AppPlugin)
+        .add_plugins(recipe_game::AppPlugin::default())
         .run();
 }
@@ -62,12 +62,99 @@ pub struct CollisionLayerConstructor {
 )]
 #[reflect(Component, Default)]
 pub enum GameLayer {
+    /// Level geometry (e.g. floors and tiles).
     #[default]
     Default,
+    /// Player characters.
     Player,
+    /// Enemy units, collides with everything except towers.
     Enemy,
+    /// Machines and other entities that players can interact with.
     Interactable,
+    /// Items that can be collected into the inventory.
     InventoryItem,
+    /// Projectiles from towers and players, only collides with
+    /// enemies, friendly units and obstacles.
     Projectile,
+    /// Placed towers, enemies walk through them.
     Tower,
+    /// Walls and cover that block projectiles.
+    Obstacle,
+    /// Loose drops that are picked up on contact.
+    Pickup,
+    /// Markers that are only queried by the minimap.
+    Minimap,
+    /// Units on the players' side, e.g. summons.
+    Friendly,
+}
+
+impl GameLayer {
+    /// [`CollisionLayers`] of [`GameLayer::Tower`] entities.
+    pub fn tower_layers() -> CollisionLayers {
+        let mut filters = LayerMask::ALL;
+        filters.remove(GameLayer::Enemy);
+
+        CollisionLayers::new(GameLayer::Tower, filters)
+    }
+
+    /// [`CollisionLayers`] of [`GameLayer::Enemy`] entities.
+    pub fn enemy_layers() -> CollisionLayers {
+        CollisionLayers::new(GameLayer::Enemy, LayerMask::ALL)
+    }
+
+    /// [`CollisionLayers`] of [`GameLayer::Friendly`] entities.
+    pub fn friendly_layers() -> CollisionLayers {
+        CollisionLayers::new(GameLayer::Friendly, LayerMask::ALL)
+    }
+
+    /// [`CollisionLayers`] of [`GameLayer::Projectile`] entities.
+    pub fn projectile_layers() -> CollisionLayers {
+        CollisionLayers::new(
+            GameLayer::Projectile,
+            [
+                GameLayer::Enemy,
+                GameLayer::Obstacle,
+                GameLayer::Friendly,
+            ],
+        )
+    }
+
+    /// Mask of the static environment, excluding all moving
+    /// entities and non-physical markers.
+    pub fn environment_mask() -> LayerMask {
+        let mut mask = LayerMask::ALL;
+        mask.remove([
+            GameLayer::Player,
+            GameLayer::Enemy,
+            GameLayer::InventoryItem,
+            GameLayer::Tower,
+            GameLayer::Projectile,
+            GameLayer::Pickup,
+            GameLayer::Minimap,
+            GameLayer::Friendly,
+        ]);
+
+        mask
+    }
+
+    /// [`SpatialQueryFilter`] that only hits this layer.
+    pub fn query_filter(self) -> SpatialQueryFilter {
+        SpatialQueryFilter::from_mask(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_projectile_hits_enemies_but_not_towers() {
+        let projectile = GameLayer::projectile_layers();
+
+        assert!(projectile.interacts_with(GameLayer::enemy_layers()));
+        assert!(
+            projectile.interacts_with(GameLayer::tower_layers())
+                == false
+        );
+    }
 }
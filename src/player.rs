@@ -8,15 +8,17 @@ use bevy::ecs::spawn::SpawnWith;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
+use crate::AppPluginConfig;
 use crate::action::{GamepadIndex, PlayerAction};
 use crate::asset_pipeline::PrefabName;
-use crate::camera_controller::split_screen::{
-    CameraType, QueryCameras,
-};
 use crate::character_controller::CharacterController;
+use crate::ui::locale::{LocalizedText, Localizer};
+use crate::ui::player_ui::PlayerUi;
 use crate::ui::world_space::WorldUi;
 use crate::util::PropagateComponentAppExt;
 
+pub mod currency;
+pub mod economy;
 pub mod player_attack;
 pub mod player_mark;
 
@@ -27,8 +29,33 @@ impl Plugin for PlayerPlugin {
         app.add_plugins((
             player_attack::PlayerAttackPlugin,
             player_mark::PlayerMarkPlugin,
+            currency::CurrencyPlugin,
+            economy::EconomyPlugin,
         ));
 
+        // Players are possessed from the start if configured.
+        if let Some(possessors) = app
+            .world()
+            .get_resource::<AppPluginConfig>()
+            .and_then(|config| config.possessors)
+        {
+            app.insert_state(PlayerState::Possessed).add_systems(
+                Startup,
+                move |mut commands: Commands| {
+                    spawn_player_action(
+                        &mut commands,
+                        &possessors[0],
+                        PlayerType::A,
+                    );
+                    spawn_player_action(
+                        &mut commands,
+                        &possessors[1],
+                        PlayerType::B,
+                    );
+                },
+            );
+        }
+
         app.init_state::<PlayerState>()
             .add_observer(setup_name_ui_for_player)
             .add_systems(
@@ -73,25 +100,26 @@ fn ready_inputs(
         return;
     }
 
-    match player_a {
-        PossessorType::Keyboard => {
-            commands.spawn(PlayerAction::new_kbm())
-        }
-        PossessorType::Gamepad(entity) => commands
-            .spawn(PlayerAction::new_gamepad().with_gamepad(*entity)),
-    }
-    .insert(PlayerType::A);
+    spawn_player_action(&mut commands, player_a, PlayerType::A);
+    spawn_player_action(&mut commands, player_b, PlayerType::B);
+
+    player_state.set(PlayerState::Possessed);
+}
 
-    match player_b {
+/// Spawn the input map of a player based on its possessor.
+fn spawn_player_action(
+    commands: &mut Commands,
+    possessor: &PossessorType,
+    player_type: PlayerType,
+) {
+    match possessor {
         PossessorType::Keyboard => {
             commands.spawn(PlayerAction::new_kbm())
         }
         PossessorType::Gamepad(entity) => commands
             .spawn(PlayerAction::new_gamepad().with_gamepad(*entity)),
     }
-    .insert(PlayerType::B);
-
-    player_state.set(PlayerState::Possessed);
+    .insert(player_type);
 }
 
 fn process_posessing_inputs(
@@ -151,6 +179,7 @@ fn handle_possession_triggers(
     mut commands: Commands,
     q_gamepad_indices: Query<&GamepadIndex>,
     mut player_possessor: ResMut<PlayerPossessor>,
+    localizer: Localizer,
 ) -> Result {
     let possession = trigger.event();
 
@@ -192,9 +221,11 @@ fn handle_possession_triggers(
 
     let get_text = |possessor: &PossessorType| {
         let text = match possessor {
-            PossessorType::Keyboard => "Keyboard".to_string(),
+            PossessorType::Keyboard => {
+                localizer.t("possession.keyboard")
+            }
             PossessorType::Gamepad(entity) => {
-                let s = "Gamepad #".to_string();
+                let s = localizer.t("possession.gamepad");
                 s + &format!(
                     "{}",
                     q_gamepad_indices.get(*entity)?.get()
@@ -216,7 +247,7 @@ fn handle_possession_triggers(
             .entity(player_possessor.ui_slot_a)
             .insert(BackgroundColor(RED_900.into()))
             .despawn_related::<Children>()
-            .with_child(centered_text("N/A"));
+            .with_child(centered_localized_text("possession.none"));
     }
 
     if let Some(possessor) = player_possessor.player_b {
@@ -230,7 +261,7 @@ fn handle_possession_triggers(
             .entity(player_possessor.ui_slot_b)
             .insert(BackgroundColor(RED_900.into()))
             .despawn_related::<Children>()
-            .with_child(centered_text("N/A"));
+            .with_child(centered_localized_text("possession.none"));
     }
 
     if player_possessor.is_ready() {
@@ -248,14 +279,10 @@ fn handle_possession_triggers(
 }
 
 fn setup_possession_ui(mut commands: Commands) {
-    const INSTRUCTION_CANCEL: &str =
-        "Press Esc (keyboard) | B (controller) to cancel.";
-    const INSTRUCTION_A: &str = "Press:\n\
-    A (keyboard) / DPadLeft (controller)";
-    const INSTRUCTION_B: &str = "Press:\n\
-    D (keyboard) / DPadRight (controller)";
-    const INSTRUCTION_READY: &str =
-        "Press Enter (keyboard) / A (controller) to confirm!";
+    const INSTRUCTION_CANCEL: &str = "possession.cancel";
+    const INSTRUCTION_A: &str = "possession.instruction_a";
+    const INSTRUCTION_B: &str = "possession.instruction_b";
+    const INSTRUCTION_READY: &str = "possession.ready";
 
     let instruction_ui_node = Node {
         justify_content: JustifyContent::Center,
@@ -282,16 +309,16 @@ fn setup_possession_ui(mut commands: Commands) {
 
     let ui_slot_a = commands
         .spawn(possession_slot.clone())
-        .with_child(centered_text("N/A"))
+        .with_child(centered_localized_text("possession.none"))
         .id();
     let ui_slot_b = commands
         .spawn(possession_slot)
-        .with_child(centered_text("N/A"))
+        .with_child(centered_localized_text("possession.none"))
         .id();
 
     let ui_ready = commands
         .spawn((
-            Text::new(INSTRUCTION_READY),
+            LocalizedText::new(INSTRUCTION_READY),
             TextLayout::new_with_justify(JustifyText::Center),
             Visibility::Hidden,
         ))
@@ -312,13 +339,13 @@ fn setup_possession_ui(mut commands: Commands) {
                 parent
                     .spawn(instruction_ui_node)
                     .with_child((
-                        Text::new("Player A"),
+                        LocalizedText::new("possession.player_a"),
                         Node {
                             margin: UiRect::all(Val::VMin(3.0)),
                             ..default()
                         },
                     ))
-                    .with_child(Text::new(INSTRUCTION_A))
+                    .with_child(LocalizedText::new(INSTRUCTION_A))
                     .add_child(ui_slot_a);
             }
         }),
@@ -335,13 +362,13 @@ fn setup_possession_ui(mut commands: Commands) {
             parent
                 .spawn(instruction_ui_node)
                 .with_child((
-                    Text::new("Player B"),
+                    LocalizedText::new("possession.player_b"),
                     Node {
                         margin: UiRect::all(Val::VMin(3.0)),
                         ..default()
                     },
                 ))
-                .with_child(Text::new(INSTRUCTION_B))
+                .with_child(LocalizedText::new(INSTRUCTION_B))
                 .add_child(ui_slot_b);
         }),
     ));
@@ -349,7 +376,7 @@ fn setup_possession_ui(mut commands: Commands) {
     let instruction_ui = [
         commands
             .spawn((
-                Text::new(INSTRUCTION_CANCEL),
+                LocalizedText::new(INSTRUCTION_CANCEL),
                 TextLayout::new_with_justify(JustifyText::Center),
             ))
             .id(),
@@ -417,18 +444,24 @@ fn centered_text(text: impl Into<String>) -> impl Bundle {
     )
 }
 
+fn centered_localized_text(key: impl Into<String>) -> impl Bundle {
+    (
+        LocalizedText::new(key),
+        TextLayout::new_with_justify(JustifyText::Center),
+    )
+}
+
 /// Setup world space name ui for players.
 fn setup_name_ui_for_player(
     trigger: Trigger<OnAdd, PlayerType>,
     mut commands: Commands,
     q_players: Query<&PlayerType, With<CharacterController>>,
-    q_cameras: QueryCameras<Entity>,
-) -> Result {
+) {
     let entity = trigger.target();
 
     let Ok(player_type) = q_players.get(entity) else {
         // Spawned entity might not be a character.
-        return Ok(());
+        return;
     };
 
     let ui_bundle = move |name: &str, height: f32| {
@@ -462,18 +495,16 @@ fn setup_name_ui_for_player(
         PlayerType::A => {
             commands.spawn((
                 ui_bundle("Polo Bun", 1.0),
-                UiTargetCamera(q_cameras.get(CameraType::B)?),
+                PlayerUi(PlayerType::B),
             ));
         }
         PlayerType::B => {
             commands.spawn((
                 ui_bundle("Baguette", 1.5),
-                UiTargetCamera(q_cameras.get(CameraType::A)?),
+                PlayerUi(PlayerType::A),
             ));
         }
     }
-
-    Ok(())
 }
 
 #[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
@@ -486,6 +517,14 @@ pub enum PlayerType {
 }
 
 impl PlayerType {
+    /// Signature color of the player, used to style their ui.
+    pub fn color(&self) -> Srgba {
+        match self {
+            PlayerType::A => AMBER_300,
+            PlayerType::B => ORANGE_400,
+        }
+    }
+
     pub fn prefab_name(&self) -> PrefabName {
         match self {
             PlayerType::A => PrefabName::FileName("polo_bun"),
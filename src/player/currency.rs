@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+use serde::Deserialize;
+
+use crate::difficulty::Difficulty;
+use crate::enemy::spawner::WaveStarted;
+use crate::ui::Screen;
+
+use super::economy::EconomyConfig;
+
+pub(super) struct CurrencyPlugin;
+
+impl Plugin for CurrencyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Currency>()
+            .add_systems(OnEnter(Screen::EnterLevel), init_currency)
+            .add_observer(grant_wave_income);
+    }
+}
+
+/// Reset [`Currency`] resource.
+fn init_currency(mut commands: Commands) {
+    commands.insert_resource(Currency::default());
+}
+
+/// Grant the [`WaveIncome`] of the current [`Difficulty`]
+/// at the start of every wave.
+fn grant_wave_income(
+    _: Trigger<WaveStarted>,
+    mut currency: ResMut<Currency>,
+    difficulty: Res<Difficulty>,
+    economy: Res<EconomyConfig>,
+) {
+    let income = economy.wave_income(*difficulty);
+    let (stipend, interest) = income.payout(currency.0);
+
+    info!("Wave income: +{stipend} stipend, +{interest} interest.");
+    currency.0 += stipend + interest;
+}
+
+/// Currency shared between both players.
+#[derive(Resource, Deref, DerefMut, Default, Debug)]
+pub struct Currency(pub u32);
+
+/// Currency granted at the start of every wave.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct WaveIncome {
+    /// Fixed amount granted every wave.
+    pub stipend: u32,
+    /// Fraction of the banked [`Currency`] granted as interest.
+    pub interest_rate: f32,
+    /// Upper limit of the interest per wave.
+    pub max_interest: u32,
+}
+
+impl WaveIncome {
+    /// Stipend and interest granted for the `banked` currency.
+    pub fn payout(&self, banked: u32) -> (u32, u32) {
+        let interest = (banked as f32 * self.interest_rate) as u32;
+        (self.stipend, interest.min(self.max_interest))
+    }
+}
+
+/// Triggered when an enemy's [`Bounty`][crate::enemy::Bounty]
+/// gets added to the [`Currency`].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct BountyAwarded {
+    pub amount: u32,
+    /// World position of the killed enemy.
+    pub position: Vec3,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::enemy::spawner::SpawnWave;
+
+    fn income_app(banked: u32) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(Currency(banked))
+            .insert_resource(Difficulty::Normal)
+            .init_resource::<EconomyConfig>()
+            .add_observer(grant_wave_income);
+
+        app
+    }
+
+    #[test]
+    fn test_interest_is_capped_on_wave_start() {
+        let income =
+            EconomyConfig::default().wave_income(Difficulty::Normal);
+
+        // Rich enough to hit the interest cap.
+        let banked = 1000;
+        let mut app = income_app(banked);
+        app.world_mut().trigger(WaveStarted(SpawnWave::One));
+
+        assert_eq!(
+            app.world().resource::<Currency>().0,
+            banked + income.stipend + income.max_interest
+        );
+
+        // Below the cap.
+        let banked = 20;
+        let mut app = income_app(banked);
+        app.world_mut().trigger(WaveStarted(SpawnWave::Two));
+
+        let interest = (banked as f32 * income.interest_rate) as u32;
+        assert!(interest < income.max_interest);
+        assert_eq!(
+            app.world().resource::<Currency>().0,
+            banked + income.stipend + interest
+        );
+    }
+}
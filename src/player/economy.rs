@@ -0,0 +1,271 @@
+use bevy::asset::{AssetLoader, io::Reader};
+use bevy::asset::{AsyncReadExt, LoadContext};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::difficulty::Difficulty;
+
+use super::currency::WaveIncome;
+
+/// Plugin to load the economy tunables from "economy.economy.ron",
+/// reloading them whenever the file changes.
+pub(super) struct EconomyPlugin;
+
+impl Plugin for EconomyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<EconomyAsset>()
+            .init_asset_loader::<EconomyAssetLoader>()
+            .init_resource::<EconomyConfig>();
+
+        app.add_systems(PreStartup, load_economy)
+            .add_systems(Update, sync_economy_config);
+    }
+}
+
+/// Startup system: load "economy.economy.ron".
+fn load_economy(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(EconomyAssetHandle(
+        asset_server.load("economy.economy.ron"),
+    ));
+}
+
+/// Copy the loaded (or hot reloaded) [`EconomyAsset`]
+/// into the [`EconomyConfig`].
+fn sync_economy_config(
+    mut asset_events: EventReader<AssetEvent<EconomyAsset>>,
+    handle: Res<EconomyAssetHandle>,
+    assets: Res<Assets<EconomyAsset>>,
+    mut config: ResMut<EconomyConfig>,
+) {
+    for event in asset_events.read() {
+        if event.is_loaded_with_dependencies(&handle.0) == false
+            && event.is_modified(&handle.0) == false
+        {
+            continue;
+        }
+
+        if let Some(economy) = assets.get(&handle.0) {
+            info!("Economy config loaded.");
+            *config = economy.0.clone();
+        }
+    }
+}
+
+/// Every economy tunable, defaults are used until
+/// "economy.economy.ron" is loaded.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct EconomyConfig {
+    /// Multiplier of every enemy's [`Bounty`][crate::enemy::Bounty].
+    pub bounty_mult: f32,
+    /// Currency needed to move a tower after the grace period.
+    pub move_cost: u32,
+    /// Duration in seconds after placement where moving is free.
+    pub move_grace_period: f32,
+    /// Currency granted at the start of every wave.
+    pub wave_income: HashMap<Difficulty, WaveIncome>,
+    /// Fraction of the [`BuildCost`][crate::tower::BuildCost]
+    /// refunded when selling a tower, rounded like the bounty.
+    pub sell_refund: f32,
+    /// Currency granted for every corn, or any other ingredient,
+    /// collected past its max stack size.
+    pub corn_value: u32,
+    /// Multiplier of every currency cost: tower worth,
+    /// upgrades and moves.
+    pub cost_mult: f32,
+    /// Multiplier of tower build and upgrade costs on top of
+    /// [`Self::cost_mult`], 1.0 if the difficulty is missing.
+    pub difficulty_cost_mult: HashMap<Difficulty, f32>,
+}
+
+impl EconomyConfig {
+    /// [`Bounty`][crate::enemy::Bounty] awarded for an enemy.
+    pub fn bounty(&self, amount: u32) -> u32 {
+        (amount as f32 * self.bounty_mult).round() as u32
+    }
+
+    /// Currency cost of anything priced at `base`,
+    /// rounded like the bounty.
+    pub fn cost(&self, base: u32) -> u32 {
+        (base as f32 * self.cost_mult).round() as u32
+    }
+
+    /// Currency cost of a tower, or one of its upgrades,
+    /// priced at `base` on the `difficulty`.
+    pub fn tower_cost(
+        &self,
+        base: u32,
+        difficulty: Difficulty,
+    ) -> u32 {
+        let difficulty_mult = self
+            .difficulty_cost_mult
+            .get(&difficulty)
+            .copied()
+            .unwrap_or(1.0);
+
+        (base as f32 * self.cost_mult * difficulty_mult).round()
+            as u32
+    }
+
+    /// Currency needed to move a tower placed at `placed_at`.
+    pub fn move_cost(
+        &self,
+        placed_at: f32,
+        elapsed_secs: f32,
+    ) -> u32 {
+        if elapsed_secs - placed_at <= self.move_grace_period {
+            0
+        } else {
+            self.cost(self.move_cost)
+        }
+    }
+
+    /// Currency refunded for selling a tower worth `build_cost`.
+    pub fn refund(&self, build_cost: u32) -> u32 {
+        (build_cost as f32 * self.sell_refund).round() as u32
+    }
+
+    /// Currency granted for `quantity` surplus corns.
+    pub fn corn_currency(&self, quantity: u32) -> u32 {
+        quantity * self.corn_value
+    }
+
+    /// [`WaveIncome`] of the difficulty, nothing if it's missing.
+    pub fn wave_income(&self, difficulty: Difficulty) -> WaveIncome {
+        self.wave_income
+            .get(&difficulty)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl Default for EconomyConfig {
+    fn default() -> Self {
+        Self {
+            bounty_mult: 1.0,
+            move_cost: 5,
+            move_grace_period: 10.0,
+            wave_income: HashMap::from_iter([
+                (
+                    Difficulty::Easy,
+                    WaveIncome {
+                        stipend: 15,
+                        interest_rate: 0.1,
+                        max_interest: 15,
+                    },
+                ),
+                (
+                    Difficulty::Normal,
+                    WaveIncome {
+                        stipend: 10,
+                        interest_rate: 0.1,
+                        max_interest: 10,
+                    },
+                ),
+                (
+                    Difficulty::Hard,
+                    WaveIncome {
+                        stipend: 5,
+                        interest_rate: 0.05,
+                        max_interest: 5,
+                    },
+                ),
+            ]),
+            sell_refund: 0.5,
+            corn_value: 1,
+            cost_mult: 1.0,
+            difficulty_cost_mult: HashMap::from_iter([
+                (Difficulty::Easy, 0.8),
+                (Difficulty::Normal, 1.0),
+                (Difficulty::Hard, 1.25),
+            ]),
+        }
+    }
+}
+
+#[derive(Asset, TypePath, Deref, Debug, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct EconomyAsset(EconomyConfig);
+
+#[derive(Resource)]
+pub struct EconomyAssetHandle(pub Handle<EconomyAsset>);
+
+#[derive(Default)]
+pub struct EconomyAssetLoader;
+
+impl AssetLoader for EconomyAssetLoader {
+    type Asset = EconomyAsset;
+
+    type Settings = ();
+
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut ron_str = String::new();
+        reader.read_to_string(&mut ron_str).await?;
+
+        // Hot reloads keep the previous asset on a typo.
+        ron::from_str::<EconomyAsset>(&ron_str).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["economy.ron"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_partial_economy_keeps_defaults() {
+        let economy = ron::from_str::<EconomyAsset>(
+            "(bounty_mult: 2.0, move_cost: 8)",
+        )
+        .unwrap();
+
+        assert_eq!(economy.bounty(5), 10);
+        assert_eq!(economy.move_cost(1.0, 2.0), 0);
+        assert_eq!(economy.move_cost(1.0, 20.0), 8);
+        assert_eq!(
+            economy.wave_income(Difficulty::Hard),
+            EconomyConfig::default().wave_income(Difficulty::Hard)
+        );
+    }
+
+    #[test]
+    fn test_cost_mult_scales_every_cost() {
+        let economy = ron::from_str::<EconomyAsset>(
+            "(move_cost: 5, cost_mult: 1.5, corn_value: 2)",
+        )
+        .unwrap();
+
+        assert_eq!(economy.cost(20), 30);
+        assert_eq!(economy.move_cost(1.0, 20.0), 8);
+        assert_eq!(economy.corn_currency(3), 6);
+    }
+
+    #[test]
+    fn test_hard_towers_cost_and_refund_more() {
+        let economy = EconomyConfig::default();
+
+        let normal_cost = economy.tower_cost(20, Difficulty::Normal);
+        let hard_cost = economy.tower_cost(20, Difficulty::Hard);
+        assert_eq!(normal_cost, 20);
+        assert_eq!(hard_cost, 25);
+        assert!(
+            economy.refund(hard_cost) > economy.refund(normal_cost)
+        );
+    }
+}
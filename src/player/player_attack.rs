@@ -7,6 +7,7 @@ use crate::enemy::IsEnemy;
 use crate::physics::GameLayer;
 use crate::player::PlayerType;
 use crate::tower::Projectile;
+use crate::tower::tower_attack::Team;
 use avian3d::prelude::*;
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::ActionState;
@@ -23,12 +24,13 @@ impl Plugin for PlayerAttackPlugin {
     }
 }
 
-fn update_cooldowns(
+/// Tick every [`AttackCooldown`], players' and towers' alike.
+pub(crate) fn update_cooldowns(
     mut q_cooldowns: Query<&mut AttackCooldown>,
     time: Res<Time>,
 ) {
     for mut cooldown in q_cooldowns.iter_mut() {
-        cooldown.0 -= time.delta_secs();
+        cooldown.0 = (cooldown.0 - time.delta_secs()).max(0.0);
     }
 }
 
@@ -98,8 +100,7 @@ fn player_shooting(
             Quat::IDENTITY,
             Dir3::new(*camera_forward).unwrap(),
             &shape_cast_config,
-            &SpatialQueryFilter::default()
-                .with_mask(GameLayer::Enemy),
+            &GameLayer::Enemy.query_filter(),
         );
 
         // Check if enemy was hit
@@ -135,7 +136,9 @@ fn player_shooting(
                 velocity: target_direction * weapon.projectile_speed,
                 damage: weapon.damage,
                 lifetime: weapon.projectile_lifetime,
+                ..default()
             },
+            Team::from(*player_type),
             Visibility::Inherited,
             Children::spawn(Spawn((
                 SceneRoot(handle),
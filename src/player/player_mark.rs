@@ -1,22 +1,26 @@
 use bevy::prelude::*;
 
+use crate::difficulty::Difficulty;
+use crate::enemy::spawner::WaveStarted;
 use crate::ui::Screen;
 
 pub(super) struct PlayerMarkPlugin;
 
 impl Plugin for PlayerMarkPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            OnEnter(Screen::EnterLevel),
-            init_player_mark,
-        )
-        .add_systems(
-            Update,
-            game_over_condition.run_if(
-                in_state(Screen::EnterLevel)
-                    .and(resource_changed::<PlayerMark>),
-            ),
-        );
+        app.init_resource::<BaseShield>()
+            .add_systems(
+                OnEnter(Screen::EnterLevel),
+                (init_player_mark, init_base_shield),
+            )
+            .add_systems(
+                Update,
+                game_over_condition.run_if(
+                    in_state(Screen::EnterLevel)
+                        .and(resource_changed::<PlayerMark>),
+                ),
+            )
+            .add_observer(recharge_base_shield);
     }
 }
 
@@ -25,6 +29,24 @@ pub fn init_player_mark(mut commands: Commands) {
     commands.insert_resource(PlayerMark(10));
 }
 
+/// Reset [`BaseShield`] according to the [`Difficulty`].
+pub fn init_base_shield(
+    mut commands: Commands,
+    difficulty: Res<Difficulty>,
+) {
+    commands.insert_resource(BaseShield::new(
+        difficulty.base_shield_charges(),
+    ));
+}
+
+/// Restore every [`BaseShield`] charge at the start of a wave.
+fn recharge_base_shield(
+    _: Trigger<WaveStarted>,
+    mut base_shield: ResMut<BaseShield>,
+) {
+    base_shield.charges = base_shield.max;
+}
+
 fn game_over_condition(
     player_mark: Res<PlayerMark>,
     mut next_screen: ResMut<NextState<Screen>>,
@@ -36,3 +58,26 @@ fn game_over_condition(
 
 #[derive(Resource, Deref, DerefMut)]
 pub struct PlayerMark(pub u32);
+
+/// Charges that absorb enemy leaks before the
+/// [`PlayerMark`] is decreased.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseShield {
+    pub charges: u32,
+    pub max: u32,
+}
+
+impl BaseShield {
+    pub fn new(max: u32) -> Self {
+        Self { charges: max, max }
+    }
+
+    /// Decrease the [`PlayerMark`] for a leaked enemy,
+    /// unless a charge absorbs it.
+    pub fn leak(&mut self, player_mark: &mut PlayerMark) {
+        match self.charges > 0 {
+            true => self.charges -= 1,
+            false => player_mark.0 = player_mark.saturating_sub(1),
+        }
+    }
+}
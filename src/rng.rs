@@ -0,0 +1,59 @@
+use core::hash::{BuildHasher, Hasher};
+use std::collections::hash_map::RandomState;
+
+use bevy::prelude::*;
+
+/// Global pseudo random number generator for gameplay variations.
+///
+/// Uses [SplitMix64](https://prng.di.unimi.it/splitmix64.c) under the
+/// hood, which is plenty for visual jitter. Construct it with
+/// [`GameRng::new`] for deterministic results (e.g. in tests).
+#[derive(Resource, Debug, Clone)]
+pub struct GameRng {
+    state: u64,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Seed the generator from the OS provided randomness.
+    pub fn from_entropy() -> Self {
+        Self::new(RandomState::new().build_hasher().finish())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in the range of `[0, 1)`.
+    pub fn f32(&mut self) -> f32 {
+        // Keep the top 24 bits, which fits exactly in the mantissa.
+        (self.next_u64() >> 40) as f32 / (1 << 24) as f32
+    }
+
+    /// Uniform value in the range of `[min, max)`.
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.f32()
+    }
+
+    /// Uniform point inside a disk of the given `radius`.
+    pub fn in_disk(&mut self, radius: f32) -> Vec2 {
+        let angle = self.f32() * core::f32::consts::TAU;
+        let distance = radius * self.f32().sqrt();
+
+        Vec2::from_angle(angle) * distance
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
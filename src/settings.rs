@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+use crate::ui::locale::Language;
+
+/// Player adjustable game settings.
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct Settings {
+    /// Draw a line from each tower to its current target.
+    pub show_target_lines: bool,
+    pub reticle_style: ReticleStyle,
+    /// Enemies alive at once before the spawner holds back the
+    /// rest of the wave, lower this on low-end devices.
+    pub max_alive_enemies: usize,
+    /// Interact with what the player's camera is aiming at,
+    /// instead of only the nearest interactable.
+    pub interaction_camera_ray: bool,
+    /// Linear volume multiplier of all music.
+    pub music_volume: f32,
+    /// Linear volume multiplier of all sound effects.
+    pub sfx_volume: f32,
+    /// Show the tutorial prompts, disabling skips the rest
+    /// of the tutorial.
+    pub show_tutorial: bool,
+    /// Enemies further than this from a player's camera are not
+    /// rendered on it, lower this on low-end devices.
+    pub enemy_cull_distance: f32,
+    pub graphics_quality: GraphicsQuality,
+    /// Language of every localized text, switched live.
+    pub language: Language,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            show_target_lines: true,
+            reticle_style: ReticleStyle::default(),
+            max_alive_enemies: 64,
+            interaction_camera_ray: true,
+            music_volume: 1.0,
+            sfx_volume: 1.0,
+            show_tutorial: true,
+            enemy_cull_distance: 60.0,
+            graphics_quality: GraphicsQuality::default(),
+            language: Language::default(),
+        }
+    }
+}
+
+/// Shape of the players' aim reticle.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReticleStyle {
+    Dot,
+    #[default]
+    Ring,
+    Cross,
+}
+
+/// Rendering quality of the game cameras.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsQuality {
+    /// Flat background without the skybox and environment lighting,
+    /// for low-end devices.
+    Low,
+    #[default]
+    High,
+}
@@ -0,0 +1,234 @@
+//! Headless [`App`] helpers for testing the combat simulation.
+
+use core::time::Duration;
+
+use avian3d::prelude::*;
+use bevy::app::Plugins;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::scene::ScenePlugin;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::asset_pipeline::{
+    AssetState, CurrentScene, PrefabAssets, PrefabName,
+};
+use crate::difficulty::Difficulty;
+use crate::enemy::{Enemy, GlobalEnemyModifiers, tick_slows};
+use crate::player::currency::Currency;
+use crate::player::economy::EconomyConfig;
+use crate::player::player_attack::update_cooldowns;
+use crate::rng::GameRng;
+use crate::tower::TowerPrefabName;
+use crate::tower::aura::AuraPlugin;
+use crate::tower::tower_attack::{
+    CooldownJitter, MaxHealth, Target, TargetsOf, Tower,
+    TowerAttackPlugin,
+};
+
+/// Duration of a single [`CombatTestAppExt::step`].
+pub const TIMESTEP: Duration = Duration::from_micros(15_625);
+
+/// Prefabs that the combat systems spawn at runtime.
+const FIXTURE_PREFABS: &[&str] =
+    &["popcorn", "roasted_corn", "corn", "mouse_a", "gun_tower"];
+
+/// Create a headless [`App`] with the tower and enemy combat systems,
+/// without any rendering or windowing plugins.
+///
+/// Every [`App::update`] advances the time by exactly [`TIMESTEP`],
+/// which also runs [`FixedUpdate`] once.
+pub fn combat_app() -> App {
+    combat_app_with(())
+}
+
+/// [`combat_app`] along with extra `plugins` under test,
+/// which can't be added once the app is finished.
+pub fn combat_app_with<M>(plugins: impl Plugins<M>) -> App {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        AssetPlugin::default(),
+        StatesPlugin,
+        // Colliders constructed from scenes need the spawner.
+        ScenePlugin,
+        PhysicsPlugins::default(),
+    ))
+    .init_asset::<Mesh>()
+    .init_asset::<Gltf>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(TIMESTEP))
+    .insert_resource(Time::<Fixed>::from_duration(TIMESTEP))
+    .insert_resource(Gravity(Vec3::ZERO))
+    .insert_state(AssetState::Loaded)
+    .init_resource::<CurrentScene>()
+    .init_resource::<GlobalEnemyModifiers>()
+    .init_resource::<Difficulty>()
+    .insert_resource(GameRng::new(0))
+    // Towers fire as soon as they can, see `CooldownJitter`.
+    .insert_resource(CooldownJitter { max: 0.0 })
+    .init_resource::<Currency>()
+    .init_resource::<EconomyConfig>()
+    .add_plugins((TowerAttackPlugin, AuraPlugin))
+    .add_plugins(plugins)
+    .add_systems(Update, (update_cooldowns, tick_slows));
+
+    load_fixtures(app.world_mut());
+
+    // Physics registers its resources once the plugins are built,
+    // normally done by `App::run`.
+    app.finish();
+    app.cleanup();
+
+    app
+}
+
+/// Insert empty prefabs in place of the actual gltf files
+/// and a [`CurrentScene`] for the spawned drops.
+fn load_fixtures(world: &mut World) {
+    let mut named_prefabs = HashMap::new();
+
+    for file_name in FIXTURE_PREFABS {
+        named_prefabs.insert(
+            PrefabName::FileName(file_name).cast(),
+            empty_gltf(world),
+        );
+    }
+
+    world.insert_resource(PrefabAssets {
+        named_prefabs,
+        named_animations: default(),
+    });
+
+    let scene = world.spawn(Transform::default()).id();
+    world.resource_mut::<CurrentScene>().set(scene);
+}
+
+/// Add a [`Gltf`] with a single empty default scene.
+///
+/// Requires the [`Gltf`] and [`Scene`] assets to be initialized.
+pub fn empty_gltf(world: &mut World) -> Handle<Gltf> {
+    let scene = world
+        .resource_mut::<Assets<Scene>>()
+        .add(Scene::new(World::new()));
+
+    world.resource_mut::<Assets<Gltf>>().add(Gltf {
+        scenes: vec![scene.clone()],
+        named_scenes: default(),
+        meshes: default(),
+        named_meshes: default(),
+        materials: default(),
+        named_materials: default(),
+        nodes: default(),
+        named_nodes: default(),
+        skins: default(),
+        named_skins: default(),
+        default_scene: Some(scene),
+        animations: default(),
+        named_animations: default(),
+        source: None,
+    })
+}
+
+pub trait CombatTestAppExt {
+    /// Spawn a tower with the given prefab name (e.g. `"gun_tower"`).
+    fn spawn_tower(
+        &mut self,
+        prefab_name: &str,
+        tower: Tower,
+        translation: Vec3,
+    ) -> Entity;
+
+    /// Spawn a stationary enemy.
+    fn spawn_enemy(
+        &mut self,
+        max_health: f32,
+        translation: Vec3,
+    ) -> Entity;
+
+    /// Run [`App::update`] `count` times.
+    fn step(&mut self, count: usize);
+
+    /// Panic if any [`Target`] or [`TargetsOf`] points to a
+    /// despawned entity or is not mirrored on the other side.
+    fn assert_no_dangling_targets(&self);
+}
+
+impl CombatTestAppExt for App {
+    fn spawn_tower(
+        &mut self,
+        prefab_name: &str,
+        tower: Tower,
+        translation: Vec3,
+    ) -> Entity {
+        self.world_mut()
+            .spawn((
+                tower,
+                TowerPrefabName::new(prefab_name),
+                Transform::from_translation(translation),
+            ))
+            .id()
+    }
+
+    fn spawn_enemy(
+        &mut self,
+        max_health: f32,
+        translation: Vec3,
+    ) -> Entity {
+        self.world_mut()
+            .spawn((
+                Enemy {
+                    movement_speed: 0.0,
+                    damage: 0.0,
+                    attack_cooldown: 1.0,
+                },
+                MaxHealth(max_health),
+                RigidBody::Dynamic,
+                Collider::sphere(0.5),
+                Transform::from_translation(translation),
+            ))
+            .id()
+    }
+
+    fn step(&mut self, count: usize) {
+        for _ in 0..count {
+            self.update();
+        }
+    }
+
+    fn assert_no_dangling_targets(&self) {
+        let world = self.world();
+
+        for entity_ref in world.iter_entities() {
+            let entity = entity_ref.id();
+
+            if let Some(target) = entity_ref.get::<Target>() {
+                let targets_of = world
+                    .get::<TargetsOf>(target.entity())
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "{entity}'s target {} is dangling.",
+                            target.entity()
+                        )
+                    });
+                assert!(
+                    targets_of.contains(&entity),
+                    "{entity} is missing from its target's `TargetsOf`."
+                );
+            }
+
+            if let Some(targets_of) = entity_ref.get::<TargetsOf>() {
+                for source in targets_of.iter() {
+                    assert_eq!(
+                        world
+                            .get::<Target>(source)
+                            .map(|target| target.entity()),
+                        Some(entity),
+                        "{entity} is targeted by a stale {source}."
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,29 +1,76 @@
 use avian3d::prelude::*;
 use bevy::color::palettes::tailwind::*;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
 
 use crate::action::{PlayerAction, TargetAction};
-use crate::asset_pipeline::{AssetState, CurrentScene, PrefabAssets};
+use crate::asset_pipeline::{
+    AssetState, CurrentScene, LevelScoped, PrefabAssets, PrefabName,
+};
 use crate::camera_controller::{A_RENDER_LAYER, B_RENDER_LAYER};
 use crate::character_controller::CharacterController;
+use crate::difficulty::Difficulty;
+use crate::enemy::{Path, PathIndex, Slow};
 use crate::inventory::Inventory;
 use crate::inventory::item::{ItemRegistry, ItemType};
 use crate::physics::GameLayer;
+use crate::player::currency::Currency;
+use crate::player::economy::EconomyConfig;
 use crate::player::{PlayerType, QueryPlayers};
 use crate::tile::{PlacedBy, PlacedOn, Tile};
+use crate::tower::placement::can_place_tower;
+use crate::tower::tower_meta::TowerMetaRegistry;
 use crate::util::PropagateComponentAppExt;
 
-mod animation;
+pub(crate) mod animation;
+pub mod aura;
+pub mod beam;
+pub mod build_tool;
+pub mod combat_timings;
+pub mod enemy_grid;
+pub mod experience;
+pub mod homing;
+mod impact_decal;
+pub mod inspect;
+pub mod placement;
+pub mod plan_overlay;
+pub mod projectile_pool;
+pub mod range_gizmo;
+pub mod sell;
+pub mod spawn_protection;
+mod target_line;
+#[cfg(feature = "dev")]
+pub mod targeting_inspector;
 pub mod tower_attack;
+pub mod tower_meta;
+pub mod upgrade;
 
 pub struct TowerPlugin;
 
 impl Plugin for TowerPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
-            tower_attack::TowerAttackPlugin,
-            animation::TowerAnimationPlugin,
+            (
+                tower_attack::TowerAttackPlugin,
+                animation::TowerAnimationPlugin,
+                aura::AuraPlugin,
+                homing::HomingPlugin,
+                target_line::TargetLinePlugin,
+                beam::BeamPlugin,
+                spawn_protection::SpawnProtectionPlugin,
+                impact_decal::ImpactDecalPlugin,
+                experience::TowerExperiencePlugin,
+            ),
+            (
+                inspect::TowerInspectPlugin,
+                tower_meta::TowerMetaPlugin,
+                plan_overlay::PlanOverlayPlugin,
+                build_tool::BuildToolPlugin,
+                range_gizmo::RangeGizmoPlugin,
+                upgrade::TowerUpgradePlugin,
+                sell::TowerSellPlugin,
+            ),
         ));
 
         app.propagate_component::<TowerPrefabName, Children>()
@@ -33,7 +80,11 @@ impl Plugin for TowerPlugin {
                 (
                     tower_placement_and_preview
                         .run_if(in_state(AssetState::Loaded)),
-                    (enter_placement_mode, exit_placement_mode),
+                    (
+                        enter_placement_mode,
+                        enter_move_mode,
+                        exit_placement_mode,
+                    ),
                 )
                     .chain(),
             );
@@ -45,14 +96,22 @@ fn setup_preview_cube(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut meshes: ResMut<Assets<Mesh>>,
 ) {
-    let preview_cube = (
-        Mesh3d(meshes.add(Cuboid::new(0.5, 0.5, 0.5))),
-        MeshMaterial3d(materials.add(StandardMaterial {
-            base_color: GREEN_600.with_alpha(0.4).into(),
+    let mut preview_material = |color: Srgba| {
+        materials.add(StandardMaterial {
+            base_color: color.with_alpha(0.4).into(),
             alpha_mode: AlphaMode::Blend,
             unlit: true,
             ..default()
-        })),
+        })
+    };
+    let preview_materials = PreviewMaterials {
+        valid: preview_material(GREEN_600),
+        invalid: preview_material(RED_600),
+    };
+
+    let preview_cube = (
+        Mesh3d(meshes.add(Cuboid::new(0.5, 0.5, 0.5))),
+        MeshMaterial3d(preview_materials.valid.clone()),
         Preview,
         Visibility::Hidden,
     );
@@ -63,17 +122,28 @@ fn setup_preview_cube(
         PlayerType::A,
     ));
     commands.spawn((preview_cube, B_RENDER_LAYER, PlayerType::B));
+    commands.insert_resource(preview_materials);
 }
 
 fn enter_placement_mode(
     mut commands: Commands,
     mut q_players: Query<
-        (&Inventory, &TargetAction, Entity),
+        (&Inventory, &TargetAction, Option<&Children>, Entity),
         (With<CharacterController>, Without<InPlacementMode>),
     >,
+    q_build_tools: Query<(), With<build_tool::BuildTool>>,
     q_actions: Query<&ActionState<PlayerAction>>,
 ) -> Result {
-    for (inventory, target_action, entity) in q_players.iter_mut() {
+    for (inventory, target_action, children, entity) in
+        q_players.iter_mut()
+    {
+        // Picked from the radial menu instead.
+        if children.is_some_and(|children| {
+            build_tool::holds_build_tool(children, &q_build_tools)
+        }) {
+            continue;
+        }
+
         let action = q_actions.get(target_action.get())?;
 
         if action.just_pressed(&PlayerAction::Placement) {
@@ -97,6 +167,58 @@ fn enter_placement_mode(
     Ok(())
 }
 
+/// Pick up the placed tower in front of the player to move it
+/// to another tile.
+fn enter_move_mode(
+    mut commands: Commands,
+    q_players: Query<
+        (&GlobalTransform, &TargetAction, Entity),
+        (With<CharacterController>, Without<InPlacementMode>),
+    >,
+    q_towers: Query<
+        (&GlobalTransform, Entity),
+        (With<TowerPrefabName>, With<PlacedOn>),
+    >,
+    q_actions: Query<&ActionState<PlayerAction>>,
+) -> Result {
+    /// Max distance from the front of the player to the tower.
+    const PICK_UP_RADIUS: f32 = 2.0;
+
+    for (global_transform, target_action, entity) in q_players.iter()
+    {
+        let action = q_actions.get(target_action.get())?;
+
+        if action.just_pressed(&PlayerAction::MoveTower) == false {
+            continue;
+        }
+
+        // In front of the player.
+        let target_position = global_transform.translation()
+            + global_transform.forward() * 2.0;
+
+        let closest_tower = q_towers
+            .iter()
+            .map(|(tower_transform, tower)| {
+                (
+                    tower,
+                    tower_transform
+                        .translation()
+                        .distance(target_position),
+                )
+            })
+            .filter(|(_, distance)| *distance <= PICK_UP_RADIUS)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        if let Some((tower, _)) = closest_tower {
+            commands
+                .entity(entity)
+                .insert((InPlacementMode, MovingTower(tower)));
+        }
+    }
+
+    Ok(())
+}
+
 fn exit_placement_mode(
     mut commands: Commands,
     mut q_players: Query<
@@ -110,8 +232,10 @@ fn exit_placement_mode(
         let action = q_actions.get(target_action.get())?;
 
         if action.just_pressed(&PlayerAction::Cancel) {
-            // Exit placement mode.
-            commands.entity(entity).remove::<InPlacementMode>();
+            // Exit placement mode, moved towers stay in place.
+            commands
+                .entity(entity)
+                .remove::<(InPlacementMode, MovingTower)>();
             *q_previews.get_mut(*player_type)? = Visibility::Hidden;
         }
     }
@@ -128,31 +252,43 @@ fn tower_placement_and_preview(
             &PlayerType,
             &mut Inventory,
             &TargetAction,
+            Option<&MovingTower>,
             Entity,
         ),
         (With<CharacterController>, With<InPlacementMode>),
     >,
     q_tiles: Query<&GlobalTransform, (With<Tile>, Without<PlacedBy>)>,
+    q_placed_ats: Query<&PlacedAt, With<TowerPrefabName>>,
     mut q_previews: QueryPlayers<
-        (&mut Transform, &mut Visibility),
+        (
+            &mut Transform,
+            &mut Visibility,
+            &mut MeshMaterial3d<StandardMaterial>,
+        ),
         With<Preview>,
     >,
+    q_paths: Query<(&Path, &PathIndex)>,
+    preview_materials: Res<PreviewMaterials>,
     q_actions: Query<&ActionState<PlayerAction>>,
     item_registry: ItemRegistry,
     spatial_query: SpatialQuery,
-    prefabs: Res<PrefabAssets>,
-    gltfs: Res<Assets<Gltf>>,
-    current_scene: Res<CurrentScene>,
+    mut tower_spawner: TowerSpawner,
+    mut currency: ResMut<Currency>,
+    economy: Res<EconomyConfig>,
+    difficulty: Res<Difficulty>,
+    tower_meta: TowerMetaRegistry,
+    time: Res<Time>,
 ) -> Result {
-    let Some(current_scene) = current_scene.get() else {
+    if tower_spawner.current_scene.get().is_none() {
         return Ok(());
-    };
+    }
 
     for (
         global_transform,
         player_type,
         mut inventory,
         target_action,
+        moving_tower,
         player_entity,
     ) in q_players.iter_mut()
     {
@@ -192,7 +328,7 @@ fn tower_placement_and_preview(
             }
         }
 
-        let (mut preview_transform, mut preview_viz) =
+        let (mut preview_transform, mut preview_viz, mut preview_mat) =
             q_previews.get_mut(*player_type)?;
 
         let Some((tile_position, tile_entity)) = closest_tile_data
@@ -201,6 +337,9 @@ fn tower_placement_and_preview(
             continue;
         };
 
+        let placement =
+            can_place_tower(tile_position, &spatial_query, &q_paths);
+
         if q_actions
             .get(target_action.get())?
             .just_pressed(&PlayerAction::Placement)
@@ -208,7 +347,36 @@ fn tower_placement_and_preview(
             // Exit placement mode regardless if placing is a success or not.
             commands
                 .entity(player_entity)
-                .remove::<InPlacementMode>();
+                .remove::<(InPlacementMode, MovingTower)>();
+            *preview_viz = Visibility::Hidden;
+
+            if placement.is_valid() == false {
+                info!("Can't place the tower: {placement:?}");
+                continue;
+            }
+
+            if let Some(&MovingTower(tower)) = moving_tower {
+                // Tower might have been destroyed while moving.
+                let Ok(placed_at) = q_placed_ats.get(tower) else {
+                    continue;
+                };
+
+                let cost = economy
+                    .move_cost(placed_at.0, time.elapsed_secs());
+                if currency.0 < cost {
+                    info!("Not enough currency to move the tower!");
+                    continue;
+                }
+                currency.0 -= cost;
+
+                relocate_tower(
+                    &mut commands,
+                    tower,
+                    tile_entity,
+                    tile_position,
+                );
+                continue;
+            }
 
             let Some(selected_tower) =
                 inventory.selected_tower.clone()
@@ -227,29 +395,26 @@ fn tower_placement_and_preview(
                 continue;
             }
 
-            // Spawn the tower.
-            commands.spawn((
-                TowerPrefabName(item.raw_prefab_name().to_string()),
-                SceneRoot(
-                    prefabs
-                        .get_gltf(item.prefab_name(), &gltfs)
-                        .ok_or(format!(
-                            "Can't find {selected_tower} prefab!"
-                        ))?
-                        .default_scene
-                        .clone()
-                        .ok_or(
-                            "Tower prefab should have a default scene.",
-                        )?,
-                ),
-                Transform::from_translation(tile_position),
-                PlacedOn(tile_entity),
-                ChildOf(current_scene),
-            ));
-
-            *preview_viz = Visibility::Hidden;
+            let build_cost = tower_meta
+                .get_tower(&selected_tower)
+                .map(|meta| {
+                    economy.tower_cost(meta.cost, *difficulty)
+                })
+                .unwrap_or_default();
+
+            tower_spawner
+                .spawn(
+                    item.raw_prefab_name(),
+                    tile_position,
+                    Some(tile_entity),
+                )?
+                .insert(BuildCost(build_cost));
         } else {
             *preview_viz = Visibility::Inherited;
+            preview_mat.0 = match placement.is_valid() {
+                true => preview_materials.valid.clone(),
+                false => preview_materials.invalid.clone(),
+            };
             // Move the preview cube to the tile position.
             preview_transform.translation =
                 tile_position + Vec3::Y * 0.25;
@@ -259,28 +424,273 @@ fn tower_placement_and_preview(
     Ok(())
 }
 
+/// Spawns towers from their prefabs, for placing towers from code.
+#[derive(SystemParam)]
+pub struct TowerSpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    prefabs: Res<'w, PrefabAssets>,
+    gltfs: Res<'w, Assets<Gltf>>,
+    current_scene: Res<'w, CurrentScene>,
+    time: Res<'w, Time>,
+}
+
+impl TowerSpawner<'_, '_> {
+    /// Spawn the tower prefab (e.g. `"gun_tower"`) at `position`
+    /// as part of the [`CurrentScene`], occupying the `tile` if any.
+    ///
+    /// The [`Tower`][tower_attack::Tower] stats come from the prefab,
+    /// insert one into the returned [`EntityCommands`] to override them.
+    pub fn spawn(
+        &mut self,
+        prefab_name: &str,
+        position: Vec3,
+        tile: Option<Entity>,
+    ) -> Result<EntityCommands<'_>> {
+        let scene = self
+            .prefabs
+            .get_gltf(PrefabName::FileName(prefab_name), &self.gltfs)
+            .ok_or(format!("Can't find {prefab_name} prefab!"))?
+            .default_scene
+            .clone()
+            .ok_or("Tower prefab should have a default scene.")?;
+
+        let mut tower = self.commands.spawn((
+            TowerPrefabName::new(prefab_name),
+            SceneRoot(scene),
+            Transform::from_translation(position),
+            PlacedAt(self.time.elapsed_secs()),
+        ));
+
+        if let Some(tile) = tile {
+            tower.insert(PlacedOn(tile));
+        }
+
+        match self.current_scene.get() {
+            Some(current_scene) => {
+                tower.insert(ChildOf(current_scene));
+            }
+            // Still despawn along with the level.
+            None => {
+                tower.insert(LevelScoped);
+            }
+        }
+
+        Ok(tower)
+    }
+}
+
+/// Move a placed tower onto another tile, freeing the old tile.
+///
+/// The tower entity itself is kept, preserving all of its state
+/// (stats, cooldowns, etc.).
+fn relocate_tower(
+    commands: &mut Commands,
+    tower: Entity,
+    tile: Entity,
+    tile_position: Vec3,
+) {
+    commands.entity(tower).insert((
+        Transform::from_translation(tile_position),
+        PlacedOn(tile),
+    ));
+}
+
 /// Tag component for players who are in placement mode.
 #[derive(Component)]
 pub struct InPlacementMode;
 
+/// The placed tower that is being moved by the player,
+/// added along with [`InPlacementMode`].
+#[derive(Component, Deref, Debug)]
+pub struct MovingTower(Entity);
+
+/// [`Time::elapsed_secs`] when the tower was placed.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PlacedAt(pub f32);
+
+/// Currency worth of a placed tower, including its upgrades.
+/// Partly refunded when the tower is sold.
+#[derive(Component, Deref, Debug, Clone, Copy)]
+pub struct BuildCost(pub u32);
+
 /// Tag component for preview mesh.
 #[derive(Component, Clone, Copy)]
 pub struct Preview;
 
+/// Materials of the [`Preview`] mesh, depending on the
+/// [`PlacementResult`][placement::PlacementResult].
+#[derive(Resource, Debug)]
+pub struct PreviewMaterials {
+    pub valid: Handle<StandardMaterial>,
+    pub invalid: Handle<StandardMaterial>,
+}
+
 /// Projectile component representing a fired projectile
 #[derive(Component, Debug)]
 #[require(
     RigidBody::Kinematic,
     CollisionEventsEnabled,
-    CollisionLayers::new(GameLayer::Projectile, GameLayer::Enemy,),
+    CollisionLayers = GameLayer::projectile_layers(),
     Collider::sphere(0.2),
-    Sensor
+    Sensor,
+    LevelScoped,
+    tower_attack::Team
 )]
 pub struct Projectile {
     pub velocity: Vec3,
     pub damage: f32,
     pub lifetime: f32,
+    /// Downward acceleration, `0.0` for straight shots.
+    pub gravity: f32,
+    pub on_hit_effect: OnHitEffect,
+}
+
+impl Default for Projectile {
+    fn default() -> Self {
+        Self {
+            velocity: Vec3::ZERO,
+            damage: 0.0,
+            lifetime: 3.0,
+            gravity: 0.0,
+            on_hit_effect: OnHitEffect::Damage,
+        }
+    }
+}
+
+/// What a [`Projectile`] does to the enemy it hits.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum OnHitEffect {
+    /// Deal the [`Projectile::damage`].
+    #[default]
+    Damage,
+    /// Slow the enemy down without damaging it.
+    Slow(Slow),
 }
 
 #[derive(Component, Debug, Clone)]
 pub struct TowerPrefabName(String);
+
+impl TowerPrefabName {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// Color that tells the towers apart in gizmos.
+    pub fn color(&self) -> Srgba {
+        match self.0.as_ref() {
+            "gun_tower" => AMBER_300,
+            "cannon_tower" => ORANGE_500,
+            _ => ZINC_300,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::player::player_attack::AttackCooldown;
+    use crate::test_util::{CombatTestAppExt, combat_app};
+    use crate::tower::tower_attack::{Target, Tower};
+
+    #[test]
+    fn test_relocate_preserves_tower_state() {
+        let mut world = World::new();
+
+        let old_tile = world.spawn(Transform::default()).id();
+        let new_tile = world.spawn(Transform::default()).id();
+        let tower = world
+            .spawn((
+                Tower {
+                    range: 5.0,
+                    damage: 3.0,
+                    attack_cooldown: 1.0,
+                    projectile_speed: 10.0,
+                },
+                TowerPrefabName::new("gun_tower"),
+                Transform::default(),
+                PlacedOn(old_tile),
+            ))
+            .id();
+        world.get_mut::<AttackCooldown>(tower).unwrap().0 = 0.4;
+
+        world
+            .run_system_once(move |mut commands: Commands| {
+                relocate_tower(
+                    &mut commands,
+                    tower,
+                    new_tile,
+                    Vec3::X * 2.0,
+                );
+            })
+            .unwrap();
+
+        assert!(world.get::<PlacedBy>(old_tile).is_none());
+        assert_eq!(
+            world.get::<PlacedBy>(new_tile).unwrap().as_slice(),
+            &[tower]
+        );
+        assert_eq!(
+            world.get::<Transform>(tower).unwrap().translation,
+            Vec3::X * 2.0
+        );
+        assert_eq!(world.get::<Tower>(tower).unwrap().damage, 3.0);
+        assert_eq!(
+            world.get::<AttackCooldown>(tower).unwrap().0,
+            0.4
+        );
+    }
+
+    #[test]
+    fn test_spawned_tower_targets_enemy() {
+        let mut app = combat_app();
+
+        let tile = app.world_mut().spawn(Transform::default()).id();
+        let tower = app
+            .world_mut()
+            .run_system_once(
+                move |mut tower_spawner: TowerSpawner| -> Result<Entity> {
+                    Ok(tower_spawner
+                        .spawn("gun_tower", Vec3::ZERO, Some(tile))?
+                        // Stats of the empty fixture prefab.
+                        .insert(Tower {
+                            range: 10.0,
+                            damage: 1.0,
+                            attack_cooldown: 1.0,
+                            projectile_speed: 20.0,
+                        })
+                        .id())
+                },
+            )
+            .unwrap()
+            .unwrap();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<ChildOf>(tower).map(ChildOf::parent),
+            world.resource::<CurrentScene>().get()
+        );
+        assert_eq!(
+            world.get::<PlacedBy>(tile).unwrap().as_slice(),
+            &[tower]
+        );
+        assert!(world.get::<AttackCooldown>(tower).is_some());
+        assert!(world.get::<CollisionLayers>(tower).is_some());
+
+        let enemy = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 3.0));
+
+        let mut target = None;
+        for _ in 0..16 {
+            app.update();
+
+            target =
+                app.world().get::<Target>(tower).map(|t| t.entity());
+            if target.is_some() {
+                break;
+            }
+        }
+
+        assert_eq!(target, Some(enemy));
+    }
+}
@@ -9,7 +9,7 @@ use crate::asset_pipeline::animation_pipeline::{
 use crate::asset_pipeline::{AssetState, PrefabAssets, PrefabName};
 
 use super::TowerPrefabName;
-use super::tower_attack::Tower;
+use super::tower_attack::{Tower, TowerFired};
 
 pub(super) struct TowerAnimationPlugin;
 
@@ -17,9 +17,87 @@ impl Plugin for TowerAnimationPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            setup_animation_graph
+            (setup_animation_graph, return_to_idle)
                 .run_if(in_state(AssetState::Loaded)),
-        );
+        )
+        .add_observer(shoot_animation);
+    }
+}
+
+/// Play the one-shot shoot animation in parallel with the
+/// projectile spawn.
+///
+/// Towers without a shoot animation (or without an animation graph
+/// setup yet) are ignored.
+pub fn shoot_animation(
+    trigger: Trigger<TowerFired>,
+    q_towers: Query<(&NodeMap, &AnimationTarget), With<Tower>>,
+    mut q_animation_players: Query<(
+        &mut AnimationPlayer,
+        &mut AnimationTransitions,
+    )>,
+) {
+    let Ok((node_map, animation_target)) =
+        q_towers.get(trigger.target())
+    else {
+        return;
+    };
+
+    let Some(shoot_node) = node_map.get("OnShoot").copied() else {
+        return;
+    };
+
+    let Ok((mut anim_player, mut anim_transitions)) =
+        q_animation_players.get_mut(animation_target.player)
+    else {
+        return;
+    };
+
+    // Restart from the beginning for rapid consecutive shots.
+    anim_transitions
+        .play(&mut anim_player, shoot_node, Duration::ZERO)
+        .replay();
+}
+
+/// Cross-fade back to the idle animation (if any)
+/// once the shoot animation finishes.
+fn return_to_idle(
+    q_towers: Query<(&NodeMap, &AnimationTarget), With<Tower>>,
+    mut q_animation_players: Query<(
+        &mut AnimationPlayer,
+        &mut AnimationTransitions,
+    )>,
+) {
+    for (node_map, animation_target) in q_towers.iter() {
+        let (Some(shoot_node), Some(idle_node)) = (
+            node_map.get("OnShoot").copied(),
+            node_map.get("Idle").copied(),
+        ) else {
+            continue;
+        };
+
+        // Keep the other towers going without a player.
+        let Ok((mut anim_player, mut anim_transitions)) =
+            q_animation_players.get_mut(animation_target.player)
+        else {
+            continue;
+        };
+
+        let shoot_finished = anim_player
+            .animation(shoot_node)
+            .is_some_and(|a| a.is_finished());
+
+        if shoot_finished
+            && anim_player.is_playing_animation(idle_node) == false
+        {
+            anim_transitions
+                .play(
+                    &mut anim_player,
+                    idle_node,
+                    Duration::from_millis(200),
+                )
+                .repeat();
+        }
     }
 }
 
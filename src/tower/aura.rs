@@ -0,0 +1,197 @@
+use core::time::Duration;
+
+use avian3d::prelude::*;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+
+use crate::physics::GameLayer;
+
+use super::tower_attack::Tower;
+
+pub(crate) struct AuraPlugin;
+
+impl Plugin for AuraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            apply_aura_buffs.run_if(on_timer(
+                Duration::from_secs_f32(AuraBuff::REFRESH_INTERVAL),
+            )),
+        );
+
+        app.register_type::<AuraBuff>()
+            .register_type::<AuraBuffed>();
+    }
+}
+
+/// Recalculate the [`AuraBuffed`] stats of every [`Tower`].
+///
+/// Buffs from overlapping auras do not stack, the strongest of each
+/// stat is used instead. Towers that are no longer in range of any
+/// aura (or whose aura tower got destroyed) lose their buff.
+fn apply_aura_buffs(
+    mut commands: Commands,
+    q_auras: Query<(&AuraBuff, &GlobalTransform, Entity)>,
+    q_towers: Query<(Option<&AuraBuffed>, Entity), With<Tower>>,
+    q_collider_ofs: Query<&ColliderOf>,
+    spatial_query: SpatialQuery,
+) {
+    let mut buffs = EntityHashMap::<AuraBuffed>::default();
+
+    for (aura, global_transform, aura_entity) in q_auras.iter() {
+        let intersections = spatial_query.shape_intersections(
+            &Collider::sphere(aura.radius),
+            global_transform.translation(),
+            Quat::IDENTITY,
+            &GameLayer::Tower.query_filter(),
+        );
+
+        for entity in intersections {
+            let tower_entity = q_collider_ofs
+                .get(entity)
+                .map(|c| c.body)
+                .unwrap_or(entity);
+
+            // Auras don't buff their own tower.
+            if tower_entity == aura_entity {
+                continue;
+            }
+
+            if q_towers.contains(tower_entity) == false {
+                continue;
+            }
+
+            let buff = buffs.entry(tower_entity).or_default();
+            buff.damage_mult = buff.damage_mult.max(aura.damage_mult);
+            buff.cooldown_mult =
+                buff.cooldown_mult.min(aura.cooldown_mult);
+        }
+    }
+
+    for (current_buff, entity) in q_towers.iter() {
+        match buffs.remove(&entity) {
+            Some(buff) => {
+                if current_buff != Some(&buff) {
+                    commands.entity(entity).insert(buff);
+                }
+            }
+            None => {
+                if current_buff.is_some() {
+                    commands.entity(entity).remove::<AuraBuffed>();
+                }
+            }
+        }
+    }
+}
+
+/// Support tower that boosts the stats of nearby [`Tower`]s.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct AuraBuff {
+    pub radius: f32,
+    /// Multiplier of the buffed tower's damage.
+    pub damage_mult: f32,
+    /// Multiplier of the buffed tower's attack cooldown
+    /// (lower is faster).
+    pub cooldown_mult: f32,
+}
+
+impl AuraBuff {
+    /// Interval in seconds between each buff recalculation.
+    pub const REFRESH_INTERVAL: f32 = 0.25;
+}
+
+/// Temporary stat boosts from the strongest [`AuraBuff`]s in range.
+///
+/// Applied on top of the base [`Tower`] stats when shooting.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct AuraBuffed {
+    pub damage_mult: f32,
+    pub cooldown_mult: f32,
+}
+
+impl Default for AuraBuffed {
+    fn default() -> Self {
+        Self {
+            damage_mult: 1.0,
+            cooldown_mult: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::platform::collections::HashMap;
+
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app};
+    use crate::tower::tower_attack::TowerFired;
+
+    #[derive(Resource, Default)]
+    struct FireCounts(HashMap<Entity, u32>);
+
+    #[test]
+    fn test_buffed_tower_fires_faster() {
+        let mut app = combat_app();
+        app.init_resource::<FireCounts>().add_observer(
+            |trigger: Trigger<TowerFired>,
+             mut counts: ResMut<FireCounts>| {
+                *counts.0.entry(trigger.target()).or_default() += 1;
+            },
+        );
+
+        let tower = || Tower {
+            range: 10.0,
+            damage: 1.0,
+            attack_cooldown: 1.0,
+            projectile_speed: 20.0,
+        };
+        let buffed_tower = app.spawn_tower(
+            "gun_tower",
+            tower(),
+            Vec3::new(-3.0, 0.0, 0.0),
+        );
+        let normal_tower = app.spawn_tower(
+            "gun_tower",
+            tower(),
+            Vec3::new(3.0, 0.0, 0.0),
+        );
+        for entity in [buffed_tower, normal_tower] {
+            app.world_mut()
+                .entity_mut(entity)
+                .insert(Collider::sphere(0.5));
+        }
+
+        app.world_mut().spawn((
+            AuraBuff {
+                radius: 2.0,
+                damage_mult: 1.0,
+                cooldown_mult: 0.5,
+            },
+            Transform::from_xyz(-4.0, 0.0, 0.0),
+        ));
+
+        app.spawn_enemy(10_000.0, Vec3::new(0.0, 0.0, 4.0));
+
+        // 4 seconds of simulation.
+        app.step(256);
+
+        assert!(
+            app.world().get::<AuraBuffed>(buffed_tower).is_some()
+        );
+        assert!(
+            app.world().get::<AuraBuffed>(normal_tower).is_none()
+        );
+
+        let counts = &app.world().resource::<FireCounts>().0;
+        let buffed_count = counts.get(&buffed_tower).copied();
+        let normal_count = counts.get(&normal_tower).copied();
+        assert!(
+            buffed_count > normal_count,
+            "Buffed tower should fire faster: \
+            {buffed_count:?} vs {normal_count:?}."
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use bevy::color::palettes::tailwind::*;
+use bevy::prelude::*;
+
+use crate::camera_controller::{A_RENDER_LAYER, B_RENDER_LAYER};
+use crate::enemy::{Armor, Enemy};
+use crate::player::player_attack::AttackCooldown;
+
+use super::aura::AuraBuffed;
+use super::enemy_grid::EnemyGrid;
+use super::tower_attack::{
+    DamageHit, Health, Invulnerable, MIN_FACING_ACCURACY, MaxHealth,
+    SpawnProtection, Target, Tower, apply_damage,
+};
+
+pub(super) struct BeamPlugin;
+
+impl Plugin for BeamPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_gizmo_config(
+            BeamGizmos,
+            GizmoConfig {
+                line: GizmoLineConfig {
+                    width: 4.0,
+                    ..default()
+                },
+                // Visible on both split-screen halves.
+                render_layers: A_RENDER_LAYER.union(&B_RENDER_LAYER),
+                ..default()
+            },
+        )
+        .add_systems(
+            PostUpdate,
+            draw_beams.after(TransformSystem::TransformPropagate),
+        );
+
+        app.register_type::<Beam>();
+    }
+}
+
+/// Continuously damage the [`Target`] of [`Beam`] towers,
+/// along with the enemies lined up behind it, while facing it.
+///
+/// A dying target is dropped by the [`Target`] relationship
+/// and a new one is picked up by the normal targeting.
+///
+/// Hit feedback is only given once per [`AttackCooldown`]
+/// instead of every frame.
+pub(super) fn beam_damage(
+    mut commands: Commands,
+    mut q_towers: Query<(
+        &Transform,
+        &GlobalTransform,
+        &Tower,
+        &Beam,
+        &mut BeamEnd,
+        &mut AttackCooldown,
+        Option<&AuraBuffed>,
+        Option<&Target>,
+        Entity,
+    )>,
+    q_global_transforms: Query<&GlobalTransform, With<Enemy>>,
+    mut q_healths: Query<
+        (&mut Health, Option<&MaxHealth>, Option<&Armor>),
+        (
+            With<Enemy>,
+            Without<Invulnerable>,
+            Without<SpawnProtection>,
+        ),
+    >,
+    enemy_grid: Res<EnemyGrid>,
+    time: Res<Time>,
+) {
+    for (
+        transform,
+        global_transform,
+        tower,
+        beam,
+        mut beam_end,
+        mut cooldown,
+        aura_buffed,
+        target,
+        tower_entity,
+    ) in q_towers.iter_mut()
+    {
+        beam_end.set_if_neq(BeamEnd(None));
+
+        let Some(target_position) = target
+            .and_then(|target| {
+                q_global_transforms.get(target.entity()).ok()
+            })
+            .map(|t| t.translation() + Vec3::Y * 0.5)
+        else {
+            continue;
+        };
+
+        let tower_position = global_transform.translation();
+        let facing_dot = (-transform.forward())
+            .dot((target_position - tower_position).normalize());
+
+        if facing_dot < MIN_FACING_ACCURACY {
+            continue;
+        }
+
+        let muzzle = tower_position + Vec3::Y * 0.5;
+        let Ok(direction) = Dir3::new(target_position - muzzle)
+        else {
+            continue;
+        };
+
+        // Enemies lined up behind the target, closest first.
+        let mut pierced = enemy_grid
+            .in_radius(muzzle, tower.range)
+            .filter(|entity| {
+                target.is_some_and(|t| t.entity() != *entity)
+            })
+            .filter_map(|entity| {
+                let position = q_global_transforms
+                    .get(entity)
+                    .ok()?
+                    .translation()
+                    + Vec3::Y * 0.5;
+                let along = (position - muzzle).dot(*direction);
+                let offset = position - muzzle - direction * along;
+
+                (along > 0.0 && offset.length() <= beam.width)
+                    .then_some((entity, along))
+            })
+            .collect::<Vec<_>>();
+        pierced.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        pierced.truncate(beam.pierce);
+
+        // Per second.
+        let damage = tower.damage
+            * aura_buffed.copied().unwrap_or_default().damage_mult;
+
+        let feedback = cooldown.0 <= 0.0;
+        if feedback {
+            cooldown.0 = tower.attack_cooldown;
+        }
+
+        let mut end_distance = muzzle.distance(target_position);
+        let hits = target
+            .map(|t| t.entity())
+            .into_iter()
+            .chain(pierced.iter().map(|(entity, _)| *entity));
+
+        for entity in hits {
+            let Ok((mut health, max_health, armor)) =
+                q_healths.get_mut(entity)
+            else {
+                continue;
+            };
+
+            // Already dead, waiting to be despawned.
+            if health.0 <= 0.0 {
+                continue;
+            }
+
+            apply_damage(
+                &mut commands,
+                DamageHit {
+                    entity,
+                    is_enemy: true,
+                    tower: Some(tower_entity),
+                    feedback,
+                },
+                (&mut health, max_health, armor),
+                damage,
+                time.delta_secs(),
+            );
+        }
+
+        if let Some((_, along)) = pierced.last() {
+            end_distance = end_distance.max(*along);
+        }
+
+        beam_end.0 = Some(muzzle + direction * end_distance);
+    }
+}
+
+/// Draw the beams from the muzzle of the towers to
+/// their [`BeamEnd`].
+fn draw_beams(
+    mut gizmos: Gizmos<BeamGizmos>,
+    q_beams: Query<(&GlobalTransform, &BeamEnd)>,
+) {
+    for (global_transform, beam_end) in q_beams.iter() {
+        let Some(end) = beam_end.0 else {
+            continue;
+        };
+
+        gizmos.line(
+            global_transform.translation() + Vec3::Y * 0.5,
+            end,
+            CYAN_300,
+        );
+    }
+}
+
+/// Towers with this component continuously damage their
+/// [`Target`] instead of firing projectiles.
+///
+/// [`Tower::damage`] is applied per second.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+#[require(BeamEnd)]
+pub struct Beam {
+    /// Number of enemies behind the target that are also damaged.
+    pub pierce: usize,
+    /// Max distance of the pierced enemies from the beam.
+    pub width: f32,
+}
+
+impl Default for Beam {
+    fn default() -> Self {
+        Self {
+            pierce: 0,
+            width: 0.5,
+        }
+    }
+}
+
+/// Where the [`Beam`] currently ends, [`None`] when not firing.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq)]
+pub struct BeamEnd(pub Option<Vec3>);
+
+/// Gizmo group of the tower beams.
+#[derive(GizmoConfigGroup, Reflect, Default)]
+pub struct BeamGizmos;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app};
+    use crate::tower::Projectile;
+
+    #[test]
+    fn test_beam_drains_target_health() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                // Per second.
+                damage: 10.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut().entity_mut(tower).insert(Beam::default());
+        let enemy = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+
+        let health =
+            |app: &App| app.world().get::<Health>(enemy).unwrap().0;
+
+        app.step(32);
+        let first = health(&app);
+        assert!(first < 100.0, "Beam should be damaging the target.");
+        assert!(
+            app.world().get::<BeamEnd>(tower).unwrap().0.is_some()
+        );
+
+        // 2 more seconds.
+        app.step(128);
+        let second = health(&app);
+        assert!(
+            (first - second - 20.0).abs() < 1.0,
+            "Expected ~20 damage over 2 seconds, got {}.",
+            first - second
+        );
+
+        let projectile_count = app
+            .world_mut()
+            .query::<&Projectile>()
+            .iter(app.world())
+            .count();
+        assert_eq!(projectile_count, 0);
+    }
+
+    #[test]
+    fn test_beam_reacquires_after_target_death() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                // Per second.
+                damage: 40.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut().entity_mut(tower).insert(Beam::default());
+        let first = app.spawn_enemy(10.0, Vec3::new(0.0, 0.0, 3.0));
+
+        app.step(16);
+        assert_eq!(
+            app.world().get::<Target>(tower).map(|t| t.entity()),
+            Some(first)
+        );
+
+        let second = app.spawn_enemy(100.0, Vec3::new(3.0, 0.0, 3.0));
+        app.step(64);
+
+        let world = app.world();
+        assert!(world.get_entity(first).is_err());
+        assert_eq!(
+            world.get::<Target>(tower).map(|t| t.entity()),
+            Some(second)
+        );
+        assert!(
+            world.get::<Health>(second).unwrap().0 < 100.0,
+            "Beam should be damaging the new target."
+        );
+        app.assert_no_dangling_targets();
+    }
+
+    #[test]
+    fn test_beam_hit_feedback_follows_attack_cooldown() {
+        use crate::enemy::EnemyHit;
+
+        #[derive(Resource, Default)]
+        struct HitCount(usize);
+
+        let mut app = combat_app();
+        app.init_resource::<HitCount>().add_observer(
+            |_: Trigger<EnemyHit>, mut count: ResMut<HitCount>| {
+                count.0 += 1;
+            },
+        );
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                // Per second.
+                damage: 1.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut().entity_mut(tower).insert(Beam::default());
+        app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+
+        // 2 seconds, damaging every frame.
+        app.step(128);
+
+        let hit_count = app.world().resource::<HitCount>().0;
+        assert!(
+            (1..=3).contains(&hit_count),
+            "Expected a hit per second, got {hit_count}."
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+use crate::action::{PlayerAction, TargetAction};
+use crate::character_controller::CharacterController;
+use crate::interaction::Occupied;
+use crate::inventory::Inventory;
+
+use super::InPlacementMode;
+
+pub(super) struct BuildToolPlugin;
+
+impl Plugin for BuildToolPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (open_radial_menu, update_radial_menu).chain(),
+        )
+        .add_observer(select_radial_slot);
+
+        app.register_type::<BuildTool>();
+    }
+}
+
+/// Open the [`RadialMenu`] when a player holding the
+/// [`BuildTool`] presses [`PlayerAction::Placement`].
+fn open_radial_menu(
+    mut commands: Commands,
+    q_players: Query<
+        (&Inventory, &Children, &TargetAction, Entity),
+        (
+            With<CharacterController>,
+            With<Occupied>,
+            Without<InPlacementMode>,
+            Without<RadialMenu>,
+        ),
+    >,
+    q_build_tools: Query<(), With<BuildTool>>,
+    q_actions: Query<&ActionState<PlayerAction>>,
+) {
+    for (inventory, children, target_action, entity) in
+        q_players.iter()
+    {
+        if holds_build_tool(children, &q_build_tools) == false {
+            continue;
+        }
+
+        let Ok(action) = q_actions.get(target_action.get()) else {
+            continue;
+        };
+
+        if action.just_pressed(&PlayerAction::Placement) == false {
+            continue;
+        }
+
+        let slots = inventory.tower_order();
+        if slots.is_empty() {
+            continue;
+        }
+
+        commands.entity(entity).insert(RadialMenu {
+            slots,
+            hovered: None,
+        });
+    }
+}
+
+/// Hover the slot that the player aims at, and select it once
+/// [`PlayerAction::Placement`] is released.
+fn update_radial_menu(
+    mut commands: Commands,
+    mut q_players: Query<(&mut RadialMenu, &TargetAction, Entity)>,
+    q_actions: Query<&ActionState<PlayerAction>>,
+) {
+    /// Min stick tilt to hover a slot.
+    const DEADZONE: f32 = 0.5;
+
+    for (mut menu, target_action, entity) in q_players.iter_mut() {
+        let Ok(action) = q_actions.get(target_action.get()) else {
+            continue;
+        };
+
+        if action.just_pressed(&PlayerAction::Cancel) {
+            commands.entity(entity).remove::<RadialMenu>();
+            continue;
+        }
+
+        let aim = action.axis_pair(&PlayerAction::Aim);
+        if aim.length() >= DEADZONE {
+            let hovered = radial_slot(aim, menu.slots.len());
+            if menu.hovered != hovered {
+                menu.hovered = hovered;
+            }
+        }
+
+        if action.just_released(&PlayerAction::Placement) {
+            match menu.hovered {
+                Some(slot) => {
+                    commands.trigger_targets(
+                        SelectRadialSlot(slot),
+                        entity,
+                    );
+                }
+                // Released without picking anything.
+                None => {
+                    commands.entity(entity).remove::<RadialMenu>();
+                }
+            }
+        }
+    }
+}
+
+/// Select the tower of the slot and enter placement mode,
+/// placement is then validated like any other tower.
+fn select_radial_slot(
+    trigger: Trigger<SelectRadialSlot>,
+    mut commands: Commands,
+    mut q_players: Query<(&RadialMenu, &mut Inventory)>,
+) {
+    let entity = trigger.target();
+    let Ok((menu, mut inventory)) = q_players.get_mut(entity) else {
+        return;
+    };
+
+    commands.entity(entity).remove::<RadialMenu>();
+
+    let Some(tower_id) = menu.slots.get(trigger.0) else {
+        return;
+    };
+
+    // Could have been placed while the menu was open.
+    if inventory.towers().get(tower_id).copied().unwrap_or(0) == 0 {
+        return;
+    }
+
+    inventory.selected_tower = Some(tower_id.clone());
+    commands.entity(entity).insert(InPlacementMode);
+}
+
+/// Whether the player's held item is a [`BuildTool`].
+pub fn holds_build_tool(
+    children: &Children,
+    q_build_tools: &Query<(), With<BuildTool>>,
+) -> bool {
+    children.iter().any(|child| q_build_tools.contains(child))
+}
+
+/// Slot of a [`RadialMenu`] with `count` slots in the given
+/// direction, the first slot is at the top going clockwise.
+pub fn radial_slot(direction: Vec2, count: usize) -> Option<usize> {
+    if count == 0 || direction == Vec2::ZERO {
+        return None;
+    }
+
+    let slot_angle = core::f32::consts::TAU / count as f32;
+    // Clockwise from the top.
+    let angle = f32::atan2(direction.x, direction.y)
+        .rem_euclid(core::f32::consts::TAU);
+
+    Some(((angle + slot_angle * 0.5) / slot_angle) as usize % count)
+}
+
+/// A grabbable tool that lets the holder pick a tower from
+/// a [`RadialMenu`] instead of cycling through the hotbar.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct BuildTool;
+
+/// Tower selection of a player holding the [`BuildTool`],
+/// open while [`PlayerAction::Placement`] is held.
+#[derive(Component, Debug, Clone)]
+pub struct RadialMenu {
+    /// Tower ids of every slot.
+    pub slots: Vec<String>,
+    pub hovered: Option<usize>,
+}
+
+/// Triggered on the player to select a slot of its [`RadialMenu`].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SelectRadialSlot(pub usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_selecting_radial_slot_sets_placement_tower() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_observer(select_radial_slot);
+
+        let mut inventory = Inventory::default();
+        inventory.add_tower("gun_tower".to_string(), 2, 10);
+        inventory.add_tower("cannon_tower".to_string(), 1, 10);
+        let slots = inventory.tower_order();
+
+        let player = app
+            .world_mut()
+            .spawn((
+                inventory,
+                RadialMenu {
+                    slots: slots.clone(),
+                    hovered: None,
+                },
+            ))
+            .id();
+
+        // Aiming down-right of two slots picks the second one.
+        let slot = radial_slot(Vec2::new(0.2, -1.0), slots.len());
+        assert_eq!(slot, Some(1));
+
+        app.world_mut().trigger_targets(SelectRadialSlot(1), player);
+        app.world_mut().flush();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<Inventory>(player).unwrap().selected_tower,
+            Some(slots[1].clone())
+        );
+        assert!(world.get::<InPlacementMode>(player).is_some());
+        assert!(world.get::<RadialMenu>(player).is_none());
+    }
+}
@@ -0,0 +1,109 @@
+use std::borrow::Cow;
+use std::time::Duration;
+
+use bevy::ecs::system::{Adapt, AdapterSystem, SystemIn};
+use bevy::platform::sync::Arc;
+use bevy::platform::sync::atomic::{AtomicU64, Ordering};
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+
+/// Run time of the last run of every combat system registered
+/// through [`CombatTimings::timed`].
+///
+/// Read by the debug overlay to show the heaviest combat system.
+#[derive(Resource, Default, Debug)]
+pub struct CombatTimings {
+    systems: Vec<(Cow<'static, str>, Arc<AtomicU64>)>,
+}
+
+impl CombatTimings {
+    /// Wrap `system` so that the duration of each of its runs
+    /// gets recorded.
+    pub fn timed<S, O, M>(
+        &mut self,
+        system: S,
+    ) -> impl System<In = (), Out = O> + use<S, O, M>
+    where
+        S: IntoSystem<(), O, M>,
+        O: 'static,
+    {
+        let system = IntoSystem::into_system(system);
+        let name = system.name();
+        let nanos = Arc::new(AtomicU64::new(0));
+
+        self.systems.push((name.clone(), nanos.clone()));
+        AdapterSystem::new(RecordRunTime(nanos), system, name)
+    }
+
+    /// Name (without its module path) and last run time of the
+    /// slowest combat system.
+    pub fn heaviest(&self) -> Option<(&str, Duration)> {
+        self.systems
+            .iter()
+            .map(|(name, nanos)| {
+                (name, nanos.load(Ordering::Relaxed))
+            })
+            .max_by_key(|(_, nanos)| *nanos)
+            .map(|(name, nanos)| {
+                (
+                    name.rsplit("::").next().unwrap_or(name.as_ref()),
+                    Duration::from_nanos(nanos),
+                )
+            })
+    }
+}
+
+struct RecordRunTime(Arc<AtomicU64>);
+
+impl<S: System<In = ()>> Adapt<S> for RecordRunTime {
+    type In = ();
+    type Out = S::Out;
+
+    fn adapt(
+        &mut self,
+        input: (),
+        run_system: impl FnOnce(SystemIn<'_, S>) -> S::Out,
+    ) -> S::Out {
+        let start = Instant::now();
+        let out = run_system(input);
+        self.0.store(
+            start.elapsed().as_nanos() as u64,
+            Ordering::Relaxed,
+        );
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn light_system() {}
+
+    fn heavy_system() {
+        std::thread::sleep(Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_heaviest_is_the_slowest_system() {
+        let mut app = App::new();
+        let mut timings = CombatTimings::default();
+        assert!(timings.heaviest().is_none());
+
+        app.add_systems(
+            Update,
+            (
+                timings.timed(light_system),
+                timings.timed(heavy_system),
+            ),
+        );
+        app.insert_resource(timings);
+        app.update();
+
+        let timings = app.world().resource::<CombatTimings>();
+        let (name, duration) = timings.heaviest().unwrap();
+        assert_eq!(name, "heavy_system");
+        assert!(duration >= Duration::from_millis(2));
+    }
+}
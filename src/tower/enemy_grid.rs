@@ -0,0 +1,118 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::enemy::Enemy;
+
+/// Rebuild the [`EnemyGrid`] from the current enemy positions.
+pub(super) fn rebuild_enemy_grid(
+    mut grid: ResMut<EnemyGrid>,
+    q_enemies: Query<(&GlobalTransform, Entity), With<Enemy>>,
+) {
+    grid.clear();
+
+    for (global_transform, entity) in q_enemies.iter() {
+        grid.insert(global_transform.translation(), entity);
+    }
+}
+
+/// Uniform grid of enemy positions, rebuilt once per frame so that
+/// towers can look up the enemies in range without issuing
+/// their own spatial query.
+#[derive(Resource, Default, Debug)]
+pub struct EnemyGrid {
+    cells: HashMap<IVec3, Vec<(Vec3, Entity)>>,
+}
+
+impl EnemyGrid {
+    /// Side length of a single cell.
+    pub const CELL_SIZE: f32 = 4.0;
+
+    fn cell(position: Vec3) -> IVec3 {
+        (position / Self::CELL_SIZE).floor().as_ivec3()
+    }
+
+    pub fn clear(&mut self) {
+        // Keep the allocations of the cells for the next rebuild.
+        for enemies in self.cells.values_mut() {
+            enemies.clear();
+        }
+    }
+
+    pub fn insert(&mut self, position: Vec3, entity: Entity) {
+        self.cells
+            .entry(Self::cell(position))
+            .or_default()
+            .push((position, entity));
+    }
+
+    /// Enemies whose position is within `radius` of `center`.
+    pub fn in_radius(
+        &self,
+        center: Vec3,
+        radius: f32,
+    ) -> impl Iterator<Item = Entity> + '_ {
+        let min = Self::cell(center - radius);
+        let max = Self::cell(center + radius);
+        let radius_squared = radius * radius;
+
+        (min.x..=max.x)
+            .flat_map(move |x| {
+                (min.y..=max.y).flat_map(move |y| {
+                    (min.z..=max.z).map(move |z| IVec3::new(x, y, z))
+                })
+            })
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter(move |(position, _)| {
+                position.distance_squared(center) <= radius_squared
+            })
+            .map(|(_, entity)| *entity)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rng::GameRng;
+
+    #[test]
+    fn test_in_radius_matches_brute_force() {
+        let mut rng = GameRng::new(0);
+        let mut grid = EnemyGrid::default();
+        let mut enemies = Vec::new();
+
+        for index in 0..200 {
+            let position = Vec3::new(
+                rng.range_f32(-30.0, 30.0),
+                rng.range_f32(-1.0, 1.0),
+                rng.range_f32(-30.0, 30.0),
+            );
+            let entity = Entity::from_raw(index);
+            grid.insert(position, entity);
+            enemies.push((position, entity));
+        }
+
+        for _ in 0..50 {
+            let center = Vec3::new(
+                rng.range_f32(-30.0, 30.0),
+                0.0,
+                rng.range_f32(-30.0, 30.0),
+            );
+            let radius = rng.range_f32(0.5, 12.0);
+
+            let mut found =
+                grid.in_radius(center, radius).collect::<Vec<_>>();
+            let mut expected = enemies
+                .iter()
+                .filter(|(position, _)| {
+                    position.distance(center) <= radius
+                })
+                .map(|(_, entity)| *entity)
+                .collect::<Vec<_>>();
+
+            found.sort();
+            expected.sort();
+            assert_eq!(found, expected);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+
+use super::tower_attack::{Tower, TowerKill};
+
+pub(super) struct TowerExperiencePlugin;
+
+impl Plugin for TowerExperiencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(gain_tower_xp);
+
+        app.register_type::<TowerXp>();
+    }
+}
+
+/// Credit the [`TowerKill`] to the tower's [`TowerXp`],
+/// bumping its stats for every level gained.
+fn gain_tower_xp(
+    trigger: Trigger<TowerKill>,
+    mut commands: Commands,
+    mut q_towers: Query<(&mut Tower, Option<&mut TowerXp>)>,
+) {
+    let entity = trigger.target();
+    // Sold or destroyed before its projectile landed.
+    let Ok((mut tower, tower_xp)) = q_towers.get_mut(entity) else {
+        return;
+    };
+
+    let levels = match tower_xp {
+        Some(mut tower_xp) => tower_xp.gain(trigger.xp),
+        None => {
+            let mut tower_xp = TowerXp::default();
+            let levels = tower_xp.gain(trigger.xp);
+            commands.entity(entity).insert(tower_xp);
+            levels
+        }
+    };
+
+    for _ in 0..levels {
+        tower.damage *= TowerXp::DAMAGE_MULT;
+        tower.range *= TowerXp::RANGE_MULT;
+    }
+}
+
+/// Experience that a tower earns from its kills.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct TowerXp {
+    /// Experience towards the next level.
+    pub current: f32,
+    pub level: u32,
+}
+
+impl TowerXp {
+    pub const MAX_LEVEL: u32 = 5;
+    /// Multiplier of the [`Tower::damage`] per level.
+    pub const DAMAGE_MULT: f32 = 1.1;
+    /// Multiplier of the [`Tower::range`] per level.
+    pub const RANGE_MULT: f32 = 1.05;
+
+    /// Experience needed to reach the next level.
+    pub fn threshold(&self) -> f32 {
+        50.0 * (self.level + 1) as f32
+    }
+
+    /// Add experience, returning the number of levels gained.
+    pub fn gain(&mut self, xp: f32) -> u32 {
+        if self.level >= Self::MAX_LEVEL {
+            return 0;
+        }
+
+        self.current += xp;
+
+        let mut levels = 0;
+        while self.level < Self::MAX_LEVEL
+            && self.current >= self.threshold()
+        {
+            self.current -= self.threshold();
+            self.level += 1;
+            levels += 1;
+        }
+
+        levels
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app_with};
+
+    #[test]
+    fn test_killing_blow_levels_up_tower() {
+        let mut app = combat_app_with(TowerExperiencePlugin);
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 100.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        let enemy = app.spawn_enemy(60.0, Vec3::new(0.0, 0.0, 3.0));
+
+        app.step(64);
+        assert!(app.world().get_entity(enemy).is_err());
+
+        let world = app.world();
+        let tower_xp = world.get::<TowerXp>(tower).unwrap();
+        assert_eq!(tower_xp.level, 1);
+        assert_eq!(tower_xp.current, 10.0);
+        assert_eq!(
+            world.get::<Tower>(tower).unwrap().damage,
+            100.0 * TowerXp::DAMAGE_MULT
+        );
+    }
+
+    #[test]
+    fn test_projectile_of_sold_tower_still_kills() {
+        use crate::tower::tower_attack::FiredBy;
+
+        let mut app = combat_app_with(TowerExperiencePlugin);
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 100.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 5.0,
+            },
+            Vec3::ZERO,
+        );
+        let enemy = app.spawn_enemy(60.0, Vec3::new(0.0, 0.0, 8.0));
+
+        let in_flight = |app: &mut App| {
+            app.world_mut()
+                .query::<&FiredBy>()
+                .iter(app.world())
+                .any(|fired_by| fired_by.0 == tower)
+        };
+        for _ in 0..64 {
+            if in_flight(&mut app) {
+                break;
+            }
+            app.step(1);
+        }
+        assert!(in_flight(&mut app), "Tower should have fired.");
+
+        // Sold while its projectile is in flight.
+        app.world_mut().despawn(tower);
+        app.step(128);
+
+        assert!(
+            app.world().get_entity(enemy).is_err(),
+            "The projectile should still land the killing blow."
+        );
+    }
+
+    #[test]
+    fn test_beam_kill_levels_up_tower() {
+        use crate::tower::beam::Beam;
+
+        let mut app = combat_app_with(TowerExperiencePlugin);
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                // Per second.
+                damage: 100.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut().entity_mut(tower).insert(Beam::default());
+        let enemy = app.spawn_enemy(60.0, Vec3::new(0.0, 0.0, 3.0));
+
+        app.step(128);
+        assert!(app.world().get_entity(enemy).is_err());
+
+        let tower_xp = app.world().get::<TowerXp>(tower).unwrap();
+        assert_eq!(tower_xp.level, 1);
+    }
+}
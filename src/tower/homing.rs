@@ -0,0 +1,248 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::enemy::Enemy;
+use crate::physics::GameLayer;
+
+use super::Projectile;
+
+pub(crate) struct HomingPlugin;
+
+impl Plugin for HomingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, homing_projectiles);
+
+        app.register_type::<HomingShots>();
+    }
+}
+
+/// Steer [`Homing`] projectiles towards their target, reacquiring
+/// a new one in front of the projectile if the target dies mid-flight.
+fn homing_projectiles(
+    mut q_projectiles: Query<(
+        &Transform,
+        &mut Projectile,
+        &mut Homing,
+    )>,
+    q_enemies: Query<&GlobalTransform, With<Enemy>>,
+    q_collider_ofs: Query<&ColliderOf>,
+    spatial_query: SpatialQuery,
+    time: Res<Time>,
+) {
+    let delta_time = time.delta_secs();
+
+    for (transform, mut projectile, mut homing) in
+        q_projectiles.iter_mut()
+    {
+        let position = transform.translation;
+        let Some(direction) = projectile.velocity.try_normalize()
+        else {
+            continue;
+        };
+
+        let target_lost = homing.target.is_some_and(|target| {
+            q_enemies.contains(target) == false
+        });
+
+        if target_lost {
+            homing.target = None;
+
+            if homing.reacquisitions > 0 {
+                homing.reacquisitions -= 1;
+
+                homing.target = spatial_query
+                    .shape_intersections(
+                        &Collider::sphere(Homing::REACQUIRE_RADIUS),
+                        position,
+                        Quat::IDENTITY,
+                        &GameLayer::Enemy.query_filter(),
+                    )
+                    .into_iter()
+                    .map(|entity| {
+                        q_collider_ofs
+                            .get(entity)
+                            .map(|c| c.body)
+                            .unwrap_or(entity)
+                    })
+                    .filter_map(|entity| {
+                        let offset =
+                            q_enemies.get(entity).ok()?.translation()
+                                - position;
+
+                        // Only enemies within the cone in front.
+                        (direction.dot(offset.normalize_or_zero())
+                            >= Homing::REACQUIRE_MIN_DOT)
+                            .then_some((entity, offset.length()))
+                    })
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(entity, _)| entity);
+            }
+        }
+
+        // Fly straight without a target.
+        let Some(target_position) = homing
+            .target
+            .and_then(|target| q_enemies.get(target).ok())
+            .map(|transform| transform.translation() + Vec3::Y * 0.5)
+        else {
+            continue;
+        };
+
+        let Some(desired_direction) =
+            (target_position - position).try_normalize()
+        else {
+            continue;
+        };
+
+        // Turn towards the target with a limited turn rate.
+        let angle = direction.angle_between(desired_direction);
+        let max_angle = homing.turn_rate * delta_time;
+        let rotation =
+            Quat::from_rotation_arc(direction, desired_direction);
+        let rotation = if angle > max_angle {
+            Quat::IDENTITY.slerp(rotation, max_angle / angle)
+        } else {
+            rotation
+        };
+
+        projectile.velocity = rotation * projectile.velocity;
+    }
+}
+
+/// Projectile that steers towards its target.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Homing {
+    pub target: Option<Entity>,
+    /// Turn rate in radians per second.
+    pub turn_rate: f32,
+    /// Number of times a new target can be acquired after
+    /// the current one dies.
+    pub reacquisitions: u32,
+}
+
+impl Homing {
+    /// Radius in which a new target is searched for.
+    pub const REACQUIRE_RADIUS: f32 = 4.0;
+    /// Cosine of the reacquisition cone's half angle (60 degrees).
+    pub const REACQUIRE_MIN_DOT: f32 = 0.5;
+}
+
+impl From<HomingShots> for Homing {
+    fn from(shots: HomingShots) -> Self {
+        Self {
+            target: None,
+            turn_rate: shots.turn_rate,
+            reacquisitions: shots.reacquisitions,
+        }
+    }
+}
+
+/// Towers with this component fire [`Homing`] projectiles.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct HomingShots {
+    /// Turn rate in radians per second.
+    pub turn_rate: f32,
+    pub reacquisitions: u32,
+}
+
+impl Default for HomingShots {
+    fn default() -> Self {
+        Self {
+            turn_rate: core::f32::consts::TAU,
+            reacquisitions: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app_with};
+    use crate::tower::tower_attack::Health;
+
+    #[test]
+    fn test_homing_reacquires_after_target_death() {
+        let mut app = combat_app_with(HomingPlugin);
+
+        let target = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 6.0));
+        let other = app.spawn_enemy(100.0, Vec3::new(1.5, 0.0, 3.0));
+        app.step(1);
+
+        let projectile = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                Projectile {
+                    velocity: Vec3::Z * 10.0,
+                    damage: 10.0,
+                    lifetime: 3.0,
+                    ..default()
+                },
+                Homing {
+                    target: Some(target),
+                    ..HomingShots::default().into()
+                },
+            ))
+            .id();
+
+        // Killed by another tower before impact.
+        app.world_mut().despawn(target);
+        app.step(1);
+
+        assert_eq!(
+            app.world().get::<Homing>(projectile).unwrap().target,
+            Some(other)
+        );
+
+        app.step(63);
+
+        assert_eq!(app.world().get::<Health>(other).unwrap().0, 90.0);
+    }
+
+    #[test]
+    fn test_homing_flies_straight_once_target_is_lost() {
+        let mut app = combat_app_with(HomingPlugin);
+
+        let target = app.spawn_enemy(100.0, Vec3::new(4.0, 0.0, 6.0));
+        app.step(1);
+
+        let projectile = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                Projectile {
+                    velocity: Vec3::Z * 10.0,
+                    damage: 10.0,
+                    lifetime: 3.0,
+                    ..default()
+                },
+                Homing {
+                    target: Some(target),
+                    reacquisitions: 0,
+                    ..HomingShots::default().into()
+                },
+            ))
+            .id();
+
+        // Curves towards the target while preserving speed.
+        app.step(4);
+        let velocity = app
+            .world()
+            .get::<Projectile>(projectile)
+            .unwrap()
+            .velocity;
+        assert!(velocity.x > 0.0);
+        assert!((velocity.length() - 10.0).abs() < 1e-3);
+
+        app.world_mut().despawn(target);
+        app.step(8);
+
+        let projectile =
+            app.world().get::<Projectile>(projectile).unwrap();
+        assert!(
+            projectile.velocity.abs_diff_eq(velocity, 1e-3),
+            "Should keep its last heading."
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use bevy::color::palettes::tailwind::*;
+use bevy::prelude::*;
+
+use crate::asset_pipeline::LevelScoped;
+use crate::camera_controller::{A_RENDER_LAYER, B_RENDER_LAYER};
+
+use super::tower_attack::{ProjectileImpact, Scorching};
+
+pub(super) struct ImpactDecalPlugin;
+
+impl Plugin for ImpactDecalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScorchAssets>()
+            .add_systems(Update, fade_scorch_decals)
+            .add_observer(spawn_scorch_decal);
+    }
+}
+
+/// Leave a [`ScorchDecal`] on the ground below the impact
+/// of a [`Scorching`] projectile.
+///
+/// The oldest decal is replaced once there are
+/// [`ScorchDecal::MAX_COUNT`] of them.
+fn spawn_scorch_decal(
+    trigger: Trigger<ProjectileImpact>,
+    mut commands: Commands,
+    q_scorching: Query<(), With<Scorching>>,
+    q_decals: Query<(&ScorchDecal, Entity)>,
+    scorch_assets: Res<ScorchAssets>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if q_scorching.contains(trigger.target()) == false {
+        return;
+    }
+
+    if q_decals.iter().len() >= ScorchDecal::MAX_COUNT {
+        if let Some((_, oldest)) =
+            q_decals.iter().min_by(|(a, _), (b, _)| {
+                a.remaining.total_cmp(&b.remaining)
+            })
+        {
+            commands.entity(oldest).despawn();
+        }
+    }
+
+    let position = trigger.position;
+    commands.spawn((
+        ScorchDecal {
+            remaining: ScorchDecal::LIFETIME,
+        },
+        Mesh3d(scorch_assets.mesh.clone()),
+        // Every decal fades on its own.
+        MeshMaterial3d(materials.add(scorch_assets.material())),
+        Transform::from_xyz(position.x, 0.02, position.z),
+        // Visible on both split-screen halves.
+        A_RENDER_LAYER.union(&B_RENDER_LAYER),
+        LevelScoped,
+    ));
+}
+
+/// Fade the [`ScorchDecal`]s out and despawn them at the
+/// end of their lifetime.
+fn fade_scorch_decals(
+    mut commands: Commands,
+    mut q_decals: Query<(
+        &mut ScorchDecal,
+        &MeshMaterial3d<StandardMaterial>,
+        Entity,
+    )>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+) {
+    for (mut decal, material, entity) in q_decals.iter_mut() {
+        decal.remaining -= time.delta_secs();
+
+        if decal.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if let Some(material) = materials.get_mut(material) {
+            let alpha = ScorchAssets::ALPHA
+                * (decal.remaining / ScorchDecal::LIFETIME);
+            material.base_color.set_alpha(alpha);
+        }
+    }
+}
+
+/// A fading mark on the ground where a [`Scorching`]
+/// projectile landed.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScorchDecal {
+    /// Seconds before the decal is despawned.
+    pub remaining: f32,
+}
+
+impl ScorchDecal {
+    pub const LIFETIME: f32 = 3.0;
+    /// Max number of decals at once.
+    pub const MAX_COUNT: usize = 32;
+}
+
+#[derive(Resource)]
+pub struct ScorchAssets {
+    pub mesh: Handle<Mesh>,
+}
+
+impl ScorchAssets {
+    /// Alpha of a fresh decal.
+    const ALPHA: f32 = 0.7;
+
+    fn material(&self) -> StandardMaterial {
+        StandardMaterial {
+            base_color: STONE_900.with_alpha(Self::ALPHA).into(),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }
+    }
+}
+
+impl FromWorld for ScorchAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(
+            Circle::new(0.6)
+                .mesh()
+                .build()
+                // Flat on the ground.
+                .rotated_by(Quat::from_rotation_x(
+                    -core::f32::consts::FRAC_PI_2,
+                )),
+        );
+
+        Self { mesh }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    #[test]
+    fn test_impact_spawns_one_decal_that_fades_out() {
+        const TIMESTEP: Duration = Duration::from_millis(100);
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                TIMESTEP,
+            ))
+            .add_plugins(ImpactDecalPlugin);
+
+        let decal_count = |app: &mut App| {
+            app.world_mut()
+                .query::<&ScorchDecal>()
+                .iter(app.world())
+                .len()
+        };
+
+        let plain = app.world_mut().spawn_empty().id();
+        let scorching = app.world_mut().spawn(Scorching).id();
+        let impact = ProjectileImpact {
+            position: Vec3::new(1.0, 0.5, 2.0),
+        };
+
+        // Only scorching projectiles leave a mark.
+        app.world_mut().trigger_targets(impact, plain);
+        app.world_mut().trigger_targets(impact, scorching);
+        app.world_mut().flush();
+        assert_eq!(decal_count(&mut app), 1);
+
+        let lifetime_steps =
+            (ScorchDecal::LIFETIME / TIMESTEP.as_secs_f32()) as usize;
+        for _ in 0..lifetime_steps + 2 {
+            app.update();
+        }
+        assert_eq!(decal_count(&mut app), 0);
+    }
+}
@@ -0,0 +1,397 @@
+use avian3d::prelude::*;
+use bevy::color::palettes::tailwind::*;
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+
+use crate::camera_controller::split_screen::{
+    CameraType, QueryCameras,
+};
+use crate::camera_controller::{A_RENDER_LAYER, B_RENDER_LAYER};
+use crate::character_controller::CharacterController;
+use crate::physics::GameLayer;
+use crate::player::PlayerType;
+
+use super::range_gizmo::Selected;
+use super::tower_attack::{Target, Tower};
+use super::upgrade::{TowerTier, TowerTiersRegistry};
+use super::{InPlacementMode, TowerPrefabName};
+
+pub(super) struct TowerInspectPlugin;
+
+impl Plugin for TowerInspectPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_gizmo_config(
+            InspectGizmosA,
+            inspect_gizmo_config(A_RENDER_LAYER),
+        )
+        .insert_gizmo_config(
+            InspectGizmosB,
+            inspect_gizmo_config(B_RENDER_LAYER),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                select_inspected_tower,
+                mark_selected_towers,
+                mark_upgrade_previews,
+                draw_inspected_tower,
+            )
+                .chain()
+                .after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+fn inspect_gizmo_config(render_layers: RenderLayers) -> GizmoConfig {
+    GizmoConfig {
+        line: GizmoLineConfig {
+            width: 2.0,
+            ..default()
+        },
+        // Only visible on the inspecting player's camera.
+        render_layers,
+        ..default()
+    }
+}
+
+/// Select the placed tower that the player is looking at,
+/// falling back to the nearest one in front of the player.
+fn select_inspected_tower(
+    mut commands: Commands,
+    q_players: Query<
+        (
+            &PlayerType,
+            &GlobalTransform,
+            Option<&InspectedTower>,
+            Entity,
+        ),
+        (With<CharacterController>, Without<InPlacementMode>),
+    >,
+    q_towers: Query<(&GlobalTransform, Entity), With<Tower>>,
+    q_parents: Query<&ChildOf>,
+    q_cameras: QueryCameras<&GlobalTransform>,
+    spatial_query: SpatialQuery,
+) {
+    for (player_type, player_transform, inspected, entity) in
+        q_players.iter()
+    {
+        let player_position = player_transform.translation();
+        let in_range = |tower_position: Vec3| {
+            tower_position.distance(player_position)
+                <= InspectedTower::RANGE
+        };
+
+        let camera_type = match player_type {
+            PlayerType::A => CameraType::A,
+            PlayerType::B => CameraType::B,
+        };
+
+        // The tower under the crosshair.
+        let aimed_tower = q_cameras
+            .get(camera_type)
+            .ok()
+            .and_then(|camera_transform| {
+                let origin = camera_transform.translation();
+
+                spatial_query.cast_ray(
+                    origin,
+                    camera_transform.forward(),
+                    origin.distance(player_position)
+                        + InspectedTower::RANGE,
+                    true,
+                    &GameLayer::Tower.query_filter(),
+                )
+            })
+            .and_then(|hit| {
+                // Colliders are usually deep within the tower's scene.
+                core::iter::once(hit.entity)
+                    .chain(q_parents.iter_ancestors(hit.entity))
+                    .find_map(|e| q_towers.get(e).ok())
+            })
+            .filter(|(tower_transform, _)| {
+                in_range(tower_transform.translation())
+            })
+            .map(|(_, tower)| tower);
+
+        let player_forward = player_transform.forward();
+        let tower = aimed_tower.or_else(|| {
+            q_towers
+                .iter()
+                .map(|(tower_transform, tower)| {
+                    (tower_transform.translation(), tower)
+                })
+                .filter(|(position, _)| {
+                    in_range(*position)
+                        && (*position - player_position)
+                            .dot(*player_forward)
+                            > 0.0
+                })
+                .min_by(|(a, _), (b, _)| {
+                    a.distance_squared(player_position).total_cmp(
+                        &b.distance_squared(player_position),
+                    )
+                })
+                .map(|(_, tower)| tower)
+        });
+
+        match tower {
+            Some(tower) => {
+                if inspected.map(|i| i.0) != Some(tower) {
+                    commands
+                        .entity(entity)
+                        .insert(InspectedTower(tower));
+                }
+            }
+            None => {
+                if inspected.is_some() {
+                    commands
+                        .entity(entity)
+                        .remove::<InspectedTower>();
+                }
+            }
+        }
+    }
+}
+
+/// Mark the inspected towers as [`Selected`] to show their range.
+fn mark_selected_towers(
+    mut commands: Commands,
+    // Placement has its own preview.
+    q_players: Query<&InspectedTower, Without<InPlacementMode>>,
+    q_selected: Query<Entity, (With<Tower>, With<Selected>)>,
+) {
+    for tower in q_selected.iter() {
+        if q_players.iter().any(|inspected| inspected.0 == tower)
+            == false
+        {
+            commands.entity(tower).try_remove::<Selected>();
+        }
+    }
+
+    for inspected in q_players.iter() {
+        if q_selected.contains(inspected.0) == false {
+            commands.entity(inspected.0).try_insert(Selected);
+        }
+    }
+}
+
+/// Preview the range of the inspected towers after their next
+/// upgrade, cleared once left or at the max tier.
+fn mark_upgrade_previews(
+    mut commands: Commands,
+    // Placement has its own preview.
+    q_players: Query<&InspectedTower, Without<InPlacementMode>>,
+    q_towers: Query<(
+        &Tower,
+        &TowerPrefabName,
+        Option<&TowerTier>,
+        Option<&UpgradePreview>,
+        Entity,
+    )>,
+    tiers: TowerTiersRegistry,
+) {
+    for (tower, prefab_name, tier, preview, entity) in q_towers.iter()
+    {
+        let inspected =
+            q_players.iter().any(|inspected| inspected.0 == entity);
+        let level = tier.map(|tier| tier.0).unwrap_or_default();
+        let upgraded_range = tiers
+            .get_tier(&prefab_name.0, level)
+            .filter(|_| inspected)
+            .map(|next_tier| tower.range * next_tier.range_mult);
+
+        match upgraded_range {
+            Some(range) => {
+                if preview.map(|p| p.0) != Some(range) {
+                    commands
+                        .entity(entity)
+                        .try_insert(UpgradePreview(range));
+                }
+            }
+            None => {
+                if preview.is_some() {
+                    commands
+                        .entity(entity)
+                        .try_remove::<UpgradePreview>();
+                }
+            }
+        }
+    }
+}
+
+/// Draw the targeting line and [`UpgradePreview`] of every
+/// inspected tower.
+/// The current range is drawn for being [`Selected`].
+fn draw_inspected_tower(
+    mut gizmos_a: Gizmos<InspectGizmosA>,
+    mut gizmos_b: Gizmos<InspectGizmosB>,
+    // Placement has its own preview.
+    q_players: Query<
+        (&PlayerType, &InspectedTower),
+        Without<InPlacementMode>,
+    >,
+    q_towers: Query<
+        (&GlobalTransform, Option<&UpgradePreview>, Option<&Target>),
+        With<Tower>,
+    >,
+    q_global_transforms: Query<&GlobalTransform>,
+) {
+    for (player_type, inspected) in q_players.iter() {
+        let Ok((tower_transform, preview, target)) =
+            q_towers.get(inspected.0)
+        else {
+            continue;
+        };
+
+        let tower_position = tower_transform.translation();
+        let target_position = target
+            .and_then(|t| q_global_transforms.get(t.entity()).ok())
+            .map(|t| t.translation());
+        let upgraded_range = preview.map(|preview| preview.0);
+
+        match player_type {
+            PlayerType::A => draw_inspection(
+                &mut gizmos_a,
+                tower_position,
+                target_position,
+                upgraded_range,
+            ),
+            PlayerType::B => draw_inspection(
+                &mut gizmos_b,
+                tower_position,
+                target_position,
+                upgraded_range,
+            ),
+        }
+    }
+}
+
+fn draw_inspection<G: GizmoConfigGroup>(
+    gizmos: &mut Gizmos<G>,
+    tower_position: Vec3,
+    target_position: Option<Vec3>,
+    upgraded_range: Option<f32>,
+) {
+    const COLOR: Srgba = SKY_300;
+    const UPGRADE_COLOR: Srgba = AMBER_300;
+
+    if let Some(target_position) = target_position {
+        gizmos.line(
+            tower_position + Vec3::Y * 0.5,
+            target_position + Vec3::Y * 0.5,
+            COLOR,
+        );
+    }
+
+    if let Some(upgraded_range) = upgraded_range {
+        // Flat on the XZ plane, right above the current range.
+        let isometry = Isometry3d::new(
+            tower_position + Vec3::Y * 0.06,
+            Quat::from_rotation_x(core::f32::consts::FRAC_PI_2),
+        );
+
+        gizmos
+            .circle(isometry, upgraded_range, UPGRADE_COLOR)
+            .resolution(64);
+    }
+}
+
+/// The placed tower that the player is currently inspecting.
+#[derive(Component, Deref, Debug)]
+pub struct InspectedTower(Entity);
+
+impl InspectedTower {
+    /// Max distance from the player to the inspected tower.
+    pub const RANGE: f32 = 4.0;
+}
+
+/// Range of the inspected tower after its next upgrade.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UpgradePreview(pub f32);
+
+/// Gizmo group of [`PlayerType::A`]'s inspected tower.
+#[derive(GizmoConfigGroup, Reflect, Default)]
+pub struct InspectGizmosA;
+
+/// Gizmo group of [`PlayerType::B`]'s inspected tower.
+#[derive(GizmoConfigGroup, Reflect, Default)]
+pub struct InspectGizmosB;
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::tower::upgrade::{
+        TowerTiersAsset, TowerTiersAssetHandle,
+    };
+
+    #[test]
+    fn test_inspected_tower_is_selected_until_left() {
+        let mut world = World::new();
+
+        let tower = world
+            .spawn(Tower {
+                range: 5.0,
+                damage: 1.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 10.0,
+            })
+            .id();
+        let player = world.spawn(InspectedTower(tower)).id();
+
+        world.run_system_once(mark_selected_towers).unwrap();
+        assert!(world.get::<Selected>(tower).is_some());
+
+        world.entity_mut(player).remove::<InspectedTower>();
+        world.run_system_once(mark_selected_towers).unwrap();
+        assert!(world.get::<Selected>(tower).is_none());
+    }
+
+    #[test]
+    fn test_upgrade_preview_clears_at_max_tier_and_when_left() {
+        let mut world = World::new();
+
+        let mut assets = Assets::<TowerTiersAsset>::default();
+        let tiers = assets.add(
+            ron::from_str::<TowerTiersAsset>(
+                r#"({ "gun_tower": [(range_mult: 1.5)] })"#,
+            )
+            .unwrap(),
+        );
+        world.insert_resource(assets);
+        world.insert_resource(TowerTiersAssetHandle(tiers));
+
+        let tower = world
+            .spawn((
+                Tower {
+                    range: 4.0,
+                    damage: 1.0,
+                    attack_cooldown: 1.0,
+                    projectile_speed: 10.0,
+                },
+                TowerPrefabName::new("gun_tower"),
+            ))
+            .id();
+        let player = world.spawn(InspectedTower(tower)).id();
+        let preview = |world: &World| {
+            world.get::<UpgradePreview>(tower).map(|p| p.0)
+        };
+
+        world.run_system_once(mark_upgrade_previews).unwrap();
+        assert_eq!(preview(&world), Some(6.0));
+
+        // No tier left.
+        world.entity_mut(tower).insert(TowerTier(1));
+        world.run_system_once(mark_upgrade_previews).unwrap();
+        assert_eq!(preview(&world), None);
+
+        world.entity_mut(tower).insert(TowerTier(0));
+        world.run_system_once(mark_upgrade_previews).unwrap();
+        assert_eq!(preview(&world), Some(6.0));
+
+        world.entity_mut(player).remove::<InspectedTower>();
+        world.run_system_once(mark_upgrade_previews).unwrap();
+        assert_eq!(preview(&world), None);
+    }
+}
@@ -0,0 +1,152 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::enemy::{Path, PathIndex};
+use crate::physics::GameLayer;
+use crate::tile::TileMap;
+
+/// Radius around the spot that must be free of other towers.
+pub const TOWER_CLEARANCE: f32 = 0.5;
+/// Min distance from the remaining [`Path`] of any enemy.
+pub const PATH_CLEARANCE: f32 = 1.0;
+
+/// Check whether a tower can be placed at `position`.
+///
+/// Only the part of the [`Path`]s that enemies still have to walk
+/// is kept clear, towers are free to reroute enemies in between
+/// waves.
+pub fn can_place_tower<'a>(
+    position: Vec3,
+    spatial_query: &SpatialQuery,
+    paths: impl IntoIterator<Item = (&'a Path, &'a PathIndex)>,
+) -> PlacementResult {
+    let overlaps_tower = spatial_query
+        .shape_intersections(
+            &Collider::sphere(TOWER_CLEARANCE),
+            position,
+            Quat::IDENTITY,
+            &GameLayer::Tower.query_filter(),
+        )
+        .is_empty()
+        == false;
+
+    if overlaps_tower {
+        return PlacementResult::OverlapsTower;
+    }
+
+    let point = position.xz();
+    for (path, path_index) in paths {
+        // Include the segment the enemy is walking on.
+        let waypoints = path
+            .iter()
+            .skip(path_index.saturating_sub(1))
+            .map(TileMap::tile_coord_to_world_space)
+            .collect::<Vec<_>>();
+
+        let on_path = match waypoints.as_slice() {
+            [] => false,
+            [waypoint] => waypoint.distance(point) < PATH_CLEARANCE,
+            _ => waypoints.windows(2).any(|segment| {
+                distance_to_segment(point, segment[0], segment[1])
+                    < PATH_CLEARANCE
+            }),
+        };
+
+        if on_path {
+            return PlacementResult::OnEnemyPath;
+        }
+    }
+
+    PlacementResult::Valid
+}
+
+fn distance_to_segment(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared <= 0.0 {
+        return point.distance(start);
+    }
+
+    let t = ((point - start).dot(segment) / length_squared)
+        .clamp(0.0, 1.0);
+    point.distance(start + segment * t)
+}
+
+/// Outcome of [`can_place_tower`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementResult {
+    Valid,
+    /// Another tower is already there.
+    OverlapsTower,
+    /// Too close to where enemies are about to walk.
+    OnEnemyPath,
+}
+
+impl PlacementResult {
+    pub fn is_valid(&self) -> bool {
+        *self == PlacementResult::Valid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app};
+    use crate::tower::tower_attack::Tower;
+
+    fn placement_at(
+        app: &mut App,
+        position: Vec3,
+    ) -> PlacementResult {
+        app.world_mut()
+            .run_system_once(
+                move |spatial_query: SpatialQuery,
+                      q_paths: Query<(&Path, &PathIndex)>| {
+                    can_place_tower(position, &spatial_query, &q_paths)
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_placement_rejects_towers_and_enemy_paths() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 5.0,
+                damage: 1.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut()
+            .entity_mut(tower)
+            .insert(Collider::sphere(0.5));
+
+        // Walking from (-4, 4) to (-4, -4) in world space.
+        let enemy = app.spawn_enemy(10.0, Vec3::new(-4.0, 0.0, 4.0));
+        app.world_mut().entity_mut(enemy).insert(Path::new(vec![
+            IVec2::new(18, 22),
+            IVec2::new(18, 18),
+        ]));
+        app.step(2);
+
+        assert_eq!(
+            placement_at(&mut app, Vec3::ZERO),
+            PlacementResult::OverlapsTower
+        );
+        assert_eq!(
+            placement_at(&mut app, Vec3::new(-4.0, 0.0, 0.0)),
+            PlacementResult::OnEnemyPath
+        );
+        assert_eq!(
+            placement_at(&mut app, Vec3::new(4.0, 0.0, 0.0)),
+            PlacementResult::Valid
+        );
+    }
+}
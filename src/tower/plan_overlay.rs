@@ -0,0 +1,189 @@
+use bevy::color::palettes::tailwind::*;
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+use crate::action::PlayerAction;
+use crate::camera_controller::{A_RENDER_LAYER, B_RENDER_LAYER};
+use crate::enemy::FinalTarget;
+use crate::enemy::spawner::{
+    EnemySpawner, WaveCountdown, WaveStarted,
+};
+use crate::tile::TileMap;
+use crate::ui::Screen;
+
+use super::tower_attack::Tower;
+
+pub(super) struct PlanOverlayPlugin;
+
+impl Plugin for PlanOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlanOverlay>()
+            .insert_gizmo_config(
+                PlanOverlayGizmos,
+                GizmoConfig {
+                    line: GizmoLineConfig {
+                        width: 2.0,
+                        ..default()
+                    },
+                    // Visible on both split-screen halves.
+                    render_layers: A_RENDER_LAYER
+                        .union(&B_RENDER_LAYER),
+                    ..default()
+                },
+            )
+            .add_systems(
+                Update,
+                toggle_plan_overlay
+                    .run_if(in_state(Screen::EnterLevel)),
+            )
+            .add_systems(
+                PostUpdate,
+                draw_plan_overlay
+                    .after(TransformSystem::TransformPropagate)
+                    .run_if(in_state(Screen::EnterLevel))
+                    .run_if(plan_overlay_active),
+            )
+            .add_observer(hide_plan_overlay);
+    }
+}
+
+/// Whether the overlay is enabled and the players are
+/// in between waves, building their defense.
+fn plan_overlay_active(
+    overlay: Res<PlanOverlay>,
+    countdown: Res<WaveCountdown>,
+) -> bool {
+    overlay.enabled && countdown.finished() == false
+}
+
+/// Toggle the [`PlanOverlay`] when any player presses
+/// [`PlayerAction::PlanOverlay`].
+fn toggle_plan_overlay(
+    q_actions: Query<&ActionState<PlayerAction>>,
+    mut overlay: ResMut<PlanOverlay>,
+) {
+    let pressed = q_actions.iter().any(|action_state| {
+        action_state.just_pressed(&PlayerAction::PlanOverlay)
+    });
+
+    if pressed {
+        overlay.enabled = !overlay.enabled;
+    }
+}
+
+/// Hide the overlay once the enemies start coming.
+fn hide_plan_overlay(
+    _: Trigger<WaveStarted>,
+    mut overlay: ResMut<PlanOverlay>,
+) {
+    overlay.enabled = false;
+}
+
+/// Draw the path that the enemies will take from the
+/// [`EnemySpawner`] and the range of every tower.
+fn draw_plan_overlay(
+    mut gizmos: Gizmos<PlanOverlayGizmos>,
+    q_spawners: Query<&GlobalTransform, With<EnemySpawner>>,
+    q_final_target: Query<&GlobalTransform, With<FinalTarget>>,
+    q_towers: Query<(&GlobalTransform, &Tower)>,
+    tile_map: Res<TileMap>,
+    // Only pathfind again when the tile map changes.
+    mut planned_path: Local<Vec<IVec2>>,
+) {
+    const PATH_COLOR: Srgba = ROSE_400;
+    const RANGE_COLOR: Srgba = SKY_300;
+
+    if let (Ok(spawner), Ok(final_target)) =
+        (q_spawners.single(), q_final_target.single())
+    {
+        if planned_path.is_empty() || tile_map.is_changed() {
+            *planned_path = tile_map
+                .pathfind_to(
+                    &spawner.translation(),
+                    &final_target.translation(),
+                    false,
+                )
+                .unwrap_or_default();
+        }
+
+        gizmos.linestrip(
+            planned_path.iter().map(|coordinate| {
+                let position =
+                    TileMap::tile_coord_to_world_space(coordinate);
+                Vec3::new(position.x, 0.1, position.y)
+            }),
+            PATH_COLOR,
+        );
+    }
+
+    for (global_transform, tower) in q_towers.iter() {
+        // Flat on the ground.
+        let isometry = Isometry3d::new(
+            global_transform.translation() + Vec3::Y * 0.05,
+            Quat::from_rotation_x(core::f32::consts::FRAC_PI_2),
+        );
+        gizmos
+            .circle(
+                isometry,
+                tower.range,
+                RANGE_COLOR.with_alpha(0.6),
+            )
+            .resolution(64);
+    }
+}
+
+/// Player toggled overlay of the enemy path and tower ranges,
+/// only shown in between waves.
+#[derive(Resource, Default, Debug)]
+pub struct PlanOverlay {
+    pub enabled: bool,
+}
+
+/// Gizmo group of the [`PlanOverlay`].
+#[derive(GizmoConfigGroup, Reflect, Default)]
+pub struct PlanOverlayGizmos;
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::enemy::spawner::SpawnWave;
+
+    #[test]
+    fn test_plan_overlay_only_active_between_waves() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(PlanOverlay { enabled: true })
+            .init_resource::<WaveCountdown>()
+            .add_observer(hide_plan_overlay);
+
+        let active = |app: &mut App| {
+            app.world_mut()
+                .run_system_once(plan_overlay_active)
+                .unwrap()
+        };
+        let set_countdown = |app: &mut App, finished: bool| {
+            let mut countdown =
+                app.world_mut().resource_mut::<WaveCountdown>();
+            **countdown = Timer::from_seconds(10.0, TimerMode::Once);
+            if finished {
+                let duration = countdown.duration();
+                countdown.tick(duration);
+            }
+        };
+
+        // Building in between waves.
+        set_countdown(&mut app, false);
+        assert!(active(&mut app));
+
+        // Enemies are spawning.
+        set_countdown(&mut app, true);
+        assert!(active(&mut app) == false);
+
+        // Hidden once the wave starts, even in the next build phase.
+        set_countdown(&mut app, false);
+        app.world_mut().trigger(WaveStarted(SpawnWave::Two));
+        assert!(active(&mut app) == false);
+    }
+}
@@ -0,0 +1,100 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use super::Projectile;
+
+/// Return the model of a removed projectile back to the
+/// [`ProjectilePool`].
+pub(super) fn release_projectile_model(
+    trigger: Trigger<OnRemove, PooledProjectileModel>,
+    q_pooled: Query<&PooledProjectileModel>,
+    mut q_models: Query<(&ProjectileModel, &mut Visibility)>,
+    mut pool: ResMut<ProjectilePool>,
+) {
+    let Ok(model_entity) =
+        q_pooled.get(trigger.target()).map(|p| p.0)
+    else {
+        return;
+    };
+    // The model might be despawned along with the level,
+    // never hand it out again.
+    let Ok((model, mut visibility)) = q_models.get_mut(model_entity)
+    else {
+        return;
+    };
+
+    *visibility = Visibility::Hidden;
+    pool.release(model.0, model_entity);
+}
+
+/// Drop despawned models from the [`ProjectilePool`]
+/// so that they are never handed out.
+pub(super) fn forget_projectile_model(
+    trigger: Trigger<OnRemove, ProjectileModel>,
+    q_models: Query<&ProjectileModel>,
+    mut pool: ResMut<ProjectilePool>,
+) {
+    if let Ok(model) = q_models.get(trigger.target()) {
+        pool.forget(model.0, trigger.target());
+    }
+}
+
+/// Move the pooled models along with their projectiles.
+pub(super) fn follow_projectile_models(
+    q_projectiles: Query<
+        (&Transform, &PooledProjectileModel),
+        With<Projectile>,
+    >,
+    mut q_models: Query<
+        &mut Transform,
+        (With<ProjectileModel>, Without<Projectile>),
+    >,
+) {
+    for (transform, pooled) in q_projectiles.iter() {
+        let Ok(mut model_transform) = q_models.get_mut(pooled.0)
+        else {
+            continue;
+        };
+
+        model_transform.translation = transform.translation;
+    }
+}
+
+/// Hidden projectile models, ready to be attached to
+/// newly fired projectiles instead of instantiating their scenes.
+#[derive(Resource, Default, Debug)]
+pub struct ProjectilePool {
+    free: HashMap<&'static str, Vec<Entity>>,
+}
+
+impl ProjectilePool {
+    /// Take a free model of the given prefab, if any.
+    pub fn acquire(&mut self, model: &'static str) -> Option<Entity> {
+        self.free.get_mut(model)?.pop()
+    }
+
+    pub fn release(&mut self, model: &'static str, entity: Entity) {
+        self.free.entry(model).or_default().push(entity);
+    }
+
+    /// Remove a model that is no longer available.
+    pub fn forget(&mut self, model: &str, entity: Entity) {
+        if let Some(free) = self.free.get_mut(model) {
+            free.retain(|e| *e != entity);
+        }
+    }
+
+    /// Number of free models of the given prefab.
+    pub fn free_count(&self, model: &str) -> usize {
+        self.free.get(model).map(Vec::len).unwrap_or(0)
+    }
+}
+
+/// The instantiated scene of a projectile prefab, owned by the
+/// [`ProjectilePool`] and reused across projectiles.
+#[derive(Component, Deref, Debug, Clone, Copy)]
+pub struct ProjectileModel(pub &'static str);
+
+/// The [`ProjectileModel`] that a projectile is currently using.
+#[derive(Component, Deref, Debug, Clone, Copy)]
+pub struct PooledProjectileModel(pub Entity);
@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+use crate::asset_pipeline::AssetState;
+use crate::camera_controller::{A_RENDER_LAYER, B_RENDER_LAYER};
+
+use super::TowerPrefabName;
+use super::tower_attack::Tower;
+
+pub(super) struct RangeGizmoPlugin;
+
+impl Plugin for RangeGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_gizmo_config(
+            RangeGizmos,
+            GizmoConfig {
+                line: GizmoLineConfig {
+                    width: 2.0,
+                    ..default()
+                },
+                // Visible on both split-screen halves.
+                render_layers: A_RENDER_LAYER.union(&B_RENDER_LAYER),
+                ..default()
+            },
+        )
+        .add_systems(
+            PostUpdate,
+            draw_selected_tower_ranges
+                .after(TransformSystem::TransformPropagate)
+                .run_if(in_state(AssetState::Loaded)),
+        );
+
+        app.register_type::<Selected>();
+    }
+}
+
+/// Draw the [`Tower::range`] of every [`Selected`] tower
+/// as a circle flat on the ground.
+///
+/// Gizmos are redrawn every frame, so the circle is gone
+/// as soon as [`Selected`] is removed.
+fn draw_selected_tower_ranges(
+    mut gizmos: Gizmos<RangeGizmos>,
+    q_towers: Query<
+        (&GlobalTransform, &Tower, &TowerPrefabName),
+        With<Selected>,
+    >,
+) {
+    for (global_transform, tower, prefab_name) in q_towers.iter() {
+        // Flat on the XZ plane.
+        let isometry = Isometry3d::new(
+            global_transform.translation() + Vec3::Y * 0.05,
+            Quat::from_rotation_x(core::f32::consts::FRAC_PI_2),
+        );
+
+        gizmos
+            .circle(isometry, tower.range, prefab_name.color())
+            .resolution(64);
+    }
+}
+
+/// Marks a tower whose range is drawn on the ground.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct Selected;
+
+/// Gizmo group of the [`Selected`] tower ranges.
+#[derive(GizmoConfigGroup, Reflect, Default)]
+pub struct RangeGizmos;
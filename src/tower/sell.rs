@@ -0,0 +1,130 @@
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+use crate::action::{PlayerAction, TargetAction};
+use crate::player::currency::Currency;
+use crate::player::economy::EconomyConfig;
+
+use super::inspect::InspectedTower;
+use super::tower_attack::Tower;
+use super::{BuildCost, InPlacementMode};
+
+pub(super) struct TowerSellPlugin;
+
+impl Plugin for TowerSellPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, sell_inspected_tower)
+            .add_observer(sell_tower);
+    }
+}
+
+/// Seconds [`PlayerAction::Sell`] needs to be held to sell.
+pub const SELL_HOLD_SECS: f32 = 0.75;
+
+/// Sell the inspected tower once [`PlayerAction::Sell`] is held
+/// for [`SELL_HOLD_SECS`], so a stray press never sells it.
+fn sell_inspected_tower(
+    mut commands: Commands,
+    q_players: Query<
+        (&InspectedTower, &TargetAction, Entity),
+        Without<InPlacementMode>,
+    >,
+    q_actions: Query<&ActionState<PlayerAction>>,
+    // Seconds each player has been holding the sell action.
+    mut held_secs: Local<EntityHashMap<f32>>,
+    // Paused games don't advance the hold.
+    time: Res<Time<Virtual>>,
+) {
+    for (inspected, target_action, entity) in q_players.iter() {
+        let Ok(action) = q_actions.get(target_action.get()) else {
+            continue;
+        };
+
+        if action.pressed(&PlayerAction::Sell) == false {
+            held_secs.remove(&entity);
+            continue;
+        }
+
+        let held = held_secs.entry(entity).or_default();
+        let prev_held = *held;
+        *held += time.delta_secs();
+
+        // Only once per hold.
+        if *held >= SELL_HOLD_SECS && prev_held < SELL_HOLD_SECS {
+            commands.trigger_targets(SellTower, **inspected);
+        }
+    }
+}
+
+/// Despawn the tower, refunding part of its [`BuildCost`].
+/// The tower item is not returned.
+///
+/// Despawning the tower removes its [`Target`] and [`TargetsOf`]
+/// relationships along with it, freeing its tile the same way.
+///
+/// [`Target`]: super::tower_attack::Target
+/// [`TargetsOf`]: super::tower_attack::TargetsOf
+fn sell_tower(
+    trigger: Trigger<SellTower>,
+    mut commands: Commands,
+    q_towers: Query<Option<&BuildCost>, With<Tower>>,
+    economy: Res<EconomyConfig>,
+    mut currency: ResMut<Currency>,
+) {
+    let entity = trigger.target();
+    let Ok(build_cost) = q_towers.get(entity) else {
+        return;
+    };
+
+    let refund =
+        economy.refund(build_cost.map(|c| c.0).unwrap_or_default());
+    currency.0 += refund;
+    commands.entity(entity).despawn();
+
+    info!("Sold tower {entity} for {refund}.");
+}
+
+/// Triggered on a placed tower to sell it.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SellTower;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app};
+    use crate::tower::tower_attack::Target;
+
+    #[test]
+    fn test_selling_refunds_and_clears_targets() {
+        let mut app = combat_app();
+        app.add_observer(sell_tower);
+        app.world_mut().resource_mut::<EconomyConfig>().sell_refund =
+            0.8;
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 1.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        // Placed for 20, upgraded for 5.
+        app.world_mut().entity_mut(tower).insert(BuildCost(25));
+        app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 3.0));
+
+        app.step(4);
+        assert!(app.world().get::<Target>(tower).is_some());
+
+        app.world_mut().trigger_targets(SellTower, tower);
+        app.step(4);
+
+        assert!(app.world().get_entity(tower).is_err());
+        // The default refund would only be 13.
+        assert_eq!(app.world().resource::<Currency>().0, 20);
+        app.assert_no_dangling_targets();
+    }
+}
@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+
+use crate::character_controller::CharacterController;
+
+use super::tower_attack::{SpawnProtection, Tower};
+
+pub(super) struct SpawnProtectionPlugin;
+
+impl Plugin for SpawnProtectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpawnProtectionConfig>()
+            .add_systems(
+                Update,
+                (tick_spawn_protection, blink_spawn_protection)
+                    .chain(),
+            )
+            .add_observer(protect_spawned_player)
+            .add_observer(protect_placed_tower)
+            .add_observer(end_spawn_protection_blink);
+
+        app.register_type::<SpawnProtectionConfig>();
+    }
+}
+
+/// Protect players as soon as they spawn.
+fn protect_spawned_player(
+    trigger: Trigger<OnAdd, CharacterController>,
+    mut commands: Commands,
+    config: Res<SpawnProtectionConfig>,
+) {
+    grant_spawn_protection(
+        &mut commands,
+        trigger.target(),
+        config.player,
+    );
+}
+
+/// Protect towers as soon as they are placed.
+fn protect_placed_tower(
+    trigger: Trigger<OnAdd, Tower>,
+    mut commands: Commands,
+    config: Res<SpawnProtectionConfig>,
+) {
+    grant_spawn_protection(
+        &mut commands,
+        trigger.target(),
+        config.tower,
+    );
+}
+
+fn grant_spawn_protection(
+    commands: &mut Commands,
+    entity: Entity,
+    duration: f32,
+) {
+    if duration <= 0.0 {
+        return;
+    }
+
+    commands.entity(entity).try_insert(SpawnProtection {
+        remaining: duration,
+    });
+}
+
+/// Remove the [`SpawnProtection`] once it runs out.
+fn tick_spawn_protection(
+    mut commands: Commands,
+    mut q_protections: Query<(&mut SpawnProtection, Entity)>,
+    time: Res<Time>,
+) {
+    for (mut protection, entity) in q_protections.iter_mut() {
+        protection.remaining -= time.delta_secs();
+
+        if protection.remaining <= 0.0 {
+            commands.entity(entity).try_remove::<SpawnProtection>();
+        }
+    }
+}
+
+/// Blink the protected entities on and off.
+fn blink_spawn_protection(
+    mut q_protections: Query<(&SpawnProtection, &mut Visibility)>,
+) {
+    /// Blinks per second.
+    const BLINK_RATE: f32 = 8.0;
+
+    for (protection, mut visibility) in q_protections.iter_mut() {
+        let shown =
+            (protection.remaining.max(0.0) * BLINK_RATE) as u32 % 2
+                == 0;
+
+        visibility.set_if_neq(match shown {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        });
+    }
+}
+
+/// Make sure the entity isn't left hidden mid-blink.
+fn end_spawn_protection_blink(
+    trigger: Trigger<OnRemove, SpawnProtection>,
+    mut q_visibilities: Query<&mut Visibility>,
+) {
+    if let Ok(mut visibility) =
+        q_visibilities.get_mut(trigger.target())
+    {
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
+
+/// Duration of the [`SpawnProtection`] in seconds.
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource)]
+pub struct SpawnProtectionConfig {
+    pub player: f32,
+    pub tower: f32,
+}
+
+impl Default for SpawnProtectionConfig {
+    fn default() -> Self {
+        Self {
+            player: 2.0,
+            tower: 1.5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{
+        CombatTestAppExt, TIMESTEP, combat_app_with,
+    };
+    use crate::tower::tower_attack::{Health, MaxHealth};
+
+    #[test]
+    fn test_spawn_protection_blocks_damage_until_expired() {
+        const PROTECTION: f32 = 1.0;
+
+        let mut app = combat_app_with(SpawnProtectionPlugin);
+        app.insert_resource(SpawnProtectionConfig {
+            player: PROTECTION,
+            tower: PROTECTION,
+        });
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 5.0,
+                attack_cooldown: 0.25,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        let enemy =
+            app.spawn_enemy(1000.0, Vec3::new(0.0, 0.0, -3.0));
+
+        // A placed tower is protected right away.
+        app.world_mut().flush();
+        assert!(app.world().get::<SpawnProtection>(tower).is_some());
+
+        // Enemy in the middle of spawn protection.
+        app.world_mut().entity_mut(enemy).insert(SpawnProtection {
+            remaining: PROTECTION,
+        });
+
+        let health =
+            |app: &App| app.world().get::<Health>(enemy).unwrap().0;
+        let protected_steps =
+            (PROTECTION / TIMESTEP.as_secs_f32()) as usize;
+
+        app.step(protected_steps - 1);
+        assert_eq!(
+            health(&app),
+            app.world().get::<MaxHealth>(enemy).unwrap().0,
+            "Protected enemy should take no damage."
+        );
+
+        app.step(protected_steps * 2);
+        assert!(app.world().get::<SpawnProtection>(enemy).is_none());
+        assert!(app.world().get::<SpawnProtection>(tower).is_none());
+        assert!(
+            health(&app) < 1000.0,
+            "Damage should land once the protection expires."
+        );
+    }
+}
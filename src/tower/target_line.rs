@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+use crate::camera_controller::{A_RENDER_LAYER, B_RENDER_LAYER};
+use crate::settings::Settings;
+
+use super::TowerPrefabName;
+use super::tower_attack::Target;
+
+pub(super) struct TargetLinePlugin;
+
+impl Plugin for TargetLinePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_gizmo_config(
+            TargetLineGizmos,
+            GizmoConfig {
+                line: GizmoLineConfig {
+                    width: 1.0,
+                    ..default()
+                },
+                // Visible on both split-screen halves.
+                render_layers: A_RENDER_LAYER.union(&B_RENDER_LAYER),
+                ..default()
+            },
+        )
+        .add_systems(
+            PostUpdate,
+            draw_target_lines
+                .after(TransformSystem::TransformPropagate)
+                .run_if(|settings: Res<Settings>| {
+                    settings.show_target_lines
+                }),
+        );
+    }
+}
+
+/// Draw a line from every tower to its current [`Target`].
+fn draw_target_lines(
+    mut gizmos: Gizmos<TargetLineGizmos>,
+    q_towers: Query<(&GlobalTransform, &Target, &TowerPrefabName)>,
+    q_global_transforms: Query<&GlobalTransform>,
+) {
+    for (global_transform, target, prefab_name) in q_towers.iter() {
+        let Ok(target_transform) =
+            q_global_transforms.get(target.entity())
+        else {
+            continue;
+        };
+
+        gizmos.line(
+            global_transform.translation() + Vec3::Y * 0.5,
+            target_transform.translation() + Vec3::Y * 0.5,
+            prefab_name.color().with_alpha(0.4),
+        );
+    }
+}
+
+/// Gizmo group of the tower to target lines.
+#[derive(GizmoConfigGroup, Reflect, Default)]
+pub struct TargetLineGizmos;
@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{
+    EguiContextPass, EguiContexts, egui,
+};
+
+use crate::player::PlayerType;
+use crate::player::player_attack::AttackCooldown;
+
+use super::inspect::InspectedTower;
+use super::tower_attack::{
+    MIN_FACING_ACCURACY, Target, TargetingDebug, Tower,
+};
+
+/// Dev panel showing why the inspected towers picked their
+/// [`Target`], next to the world inspector.
+pub struct TargetingInspectorPlugin;
+
+impl Plugin for TargetingInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(EguiContextPass, targeting_inspector_ui);
+    }
+}
+
+/// List the candidates of the last targeting pass of every
+/// player's [`InspectedTower`].
+fn targeting_inspector_ui(
+    mut contexts: EguiContexts,
+    q_players: Query<(&PlayerType, &InspectedTower)>,
+    q_towers: Query<(
+        &Tower,
+        &GlobalTransform,
+        Option<&AttackCooldown>,
+        Option<&Target>,
+    )>,
+    q_global_transforms: Query<&GlobalTransform>,
+    targeting_debug: Res<TargetingDebug>,
+) {
+    let inspected = q_players
+        .iter()
+        .filter_map(|(player_type, inspected)| {
+            q_towers
+                .get(**inspected)
+                .ok()
+                .map(|tower| (player_type, **inspected, tower))
+        })
+        .collect::<Vec<_>>();
+
+    if inspected.is_empty() {
+        return;
+    }
+
+    let Some(ctx) = contexts.try_ctx_mut() else {
+        return;
+    };
+
+    egui::Window::new("Tower Targeting")
+        .default_pos([8.0, 320.0])
+        .show(ctx, |ui| {
+            for (
+                player_type,
+                tower_entity,
+                (tower, global_transform, cooldown, target),
+            ) in inspected
+            {
+                ui.heading(format!("{player_type:?}: {tower_entity}"));
+                ui.label(format!("Range: {:.2}", tower.range));
+                ui.label(format!(
+                    "Cooldown: {:.2}s",
+                    cooldown.map(|c| c.0).unwrap_or(0.0)
+                ));
+
+                match target {
+                    Some(target) => {
+                        ui.label(format!("Target: {}", target.entity()));
+
+                        if let Ok(target_transform) =
+                            q_global_transforms.get(target.entity())
+                        {
+                            // Same as the check before firing.
+                            let tower_position =
+                                global_transform.translation();
+                            let target_direction = (target_transform
+                                .translation()
+                                + Vec3::Y * 0.5
+                                - tower_position)
+                                .normalize_or_zero();
+                            let facing_dot = (-global_transform
+                                .forward())
+                            .dot(target_direction);
+
+                            ui.label(format!(
+                                "Facing: {facing_dot:.3} (fires at {MIN_FACING_ACCURACY})"
+                            ));
+                        }
+                    }
+                    None => {
+                        ui.label("Target: none");
+                    }
+                }
+
+                let Some(pass) = targeting_debug.get(tower_entity)
+                else {
+                    ui.label("No targeting pass yet.");
+                    ui.separator();
+                    continue;
+                };
+
+                egui::Grid::new(tower_entity)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label("Enemy");
+                        ui.label("Path");
+                        ui.label("Progress");
+                        ui.label("Score");
+                        ui.label("Finishable");
+                        ui.end_row();
+
+                        for candidate in pass.candidates.iter() {
+                            let chosen =
+                                pass.chosen == Some(candidate.enemy);
+                            let enemy = match chosen {
+                                true => format!("> {}", candidate.enemy),
+                                false => candidate.enemy.to_string(),
+                            };
+
+                            ui.label(enemy);
+                            ui.label(
+                                candidate.remaining_path.to_string(),
+                            );
+                            ui.label(format!("{:.2}", candidate.progress));
+                            ui.label(format!("{:.2}", candidate.score));
+                            ui.label(candidate.finishable.to_string());
+                            ui.end_row();
+                        }
+                    });
+
+                ui.separator();
+            }
+        });
+}
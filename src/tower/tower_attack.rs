@@ -1,111 +1,282 @@
 use avian3d::prelude::*;
 use bevy::ecs::component::{ComponentHooks, Immutable, StorageType};
+use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
+use serde::Deserialize;
 
 use crate::asset_pipeline::{
-    AssetState, CurrentScene, PrefabAssets, PrefabName,
+    AssetState, CurrentScene, LevelScoped, PrefabAssets, PrefabName,
+};
+use crate::character_controller::CharacterController;
+use crate::enemy::ability::{SplitDepth, SplitOf, SplitOnDeath};
+use crate::enemy::hit_flash::HitFlash;
+use crate::enemy::spawner::Formation;
+use crate::enemy::{
+    Armor, Bounty, DeathDrop, Enemy, EnemyDied, EnemyHit,
+    GlobalEnemyModifiers, IsEnemy, Path, PathIndex, PathProgress,
+    Slow, TargetReached, TargetTower,
 };
-use crate::enemy::{Enemy, IsEnemy, Path};
 use crate::physics::GameLayer;
-use crate::player::player_attack::AttackCooldown;
+use crate::player::PlayerType;
+use crate::player::currency::{BountyAwarded, Currency};
+use crate::player::economy::EconomyConfig;
+use crate::player::player_attack::{
+    AttackCooldown, update_cooldowns,
+};
+use crate::rng::GameRng;
+
+use super::aura::AuraBuffed;
+use super::beam::{Beam, beam_damage};
+use super::combat_timings::CombatTimings;
+use super::enemy_grid::{EnemyGrid, rebuild_enemy_grid};
+use super::homing::{Homing, HomingShots};
+use super::projectile_pool::{
+    PooledProjectileModel, ProjectileModel, ProjectilePool,
+    follow_projectile_models, forget_projectile_model,
+    release_projectile_model,
+};
+use super::{OnHitEffect, Projectile, TowerPrefabName};
 
-use super::{Projectile, TowerPrefabName};
+/// Minimum facing accuracy to fire.
+pub(super) const MIN_FACING_ACCURACY: f32 = 0.9;
 
-pub(super) struct TowerAttackPlugin;
+pub(crate) struct TowerAttackPlugin;
 
 impl Plugin for TowerAttackPlugin {
     fn build(&self, app: &mut App) {
+        // Time every combat system for the debug overlay.
+        let mut timings = CombatTimings::default();
         app.add_systems(
             Update,
             (
                 (
-                    check_target_range,
-                    find_target,
-                    tower_rotation,
-                    tower_shooting
-                        .run_if(in_state(AssetState::Loaded)),
+                    timings.timed(check_target_range),
+                    timings.timed(rebuild_enemy_grid),
+                    timings.timed(find_target),
+                    timings.timed(tower_rotation),
+                    (
+                        timings
+                            .timed(tower_shooting)
+                            .after(update_cooldowns)
+                            .run_if(in_state(AssetState::Loaded)),
+                        timings.timed(beam_damage),
+                    ),
                 )
                     .chain(),
-                handle_projectile_collisions,
-                projectile_movement,
-                despawn_on_death.run_if(in_state(AssetState::Loaded)),
+                timings.timed(handle_projectile_collisions),
+                timings.timed(projectile_movement),
+                timings.timed(tick_heat),
+                timings
+                    .timed(despawn_on_death)
+                    .run_if(in_state(AssetState::Loaded)),
             ),
         );
 
-        app.register_type::<Tower>().register_type::<MaxHealth>();
+        app.insert_resource(timings)
+            .init_resource::<WindField>()
+            .init_resource::<CooldownJitter>()
+            .init_resource::<FriendlyFire>()
+            .init_resource::<EnemyGrid>()
+            .init_resource::<ProjectilePool>()
+            .add_systems(
+                PostUpdate,
+                follow_projectile_models
+                    .before(TransformSystem::TransformPropagate),
+            )
+            .add_observer(release_projectile_model)
+            .add_observer(forget_projectile_model)
+            .add_observer(jitter_attack_cooldown)
+            .add_observer(on_add_level_wind)
+            .add_observer(on_remove_level_wind);
+
+        #[cfg(feature = "dev")]
+        app.init_resource::<TargetingDebug>()
+            .add_observer(forget_targeting_debug);
+
+        app.register_type::<Tower>()
+            .register_type::<MaxHealth>()
+            .register_type::<Invulnerable>()
+            .register_type::<SpawnProtection>()
+            .register_type::<NoTarget>()
+            .register_type::<Team>()
+            .register_type::<FriendlyFire>()
+            .register_type::<TargetingMode>()
+            .register_type::<MomentumShots>()
+            .register_type::<ScorchShots>()
+            .register_type::<SlowShots>()
+            .register_type::<CriticalHits>()
+            .register_type::<LobbedShots>()
+            .register_type::<ChainShots>()
+            .register_type::<ProjectileShape>()
+            .register_type::<MuzzleOffset>()
+            .register_type::<TowerSfx>()
+            .register_type::<Heat>()
+            .register_type::<WindField>()
+            .register_type::<CooldownJitter>()
+            .register_type::<LevelWind>();
     }
 }
 
 fn check_target_range(
     mut commands: Commands,
-    q_towers: Query<(&Tower, &Target, Entity)>,
+    q_towers: Query<(&Tower, &Target, &GlobalTransform, Entity)>,
     q_global_transforms: Query<&GlobalTransform>,
-) -> Result {
-    for (tower, target, entity) in q_towers.iter() {
-        let tower_position =
-            q_global_transforms.get(entity)?.translation();
-        let target_position =
-            q_global_transforms.get(target.entity())?.translation();
+    // Not attacking a tower at the end of their path.
+    q_idle_enemies: Query<
+        (),
+        (With<TargetReached>, Without<TargetTower>),
+    >,
+    q_no_targets: Query<(), With<NoTarget>>,
+) {
+    for (tower, target, global_transform, entity) in q_towers.iter() {
+        let tower_position = global_transform.translation();
+        // The target might have lost its transform
+        // while waiting to be despawned.
+        let Ok(target_position) = q_global_transforms
+            .get(target.entity())
+            .map(|t| t.translation())
+        else {
+            commands.entity(entity).try_remove::<Target>();
+            continue;
+        };
 
-        // Switch target if out of range.
-        if target_position.distance(tower_position) > tower.range {
-            commands.entity(entity).remove::<Target>();
+        // Switch target if out of range or the target is
+        // waiting to leak.
+        if target_position.distance(tower_position) > tower.range
+            || q_idle_enemies.contains(target.entity())
+            || q_no_targets.contains(target.entity())
+        {
+            commands.entity(entity).try_remove::<Target>();
         }
     }
-
-    Ok(())
 }
 
-/// Find and target the best enemy based on [`Path`] length (lower is better).
+/// Find and target the best enemy based on the tower's
+/// [`TargetingMode`], [`TargetingMode::First`] if it has none.
+///
+/// Enemies that completed their [`Path`] without a tower to attack
+/// are about to leak and are never targeted, neither are
+/// [`NoTarget`] entities.
+///
+/// Only towers without a [`Target`] look for one, a tower keeps its
+/// target until [`check_target_range`] drops it, even when a better
+/// enemy comes into range.
+///
+/// Enemies in range are looked up from the shared [`EnemyGrid`],
+/// using the same center to center distance as [`check_target_range`].
 fn find_target(
     mut commands: Commands,
-    q_towers: Query<(&Tower, Entity), Without<Target>>,
-    q_collider_ofs: Query<&ColliderOf>,
-    q_enemies: Query<(&Path, Entity), With<Enemy>>,
+    q_towers: Query<
+        (&Tower, Option<&TargetingMode>, Entity),
+        Without<Target>,
+    >,
+    q_enemies: Query<
+        (&Path, &PathIndex, &PathProgress, Option<&Health>, Entity),
+        (
+            With<Enemy>,
+            Without<NoTarget>,
+            Or<(Without<TargetReached>, With<TargetTower>)>,
+        ),
+    >,
     q_global_transforms: Query<&GlobalTransform>,
-    spatial_query: SpatialQuery,
-) -> Result {
-    for (tower, tower_entity) in q_towers.iter() {
-        let tower_position =
-            q_global_transforms.get(tower_entity)?.translation();
-
-        // Find enemies in range using shape intersection.
-        let detection_sphere = Collider::sphere(tower.range);
-        let intersections = spatial_query.shape_intersections(
-            &detection_sphere,
-            tower_position,
-            Quat::IDENTITY,
-            &SpatialQueryFilter::default()
-                .with_mask(GameLayer::Enemy),
-        );
+    enemy_grid: Res<EnemyGrid>,
+    #[cfg(feature = "dev")] mut targeting_debug: ResMut<
+        TargetingDebug,
+    >,
+) {
+    for (tower, mode, tower_entity) in q_towers.iter() {
+        let mode = mode.copied().unwrap_or_default();
+        let Ok(tower_position) = q_global_transforms
+            .get(tower_entity)
+            .map(|t| t.translation())
+        else {
+            continue;
+        };
 
-        // Find best target from intersected entities.
+        // Find best target from the enemies in range.
         let mut best_target = None;
-        let mut least_path = usize::MAX;
-
-        for entity in intersections {
-            let Ok((path, enemy_entity)) = q_enemies.get(
-                q_collider_ofs
-                    .get(entity)
-                    .map(|c| c.body)
-                    .unwrap_or(entity),
-            ) else {
+        let mut best_score = f32::NEG_INFINITY;
+        // Best target that can be finished off in a single shot.
+        let mut best_execute_target = None;
+        let mut least_execute_path = usize::MAX;
+        #[cfg(feature = "dev")]
+        let mut candidates = Vec::new();
+
+        for entity in
+            enemy_grid.in_radius(tower_position, tower.range)
+        {
+            let Ok((
+                path,
+                path_index,
+                progress,
+                health,
+                enemy_entity,
+            )) = q_enemies.get(entity)
+            else {
                 continue;
             };
 
+            let remaining_path =
+                path.len().saturating_sub(**path_index);
+            let health = health.map(|health| health.0);
+            let distance = q_global_transforms
+                .get(enemy_entity)
+                .map(|t| t.translation().distance(tower_position))
+                .unwrap_or(f32::MAX);
+
             // Check if this enemy has better priority
-            if path.len() < least_path {
-                least_path = path.len();
+            let score = mode.score(
+                remaining_path,
+                progress.0,
+                distance,
+                health,
+            );
+            if score > best_score {
+                best_score = score;
                 best_target = Some(enemy_entity);
             }
+
+            let finishable =
+                health.is_some_and(|health| health <= tower.damage);
+            if mode == TargetingMode::Execute
+                && finishable
+                && remaining_path < least_execute_path
+            {
+                least_execute_path = remaining_path;
+                best_execute_target = Some(enemy_entity);
+            }
+
+            #[cfg(feature = "dev")]
+            candidates.push(TargetCandidate {
+                enemy: enemy_entity,
+                remaining_path,
+                progress: progress.0,
+                score,
+                finishable,
+            });
         }
 
-        if let Some(target) = best_target {
-            commands.entity(tower_entity).insert(Target(target));
+        let chosen = best_execute_target.or(best_target);
+
+        #[cfg(feature = "dev")]
+        targeting_debug.0.insert(
+            tower_entity,
+            TargetingPass { candidates, chosen },
+        );
+
+        if let Some(target) = chosen {
+            commands.entity(tower_entity).try_insert(Target(target));
         }
     }
+}
 
-    Ok(())
+/// Drop the [`TargetingDebug`] of despawned towers.
+#[cfg(feature = "dev")]
+fn forget_targeting_debug(
+    trigger: Trigger<OnRemove, Tower>,
+    mut targeting_debug: ResMut<TargetingDebug>,
+) {
+    targeting_debug.0.remove(&trigger.target());
 }
 
 /// Rotate towers to face their targets.
@@ -116,7 +287,7 @@ fn tower_rotation(
     >,
     q_global_transforms: Query<&GlobalTransform>,
     time: Res<Time>,
-) -> Result {
+) {
     const ROTATION_SPEED: f32 = 8.0;
     const SNAP_THRESHOLD: f32 = 0.15;
 
@@ -124,8 +295,12 @@ fn tower_rotation(
         q_towers.iter_mut()
     {
         let tower_position = global_transform.translation();
-        let target_position =
-            q_global_transforms.get(target.entity())?.translation();
+        let Ok(target_position) = q_global_transforms
+            .get(target.entity())
+            .map(|t| t.translation())
+        else {
+            continue;
+        };
 
         let Ok(direction) =
             Dir3::new(target_position - tower_position)
@@ -149,8 +324,6 @@ fn tower_rotation(
             );
         }
     }
-
-    Ok(())
 }
 
 /// Shoot at current target
@@ -161,36 +334,77 @@ fn tower_shooting(
             &Transform,
             &GlobalTransform,
             &Tower,
+            Option<&AuraBuffed>,
+            Option<&HomingShots>,
+            Option<&MomentumShots>,
+            Option<&ProjectileShape>,
+            Option<&MuzzleOffset>,
+            Has<ScorchShots>,
+            Option<&SlowShots>,
+            Option<&mut Heat>,
             &mut AttackCooldown,
             &Target,
             &TowerPrefabName,
+            Entity,
         ),
-        Without<Enemy>,
+        (Without<Enemy>, Without<Beam>),
     >,
     q_enemies: Query<&GlobalTransform, With<Enemy>>,
+    q_critical_hits: Query<&CriticalHits>,
+    q_lobbed_shots: Query<&LobbedShots>,
+    q_chain_shots: Query<&ChainShots>,
     prefabs: Res<PrefabAssets>,
     gltfs: Res<Assets<Gltf>>,
-) -> Result {
-    // Minimum facing accuracy to fire.
-    const MIN_FACING_ACCURACY: f32 = 0.9;
+    mut pool: ResMut<ProjectilePool>,
+    mut rng: ResMut<GameRng>,
+    // Unknown towers that have already been reported.
+    mut unknown_towers: Local<HashSet<String>>,
+    // Missing projectile models that have already been reported.
+    mut missing_models: Local<HashSet<&'static str>>,
+) {
+    // Plain projectiles are spawned together in a single batch.
+    let mut batch = Vec::new();
+    let mut models = Vec::new();
 
     for (
         transform,
         global_transform,
         tower,
+        aura_buffed,
+        homing_shots,
+        momentum_shots,
+        projectile_shape,
+        muzzle_offset,
+        scorch_shots,
+        slow_shots,
+        mut heat,
         mut cooldown,
         target,
         prefab_name,
+        entity,
     ) in q_towers.iter_mut()
     {
         if cooldown.0 > 0.0 {
             continue;
         }
 
+        if heat.as_ref().is_some_and(|heat| heat.overheated) {
+            continue;
+        }
+
+        let buff = aura_buffed.copied().unwrap_or_default();
+        let projectile_shape =
+            projectile_shape.copied().unwrap_or_default();
+
         let tower_position = global_transform.translation();
-        let target_position =
-            q_enemies.get(target.entity())?.translation()
-                + Vec3::Y * 0.5;
+        let Ok(target_position) = q_enemies
+            .get(target.entity())
+            .map(|t| t.translation() + Vec3::Y * 0.5)
+        else {
+            // Stale target, a new one is picked next frame.
+            commands.entity(entity).try_remove::<Target>();
+            continue;
+        };
 
         // Check if tower is facing the target
         let tower_forward = -transform.forward();
@@ -202,80 +416,387 @@ fn tower_shooting(
             continue;
         }
 
-        let projectile_start = tower_position + Vec3::Y * 0.5;
-        let direction =
-            (target_position - projectile_start).normalize();
+        let projectile_start = match muzzle_offset {
+            Some(offset) => {
+                global_transform.transform_point(offset.0)
+            }
+            None => tower_position + Vec3::Y * 0.5,
+        };
+        // Lobbed shots fly straight when the target is out of
+        // their ballistic range.
+        let (velocity, gravity) = q_lobbed_shots
+            .get(entity)
+            .ok()
+            .and_then(|lobbed_shots| {
+                let velocity = lobbed_shots.launch_velocity(
+                    projectile_start,
+                    target_position,
+                    tower.projectile_speed,
+                )?;
+                Some((velocity, lobbed_shots.gravity))
+            })
+            .unwrap_or_else(|| {
+                let direction =
+                    (target_position - projectile_start).normalize();
+                (direction * tower.projectile_speed, 0.0)
+            });
+        let direction = velocity.normalize();
 
         let model_name = match prefab_name.0.as_ref() {
             "gun_tower" => "popcorn",
             "cannon_tower" => "roasted_corn",
-            _ => return Err("Unrecognized tower...".into()),
+            unknown => {
+                // Skip it without holding back the other towers.
+                if unknown_towers.insert(unknown.to_string()) {
+                    error!("Unrecognized tower '{unknown}'!");
+                }
+                continue;
+            }
         };
 
-        let handle = prefabs
-            .get_gltf(PrefabName::FileName(model_name), &gltfs)
-            .ok_or(format!("Can't find {model_name} prefab!"))?
-            .default_scene
-            .clone()
-            .ok_or(format!(
-                "{model_name} prefab should have a default scene."
-            ))?;
+        // Reuse a hidden model instead of instantiating the scene.
+        let model_entity = match pool.acquire(model_name) {
+            Some(model_entity) => model_entity,
+            None => {
+                // Skip it without dropping the batched shots
+                // of the other towers.
+                let Some(handle) = prefabs
+                    .get_gltf(
+                        PrefabName::FileName(model_name),
+                        &gltfs,
+                    )
+                    .and_then(|gltf| gltf.default_scene.clone())
+                else {
+                    if missing_models.insert(model_name) {
+                        error!(
+                            "Can't find the default scene of {model_name} prefab!"
+                        );
+                    }
+                    continue;
+                };
 
-        commands.spawn((
+                commands
+                    .spawn((
+                        ProjectileModel(model_name),
+                        SceneRoot(handle),
+                        Visibility::Hidden,
+                    ))
+                    .id()
+            }
+        };
+
+        models.push((
+            model_entity,
+            (
+                Transform::from_translation(projectile_start)
+                    .with_scale(Vec3::splat(projectile_shape.scale))
+                    .looking_to(direction, Vec3::Y),
+                Visibility::Inherited,
+            ),
+        ));
+
+        // Towers without a crit chance never touch the rng.
+        let critical =
+            q_critical_hits.get(entity).ok().filter(|crit| {
+                crit.chance > 0.0 && rng.f32() < crit.chance
+            });
+        let crit_mult =
+            critical.map(|crit| crit.multiplier).unwrap_or(1.0);
+
+        let projectile = (
             Transform::from_translation(projectile_start),
             Projectile {
-                velocity: direction * tower.projectile_speed,
-                damage: tower.damage,
+                velocity,
+                damage: tower.damage * buff.damage_mult * crit_mult,
                 lifetime: 3.0,
+                gravity,
+                on_hit_effect: match slow_shots {
+                    Some(&slow_shots) => {
+                        OnHitEffect::Slow(slow_shots.into())
+                    }
+                    None => OnHitEffect::Damage,
+                },
             },
-            Visibility::Inherited,
-            Children::spawn(Spawn((
-                SceneRoot(handle),
-                Transform::from_scale(Vec3::splat(0.2))
-                    .looking_to(direction, Vec3::Y),
-            ))),
-        ));
+            Collider::sphere(projectile_shape.scaled_radius()),
+            PooledProjectileModel(model_entity),
+            FiredBy(entity),
+        );
+
+        let chain_shots = q_chain_shots.get(entity).ok();
+
+        if homing_shots.is_none()
+            && momentum_shots.is_none()
+            && projectile_shape.swept == false
+            && scorch_shots == false
+            && critical.is_none()
+            && chain_shots.is_none()
+        {
+            batch.push(projectile);
+        } else {
+            let mut projectile = commands.spawn(projectile);
 
-        cooldown.0 = tower.attack_cooldown;
+            if let Some(&homing_shots) = homing_shots {
+                projectile.insert(Homing {
+                    target: Some(target.entity()),
+                    ..homing_shots.into()
+                });
+            }
+
+            if let Some(&momentum_shots) = momentum_shots {
+                projectile.insert(Momentum::from(momentum_shots));
+            }
+
+            if projectile_shape.swept {
+                projectile.insert(SweptProjectile);
+            }
+
+            if scorch_shots {
+                projectile.insert(Scorching);
+            }
+
+            if critical.is_some() {
+                projectile.insert(CriticalShot);
+            }
+
+            if let Some(&chain_shots) = chain_shots {
+                projectile.insert(Chaining::from(chain_shots));
+            }
+        }
+
+        commands.trigger_targets(TowerFired, entity);
+
+        cooldown.0 = tower.attack_cooldown * buff.cooldown_mult;
+
+        if let Some(heat) = heat.as_mut() {
+            heat.add_shot();
+        }
     }
 
-    Ok(())
+    commands.spawn_batch(batch);
+    commands.insert_batch(models);
 }
 
 /// Handle projectile collisions using physics system.
 fn handle_projectile_collisions(
     mut commands: Commands,
     mut collision_events: EventReader<CollisionStarted>,
-    q_projectiles: Query<&Projectile>,
+    q_projectiles: Query<(&Projectile, &Team)>,
+    q_momentums: Query<&Momentum>,
+    q_critical_shots: Query<(), With<CriticalShot>>,
+    q_fired_bys: Query<&FiredBy>,
+    q_chainings: Query<&Chaining>,
     q_collider_ofs: Query<&ColliderOf>,
     q_is_enemy: Query<(), With<IsEnemy>>,
-    mut q_healths: Query<&mut Health>,
+    q_teams: Query<&Team, Without<Projectile>>,
+    q_collision_layers: Query<&CollisionLayers>,
+    q_invulnerables: Query<
+        (),
+        Or<(With<Invulnerable>, With<SpawnProtection>)>,
+    >,
+    q_global_transforms: Query<&GlobalTransform>,
+    mut q_healths: Query<(
+        &mut Health,
+        Option<&MaxHealth>,
+        Option<&Armor>,
+    )>,
+    spatial_query: SpatialQuery,
+    friendly_fire: Res<FriendlyFire>,
 ) {
+    let is_obstacle = |entity: Entity| {
+        q_collision_layers.get(entity).is_ok_and(|layers| {
+            layers.memberships.has_all(GameLayer::Obstacle)
+        })
+    };
+
     for CollisionStarted(entity1, entity2) in collision_events.read()
     {
-        // Check if one is projectile, other is enemy
-        let (projectile_entity, enemy_entity) = if q_projectiles
-            .contains(*entity1)
-            && q_is_enemy.contains(*entity2)
-        {
-            (*entity1, *entity2)
-        } else if q_projectiles.contains(*entity2)
-            && q_is_enemy.contains(*entity1)
-        {
-            (*entity2, *entity1)
-        } else {
+        // Projectiles are blocked by obstacles instead of tunneling.
+        if let Some(projectile_entity) = match (
+            q_projectiles.contains(*entity1),
+            q_projectiles.contains(*entity2),
+        ) {
+            (true, false) if is_obstacle(*entity2) => Some(*entity1),
+            (false, true) if is_obstacle(*entity1) => Some(*entity2),
+            _ => None,
+        } {
+            commands.entity(projectile_entity).try_despawn();
             continue;
+        }
+
+        // Check if one is projectile, other is enemy or a friendly unit
+        let (projectile_entity, hit_entity) = match (
+            q_projectiles.contains(*entity1),
+            q_projectiles.contains(*entity2),
+        ) {
+            (true, false) => (*entity1, *entity2),
+            (false, true) => (*entity2, *entity1),
+            _ => continue,
         };
 
         // Get projectile data and apply damage
-        if let Ok(projectile) = q_projectiles.get(projectile_entity) {
-            let enemy_entity = q_collider_ofs
-                .get(enemy_entity)
+        if let Ok((projectile, team)) =
+            q_projectiles.get(projectile_entity)
+        {
+            let is_enemy = q_is_enemy.contains(hit_entity);
+            let hit_entity = q_collider_ofs
+                .get(hit_entity)
                 .map(|c| c.body)
-                .unwrap_or(enemy_entity);
+                .unwrap_or(hit_entity);
+
+            // Enemies are always damageable.
+            if is_enemy == false {
+                let Ok(hit_team) = q_teams.get(hit_entity) else {
+                    continue;
+                };
+
+                // Flies through the friendly unit.
+                if friendly_fire.hits(*team, *hit_team) == false {
+                    continue;
+                }
+            }
+
+            // Absorbed by the shield.
+            if q_invulnerables.contains(hit_entity) {
+                commands.entity(projectile_entity).despawn();
+                continue;
+            }
+
+            if let Ok(global_transform) =
+                q_global_transforms.get(projectile_entity)
+            {
+                commands.trigger_targets(
+                    ProjectileImpact {
+                        position: global_transform.translation(),
+                    },
+                    projectile_entity,
+                );
+            }
+
+            if let OnHitEffect::Slow(slow) = projectile.on_hit_effect
+            {
+                if is_enemy {
+                    commands
+                        .entity(hit_entity)
+                        .entry::<Slow>()
+                        .and_modify(move |mut current| {
+                            current.stack(slow)
+                        })
+                        .or_insert(slow);
+                }
+            } else if q_healths.contains(hit_entity) {
+                let multiplier = q_momentums
+                    .get(projectile_entity)
+                    .map(Momentum::multiplier)
+                    .unwrap_or(1.0);
+
+                let amount = projectile.damage * multiplier;
+                let mut hits = vec![(hit_entity, amount)];
+
+                if let Some(chaining) = q_chainings
+                    .get(projectile_entity)
+                    .ok()
+                    .filter(|_| is_enemy)
+                {
+                    let mut chained = HashSet::new();
+                    chained.insert(hit_entity);
+                    let mut path = Vec::new();
+                    let mut current = hit_entity;
+                    let mut amount = amount;
+
+                    for _ in 0..chaining.jumps {
+                        let Ok(from) = q_global_transforms
+                            .get(current)
+                            .map(|t| t.translation())
+                        else {
+                            break;
+                        };
+                        if path.is_empty() {
+                            path.push(from);
+                        }
+
+                        // Nearest living enemy that wasn't hit yet.
+                        let next = spatial_query
+                            .shape_intersections(
+                                &Collider::sphere(chaining.range),
+                                from,
+                                Quat::IDENTITY,
+                                &GameLayer::Enemy.query_filter(),
+                            )
+                            .into_iter()
+                            .filter(|e| q_is_enemy.contains(*e))
+                            .map(|e| {
+                                q_collider_ofs
+                                    .get(e)
+                                    .map(|c| c.body)
+                                    .unwrap_or(e)
+                            })
+                            .filter(|e| {
+                                chained.contains(e) == false
+                                    && q_invulnerables.contains(*e)
+                                        == false
+                                    && q_healths.get(*e).is_ok_and(
+                                        |(h, ..)| h.0 > 0.0,
+                                    )
+                            })
+                            .filter_map(|e| {
+                                let position = q_global_transforms
+                                    .get(e)
+                                    .ok()?
+                                    .translation();
+                                Some((e, position))
+                            })
+                            .min_by(|(_, a), (_, b)| {
+                                a.distance_squared(from).total_cmp(
+                                    &b.distance_squared(from),
+                                )
+                            });
+
+                        let Some((next, position)) = next else {
+                            break;
+                        };
+
+                        amount *= chaining.falloff;
+                        hits.push((next, amount));
+                        chained.insert(next);
+                        path.push(position);
+                        current = next;
+                    }
+
+                    if path.len() > 1 {
+                        commands.trigger(ChainArced { path });
+                    }
+                }
+
+                for (hit_entity, amount) in hits {
+                    let Ok((mut health, max_health, armor)) =
+                        q_healths.get_mut(hit_entity)
+                    else {
+                        continue;
+                    };
+
+                    let amount = apply_damage(
+                        &mut commands,
+                        DamageHit {
+                            entity: hit_entity,
+                            is_enemy,
+                            tower: q_fired_bys
+                                .get(projectile_entity)
+                                .ok()
+                                .map(|fired_by| fired_by.0),
+                            feedback: true,
+                        },
+                        (&mut health, max_health, armor),
+                        amount,
+                        1.0,
+                    );
 
-            if let Ok(mut health) = q_healths.get_mut(enemy_entity) {
-                health.0 -= projectile.damage;
+                    if q_critical_shots.contains(projectile_entity) {
+                        commands.trigger(CritLanded {
+                            enemy: hit_entity,
+                            amount,
+                        });
+                    }
+                }
             }
 
             // Despawn projectile after hit
@@ -284,48 +805,231 @@ fn handle_projectile_collisions(
     }
 }
 
+/// Entity damaged by [`apply_damage`].
+pub(super) struct DamageHit {
+    pub entity: Entity,
+    pub is_enemy: bool,
+    /// Tower credited with the killing blow, which might be
+    /// gone by now.
+    pub tower: Option<Entity>,
+    /// Flash the entity and announce the [`EnemyHit`],
+    /// throttled by continuous damage.
+    pub feedback: bool,
+}
+
+/// Damage the [`Health`] by `amount` reduced by the [`Armor`],
+/// then multiplied by `scale`, returning the damage dealt.
+///
+/// Continuous damage passes its rate per second as the `amount`
+/// and the delta time as the `scale`, so that the flat [`Armor`]
+/// applies per second instead of per frame.
+pub(super) fn apply_damage(
+    commands: &mut Commands,
+    hit: DamageHit,
+    (health, max_health, armor): (
+        &mut Health,
+        Option<&MaxHealth>,
+        Option<&Armor>,
+    ),
+    amount: f32,
+    scale: f32,
+) -> f32 {
+    let amount =
+        armor.map(|armor| armor.reduce(amount)).unwrap_or(amount)
+            * scale;
+    let was_alive = health.0 > 0.0;
+    health.0 -= amount;
+
+    if hit.is_enemy && was_alive && health.0 <= 0.0 {
+        if let Some(tower) = hit.tower {
+            commands.trigger_targets(
+                TowerKill {
+                    enemy: hit.entity,
+                    xp: max_health
+                        .map(|max_health| max_health.0)
+                        .unwrap_or_default(),
+                },
+                tower,
+            );
+        }
+    }
+
+    if hit.feedback == false {
+        return amount;
+    }
+
+    commands.entity(hit.entity).try_insert(HitFlash::default());
+
+    if hit.is_enemy {
+        commands.trigger_targets(EnemyHit, hit.entity);
+    }
+
+    amount
+}
+
+/// Horizontal radius of the random offset applied to dropped corns.
+const CORN_SCATTER_RADIUS: f32 = 0.4;
+/// Max extra height of the random offset applied to dropped corns.
+const CORN_SCATTER_HEIGHT: f32 = 0.3;
+
 fn despawn_on_death(
     mut commands: Commands,
     q_healths: Query<
-        (&Health, &GlobalTransform, Has<Enemy>, Entity),
-        Changed<Health>,
+        (
+            &Health,
+            &GlobalTransform,
+            Option<&DeathDrop>,
+            Option<&Bounty>,
+            Option<(
+                &SplitOnDeath,
+                &Path,
+                &PathIndex,
+                Option<&SplitDepth>,
+            )>,
+            Has<Enemy>,
+            Entity,
+        ),
+        // Players are never despawned.
+        (Changed<Health>, Without<CharacterController>),
     >,
     prefabs: Res<PrefabAssets>,
     gltfs: Res<Assets<Gltf>>,
     current_scene: Res<CurrentScene>,
-) -> Result {
-    let Some(current_scene) = current_scene.get() else {
-        return Ok(());
-    };
-
-    for (health, global_transform, is_enemy, entity) in
-        q_healths.iter()
+    modifiers: Res<GlobalEnemyModifiers>,
+    mut rng: ResMut<GameRng>,
+    mut currency: ResMut<Currency>,
+    economy: Res<EconomyConfig>,
+    mut warned_no_scene: Local<bool>,
+) {
+    for (
+        health,
+        global_transform,
+        death_drop,
+        bounty,
+        split,
+        is_enemy,
+        entity,
+    ) in q_healths.iter()
     {
         if health.0 > 0.0 {
             continue;
         }
+        // Only enemies announce their death.
+        if is_enemy {
+            commands.trigger_targets(EnemyDied, entity);
+        }
         commands.entity(entity).despawn();
 
-        if is_enemy {
+        if let Some((split_on_death, path, path_index, depth)) = split
+        {
+            let depth = depth.copied().unwrap_or_default().0;
+
+            let prefab_name = split_on_death.child_prefab.as_str();
             let scene = prefabs
-                .get_gltf(PrefabName::FileName("corn"), &gltfs)
-                .ok_or("Can't find corn prefab!")?
-                .default_scene
-                .clone()
-                .ok_or("Corn prefab shoould have a default scene.")?;
+                .get_gltf(PrefabName::FileName(prefab_name), &gltfs)
+                .and_then(|gltf| gltf.default_scene.clone());
+
+            // Keep despawning the other dead enemies on a bad prefab.
+            if scene.is_none() {
+                error!(
+                    "Can't find the default scene of {prefab_name} prefab!"
+                );
+            }
+
+            if let Some(scene) =
+                scene.filter(|_| depth < SplitOnDeath::MAX_DEPTH)
+            {
+                let remaining_path = path
+                    .get(**path_index..)
+                    .unwrap_or_default()
+                    .to_vec();
+                let count = split_on_death.count as usize;
+                let formation = Formation::Group {
+                    size: count,
+                    radius: SplitOnDeath::SCATTER_RADIUS,
+                };
+
+                // Split enemies carry on from where this enemy died.
+                for offset in formation.offsets(count) {
+                    let mut split = commands.spawn((
+                        SceneRoot(scene.clone()),
+                        SplitOf {
+                            path: remaining_path.clone(),
+                            depth: depth + 1,
+                        },
+                        LevelScoped,
+                        Transform::from_translation(
+                            global_transform.translation() + offset,
+                        ),
+                    ));
+
+                    if let Some(current_scene) = current_scene.get() {
+                        split.insert(ChildOf(current_scene));
+                    }
+                }
+            }
+        }
+
+        if let Some(&Bounty(amount)) = bounty {
+            let amount = economy.bounty(amount);
+            **currency += amount;
+            commands.trigger(BountyAwarded {
+                amount,
+                position: global_transform.translation(),
+            });
+        }
+
+        if let Some(death_drop) = death_drop {
+            let prefab_name = death_drop.prefab.as_str();
+            let Some(scene) = prefabs
+                .get_gltf(PrefabName::FileName(prefab_name), &gltfs)
+                .and_then(|gltf| gltf.default_scene.clone())
+            else {
+                // Keep despawning the other dead enemies on a bad prefab.
+                warn!(
+                    "Can't find the default scene of {prefab_name} prefab!"
+                );
+                continue;
+            };
 
             // Spawn new corns for the player.
-            commands.spawn((
-                SceneRoot(scene),
-                Transform::from_translation(
-                    global_transform.translation() + Vec3::Y * 1.5,
-                ),
-                ChildOf(current_scene),
-            ));
+            let corn_count = (death_drop.count as f32
+                * modifiers.reward_mult)
+                .round() as usize;
+            for _ in 0..corn_count {
+                // Scatter the corns so that simultaneous deaths
+                // don't pile them up on top of each other.
+                let scatter = rng.in_disk(CORN_SCATTER_RADIUS);
+                let height = rng.range_f32(0.0, CORN_SCATTER_HEIGHT);
+
+                let mut corn = commands.spawn((
+                    SceneRoot(scene.clone()),
+                    LevelScoped,
+                    Transform::from_translation(
+                        global_transform.translation()
+                            + Vec3::Y * 1.5
+                            + Vec3::new(scatter.x, height, scatter.y),
+                    ),
+                ));
+
+                // Keep the corns at the world root during scene
+                // transitions instead of losing them.
+                match current_scene.get() {
+                    Some(current_scene) => {
+                        corn.insert(ChildOf(current_scene));
+                    }
+                    None => {
+                        if *warned_no_scene == false {
+                            warn!(
+                                "No current scene, spawning dropped corns at the world root."
+                            );
+                            *warned_no_scene = true;
+                        }
+                    }
+                }
+            }
         }
     }
-
-    Ok(())
 }
 
 /// Move projectiles.
@@ -334,14 +1038,32 @@ fn projectile_movement(
     mut q_projectiles: Query<(
         &mut Transform,
         &mut Projectile,
+        Option<&mut Momentum>,
+        &Collider,
+        Has<SweptProjectile>,
+        Has<Homing>,
         Entity,
     )>,
+    spatial_query: SpatialQuery,
+    wind_field: Res<WindField>,
     time: Res<Time>,
 ) {
+    let filter = SpatialQueryFilter::from_mask([
+        GameLayer::Enemy,
+        GameLayer::Obstacle,
+    ]);
+
     let delta_time = time.delta_secs();
 
-    for (mut transform, mut projectile, projectile_entity) in
-        q_projectiles.iter_mut()
+    for (
+        mut transform,
+        mut projectile,
+        momentum,
+        collider,
+        swept,
+        homing,
+        projectile_entity,
+    ) in q_projectiles.iter_mut()
     {
         // Update lifetime
         projectile.lifetime -= delta_time;
@@ -350,21 +1072,107 @@ fn projectile_movement(
             continue;
         }
 
+        // Drift with the wind, homing ones steer against it.
+        if homing == false {
+            projectile.velocity += wind_field.force * delta_time;
+        }
+        projectile.velocity.y -= projectile.gravity * delta_time;
+
         // Move projectile
-        transform.translation += projectile.velocity * delta_time;
+        let mut displacement = projectile.velocity * delta_time;
+
+        // Stop at the first thing in the way instead of
+        // stepping over it.
+        let hit = Dir3::new(displacement)
+            .ok()
+            .filter(|_| swept)
+            .and_then(|direction| {
+                spatial_query.cast_shape(
+                    collider,
+                    transform.translation,
+                    Quat::IDENTITY,
+                    direction,
+                    &ShapeCastConfig::from_max_distance(
+                        displacement.length(),
+                    ),
+                    &filter,
+                )
+            });
+
+        if let Some(hit) = hit {
+            // Overlap the hit surface so that the
+            // collision gets registered.
+            displacement = hit.point1 - transform.translation;
+        }
+
+        transform.translation += displacement;
+
+        if let Some(mut momentum) = momentum {
+            momentum.distance += displacement.length();
+        }
+    }
+}
+
+/// Dissipate the [`Heat`] of towers, overheated towers
+/// can fire again once they are fully cooled down.
+fn tick_heat(mut q_heats: Query<&mut Heat>, time: Res<Time>) {
+    let delta_time = time.delta_secs();
+
+    for mut heat in q_heats.iter_mut() {
+        if heat.current <= 0.0 {
+            continue;
+        }
+
+        heat.current =
+            (heat.current - heat.cooldown_rate * delta_time).max(0.0);
+
+        if heat.current <= 0.0 {
+            heat.overheated = false;
+        }
+    }
+}
+
+/// Start new towers at a random point of their cooldown so that
+/// towers placed together don't fire on the same frame.
+fn jitter_attack_cooldown(
+    trigger: Trigger<OnAdd, Tower>,
+    mut q_cooldowns: Query<&mut AttackCooldown>,
+    jitter: Res<CooldownJitter>,
+    mut rng: ResMut<GameRng>,
+) {
+    if jitter.max <= 0.0 {
+        return;
+    }
+
+    if let Ok(mut cooldown) = q_cooldowns.get_mut(trigger.target()) {
+        cooldown.0 = rng.range_f32(0.0, jitter.max);
     }
 }
 
+/// Apply the level's wind once the level is loaded.
+fn on_add_level_wind(
+    trigger: Trigger<OnAdd, LevelWind>,
+    q_level_winds: Query<&LevelWind>,
+    mut wind_field: ResMut<WindField>,
+) -> Result {
+    wind_field.force = q_level_winds.get(trigger.target())?.force;
+    Ok(())
+}
+
+/// Calm the wind once the level is unloaded.
+fn on_remove_level_wind(
+    _: Trigger<OnRemove, LevelWind>,
+    mut wind_field: ResMut<WindField>,
+) {
+    *wind_field = WindField::default();
+}
+
 /// Tower component with stats only.
 #[derive(Component, Reflect, Debug)]
 #[reflect(Component)]
 #[require(
     AttackCooldown,
-    CollisionLayers::new(GameLayer::Tower, {
-        let mut layer = LayerMask::ALL;
-        layer.remove(GameLayer::Enemy);
-        layer
-    })
+    CollisionLayers = GameLayer::tower_layers()
 )]
 pub struct Tower {
     pub range: f32,
@@ -373,39 +1181,2498 @@ pub struct Tower {
     pub projectile_speed: f32,
 }
 
-/// Health component for entities that can take damage
-#[derive(Reflect, Debug)]
+/// Global force that nudges every [`Projectile`] each frame.
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Resource)]
+pub struct WindField {
+    pub force: Vec3,
+}
+
+/// Max initial [`AttackCooldown`] in seconds given to new towers,
+/// 0 disables the jitter.
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource, Default)]
+pub struct CooldownJitter {
+    pub max: f32,
+}
+
+impl Default for CooldownJitter {
+    fn default() -> Self {
+        Self { max: 0.25 }
+    }
+}
+
+/// Per-level [`WindField`], to be placed in the level scene.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
 #[reflect(Component)]
-pub struct MaxHealth(pub f32);
+#[component(immutable)]
+pub struct LevelWind {
+    pub force: Vec3,
+}
 
-impl Component for MaxHealth {
-    const STORAGE_TYPE: StorageType = StorageType::Table;
+/// Triggered on the [`Tower`] entity whenever it fires a [`Projectile`].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TowerFired;
 
-    type Mutability = Immutable;
+/// Triggered on a [`Projectile`] right before it despawns
+/// from hitting its target.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ProjectileImpact {
+    pub position: Vec3,
+}
 
-    /// Setup camera tag: [`Health`] based on [`MaxHealth`].
-    fn register_component_hooks(hooks: &mut ComponentHooks) {
-        hooks.on_add(|mut world, hook| {
-            let entity = hook.entity;
-            let max_health =
-                world.get::<Self>(hook.entity).unwrap().0;
+/// Projectiles fired by this tower leave a scorch mark
+/// where they land.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct ScorchShots;
 
-            world
-                .commands()
-                .entity(entity)
-                .insert(Health(max_health));
-        });
+/// Chance for the projectiles fired by this tower to deal
+/// extra damage, rolled from the [`GameRng`] when firing.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct CriticalHits {
+    /// Chance of a critical hit within `0.0..=1.0`.
+    pub chance: f32,
+    /// Multiplier of the damage of a critical hit.
+    pub multiplier: f32,
+}
+
+impl Default for CriticalHits {
+    fn default() -> Self {
+        Self {
+            chance: 0.0,
+            multiplier: 2.0,
+        }
     }
 }
 
-#[derive(Component, Deref, DerefMut, Debug)]
-pub struct Health(pub f32);
+/// Projectiles fired by this tower arc towards their target
+/// under gravity, like a mortar.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct LobbedShots {
+    /// Downward acceleration of the [`Projectile`]s.
+    pub gravity: f32,
+}
 
-/// Relationship components for tower targeting
-#[derive(Component, Deref, Debug)]
-#[relationship(relationship_target = TargetsOf)]
-pub struct Target(Entity);
+impl LobbedShots {
+    /// Velocity to launch a projectile at `speed` from `start` to
+    /// land on `target`, taking the lower of the two arcs.
+    ///
+    /// Returns [`None`] if the target is out of ballistic range.
+    pub fn launch_velocity(
+        &self,
+        start: Vec3,
+        target: Vec3,
+        speed: f32,
+    ) -> Option<Vec3> {
+        let offset = target - start;
+        let horizontal = Vec2::new(offset.x, offset.z);
+        let distance = horizontal.length();
+        if self.gravity <= 0.0 || distance <= f32::EPSILON {
+            return None;
+        }
 
-#[derive(Component, Deref, Default, Debug)]
-#[relationship_target(relationship = Target)]
-pub struct TargetsOf(Vec<Entity>);
+        let speed2 = speed * speed;
+        let discriminant = speed2 * speed2
+            - self.gravity
+                * (self.gravity * distance * distance
+                    + 2.0 * offset.y * speed2);
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let angle = ((speed2 - discriminant.sqrt())
+            / (self.gravity * distance))
+            .atan();
+        let horizontal = horizontal / distance * angle.cos() * speed;
+
+        Some(Vec3::new(
+            horizontal.x,
+            angle.sin() * speed,
+            horizontal.y,
+        ))
+    }
+}
+
+impl Default for LobbedShots {
+    fn default() -> Self {
+        Self { gravity: 9.81 }
+    }
+}
+
+/// The tower that fired the [`Projectile`].
+#[derive(Component, Deref, Debug, Clone, Copy)]
+pub struct FiredBy(pub Entity);
+
+/// Triggered on a tower when its [`Projectile`]
+/// lands the killing blow on an enemy.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TowerKill {
+    pub enemy: Entity,
+    /// Experience earned, the enemy's [`MaxHealth`].
+    pub xp: f32,
+}
+
+/// A [`Projectile`] that rolled a critical hit,
+/// its damage is already multiplied.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CriticalShot;
+
+/// Triggered when a [`CriticalShot`] damages its target.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CritLanded {
+    /// The hit entity, usually an enemy.
+    pub enemy: Entity,
+    /// Damage dealt, including the critical multiplier.
+    pub amount: f32,
+}
+
+/// Projectiles fired by this tower [`Slow`] the enemies
+/// down instead of damaging them.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct SlowShots {
+    /// Multiplier of the movement speed, lower is slower.
+    pub factor: f32,
+    /// Seconds that the slow lasts.
+    pub duration: f32,
+}
+
+impl Default for SlowShots {
+    fn default() -> Self {
+        Self {
+            factor: 0.5,
+            duration: 2.0,
+        }
+    }
+}
+
+impl From<SlowShots> for Slow {
+    fn from(shots: SlowShots) -> Self {
+        Self {
+            factor: shots.factor,
+            duration: shots.duration,
+        }
+    }
+}
+
+/// A [`Projectile`] of a [`ScorchShots`] tower.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Scorching;
+
+/// Sound effects of a tower prefab, relative to `audios/tower/`.
+#[derive(Component, Reflect, Default, Debug, Clone, Deserialize)]
+#[reflect(Component, Default)]
+#[serde(default)]
+pub struct TowerSfx {
+    pub fire: Option<String>,
+    /// Played where the tower's projectiles land.
+    pub impact: Option<String>,
+}
+
+/// Which enemy in range a tower prefers to target.
+#[derive(
+    Component, Reflect, Default, Debug, Clone, Copy, PartialEq,
+)]
+#[reflect(Component, Default)]
+pub enum TargetingMode {
+    /// Shortest [`Path`] left, closest to leaking.
+    #[default]
+    First,
+    /// Longest [`Path`] left.
+    Last,
+    /// Physically closest to the tower.
+    Closest,
+    /// Most [`Health`] left.
+    Strongest,
+    /// Least [`Health`] left.
+    Weakest,
+    /// Furthest along its [`Path`], compared by [`PathProgress`]
+    /// instead of the tiles left.
+    Progress,
+    /// Most threatening, see [`ThreatWeights::score`].
+    Threat(ThreatWeights),
+    /// Enemies that can be killed in a single shot to finish off
+    /// stragglers before they leak, [`Self::First`] otherwise.
+    Execute,
+}
+
+impl TargetingMode {
+    /// Higher is preferred.
+    ///
+    /// Enemies without [`Health`] rank last when comparing health,
+    /// and only by their path for [`TargetingMode::Threat`].
+    pub fn score(
+        &self,
+        remaining_path: usize,
+        progress: f32,
+        distance: f32,
+        health: Option<f32>,
+    ) -> f32 {
+        match self {
+            TargetingMode::First | TargetingMode::Execute => {
+                -(remaining_path as f32)
+            }
+            TargetingMode::Last => remaining_path as f32,
+            TargetingMode::Closest => -distance,
+            TargetingMode::Strongest => health.unwrap_or(f32::MIN),
+            TargetingMode::Weakest => {
+                health.map(|health| -health).unwrap_or(f32::MIN)
+            }
+            TargetingMode::Progress => progress,
+            TargetingMode::Threat(weights) => {
+                weights.score(remaining_path, health.unwrap_or(0.0))
+            }
+        }
+    }
+}
+
+/// Weighs how close an enemy is to the end of its [`Path`] against
+/// how much [`Health`] it has left, see [`TargetingMode::Threat`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Default)]
+pub struct ThreatWeights {
+    /// Threat gained for every tile closer to the end of the path.
+    pub path_weight: f32,
+    /// Threat gained for every point of health left.
+    pub health_weight: f32,
+}
+
+impl ThreatWeights {
+    /// Higher is more threatening.
+    pub fn score(&self, remaining_path: usize, health: f32) -> f32 {
+        health * self.health_weight
+            - remaining_path as f32 * self.path_weight
+    }
+}
+
+impl Default for ThreatWeights {
+    fn default() -> Self {
+        Self {
+            path_weight: 1.0,
+            health_weight: 0.1,
+        }
+    }
+}
+
+/// Projectile whose damage scales with the distance it has flown.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Momentum {
+    /// Change in damage multiplier per unit of distance flown,
+    /// negative values result in a falloff instead.
+    pub per_distance: f32,
+    pub min_multiplier: f32,
+    pub max_multiplier: f32,
+    /// Distance flown so far.
+    pub distance: f32,
+}
+
+impl Momentum {
+    pub fn multiplier(&self) -> f32 {
+        (1.0 + self.per_distance * self.distance)
+            .clamp(self.min_multiplier, self.max_multiplier)
+    }
+}
+
+impl From<MomentumShots> for Momentum {
+    fn from(shots: MomentumShots) -> Self {
+        Self {
+            per_distance: shots.momentum,
+            min_multiplier: shots.min_multiplier,
+            max_multiplier: shots.max_multiplier,
+            distance: 0.0,
+        }
+    }
+}
+
+/// Towers with this component fire [`Momentum`] projectiles.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct MomentumShots {
+    /// Change in damage multiplier per unit of distance flown.
+    pub momentum: f32,
+    pub min_multiplier: f32,
+    pub max_multiplier: f32,
+}
+
+impl Default for MomentumShots {
+    fn default() -> Self {
+        Self {
+            momentum: 0.1,
+            min_multiplier: 0.5,
+            max_multiplier: 2.0,
+        }
+    }
+}
+
+/// Projectile whose hit on an enemy jumps on to the nearest
+/// enemies that weren't hit yet.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Chaining {
+    /// Max number of enemies jumped to after the first hit.
+    pub jumps: u32,
+    /// Max distance of a single jump.
+    pub range: f32,
+    /// Damage multiplier applied on every jump.
+    pub falloff: f32,
+}
+
+impl From<ChainShots> for Chaining {
+    fn from(shots: ChainShots) -> Self {
+        Self {
+            jumps: shots.jumps,
+            range: shots.range,
+            falloff: shots.falloff,
+        }
+    }
+}
+
+/// Towers with this component fire [`Chaining`] projectiles.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct ChainShots {
+    pub jumps: u32,
+    pub range: f32,
+    pub falloff: f32,
+}
+
+impl Default for ChainShots {
+    fn default() -> Self {
+        Self {
+            jumps: 3,
+            range: 3.0,
+            falloff: 0.7,
+        }
+    }
+}
+
+/// Triggered when a [`Chaining`] projectile jumps between enemies.
+#[derive(Event, Debug, Clone)]
+pub struct ChainArced {
+    /// Positions of the hit enemies, starting with the first hit.
+    pub path: Vec<Vec3>,
+}
+
+/// Collision shape and size of the projectiles fired by a tower.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct ProjectileShape {
+    /// Collider radius at [`ProjectileShape::DEFAULT_SCALE`].
+    pub radius: f32,
+    /// Sweep the projectiles along their path every frame so that
+    /// fast projectiles can't skip over thin enemies.
+    pub swept: bool,
+    /// Scale of the projectile model, bigger shots for bigger guns.
+    /// The collider grows along with it.
+    #[reflect(default = "ProjectileShape::default_scale")]
+    pub scale: f32,
+}
+
+impl ProjectileShape {
+    pub const DEFAULT_SCALE: f32 = 0.2;
+
+    fn default_scale() -> f32 {
+        Self::DEFAULT_SCALE
+    }
+
+    /// Collider radius of the projectile at its [`Self::scale`].
+    pub fn scaled_radius(&self) -> f32 {
+        self.radius * self.scale / Self::DEFAULT_SCALE
+    }
+}
+
+impl Default for ProjectileShape {
+    fn default() -> Self {
+        Self {
+            radius: 0.2,
+            swept: false,
+            scale: Self::DEFAULT_SCALE,
+        }
+    }
+}
+
+/// Where the projectiles of a tower are fired from,
+/// relative to the tower so that it turns along with the barrel.
+///
+/// Towers without it fire from half a unit above their origin.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct MuzzleOffset(pub Vec3);
+
+/// Projectile that is swept along its path, see
+/// [`ProjectileShape::swept`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SweptProjectile;
+
+/// Towers with this component fire in bursts, every shot heats
+/// the tower up until it overheats and has to fully cool down.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct Heat {
+    pub current: f32,
+    pub max: f32,
+    /// Heat added by every shot.
+    pub per_shot: f32,
+    /// Heat dissipated per second.
+    pub cooldown_rate: f32,
+    /// Reached [`max`](Self::max), can't fire until cooled down.
+    pub overheated: bool,
+}
+
+impl Heat {
+    pub fn add_shot(&mut self) {
+        self.current = (self.current + self.per_shot).min(self.max);
+
+        if self.current >= self.max {
+            self.overheated = true;
+        }
+    }
+
+    /// Fraction of the [`max`](Self::max) heat.
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+
+        self.current / self.max
+    }
+}
+
+impl Default for Heat {
+    fn default() -> Self {
+        Self {
+            current: 0.0,
+            max: 100.0,
+            per_shot: 20.0,
+            cooldown_rate: 25.0,
+            overheated: false,
+        }
+    }
+}
+
+/// Health component for entities that can take damage
+#[derive(Reflect, Debug)]
+#[reflect(Component)]
+pub struct MaxHealth(pub f32);
+
+impl Component for MaxHealth {
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    type Mutability = Immutable;
+
+    /// Setup camera tag: [`Health`] based on [`MaxHealth`].
+    fn register_component_hooks(hooks: &mut ComponentHooks) {
+        hooks.on_add(|mut world, hook| {
+            let entity = hook.entity;
+            let max_health =
+                world.get::<Self>(hook.entity).unwrap().0;
+
+            world
+                .commands()
+                .entity(entity)
+                .insert(Health(max_health));
+        });
+    }
+}
+
+#[derive(Component, Deref, DerefMut, Debug)]
+pub struct Health(pub f32);
+
+/// Entities with this component don't take any damage.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct Invulnerable;
+
+/// Short [`Invulnerable`] window of a freshly spawned player
+/// or placed tower, removed once `remaining` runs out.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct SpawnProtection {
+    /// Seconds left before the entity can take damage.
+    pub remaining: f32,
+}
+
+/// Side of a projectile or a friendly unit.
+///
+/// Projectiles only hit the friendly units of another team,
+/// and only with [`FriendlyFire`] enabled.
+#[derive(
+    Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[reflect(Component, Default)]
+pub enum Team {
+    PlayerA,
+    PlayerB,
+    /// Towers and anything that isn't owned by a player.
+    #[default]
+    Neutral,
+}
+
+impl From<PlayerType> for Team {
+    fn from(player_type: PlayerType) -> Self {
+        match player_type {
+            PlayerType::A => Team::PlayerA,
+            PlayerType::B => Team::PlayerB,
+        }
+    }
+}
+
+/// Whether projectiles damage the friendly units of other
+/// [`Team`]s. Enemies are damaged either way.
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Resource)]
+pub struct FriendlyFire {
+    pub enabled: bool,
+}
+
+impl FriendlyFire {
+    /// Whether a projectile of the `source` team damages
+    /// a friendly unit of the `target` team.
+    pub fn hits(&self, source: Team, target: Team) -> bool {
+        self.enabled && source != target
+    }
+}
+
+/// Entities with this component are never targeted by towers,
+/// e.g. decoys, friendly summons or training dummies.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct NoTarget;
+
+/// Relationship components for tower targeting
+#[derive(Component, Deref, Debug)]
+#[relationship(relationship_target = TargetsOf)]
+pub struct Target(Entity);
+
+#[derive(Component, Deref, Default, Debug)]
+#[relationship_target(relationship = Target)]
+pub struct TargetsOf(Vec<Entity>);
+
+/// The last [`find_target`] pass of every tower.
+#[cfg(feature = "dev")]
+#[derive(Resource, Default, Debug)]
+pub struct TargetingDebug(
+    bevy::platform::collections::HashMap<Entity, TargetingPass>,
+);
+
+#[cfg(feature = "dev")]
+impl TargetingDebug {
+    pub fn get(&self, tower: Entity) -> Option<&TargetingPass> {
+        self.0.get(&tower)
+    }
+}
+
+/// Enemies that a tower considered when looking for a [`Target`].
+#[cfg(feature = "dev")]
+#[derive(Default, Debug, Clone)]
+pub struct TargetingPass {
+    pub candidates: Vec<TargetCandidate>,
+    /// The enemy that became the [`Target`].
+    pub chosen: Option<Entity>,
+}
+
+/// Scores of an enemy in range during a [`find_target`] pass.
+#[cfg(feature = "dev")]
+#[derive(Debug, Clone, Copy)]
+pub struct TargetCandidate {
+    pub enemy: Entity,
+    /// Remaining tiles, lower is preferred by default.
+    pub remaining_path: usize,
+    pub progress: f32,
+    /// [`TargetingMode::score`] of the tower.
+    pub score: f32,
+    /// Preferred by [`TargetingMode::Execute`] towers.
+    pub finishable: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{CombatTestAppExt, combat_app};
+
+    #[test]
+    fn test_invulnerable_enemy_takes_no_projectile_damage() {
+        let mut app = combat_app();
+
+        app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 10.0,
+                attack_cooldown: 0.25,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        let enemy = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 4.0));
+        app.world_mut().entity_mut(enemy).insert(Invulnerable);
+
+        let health =
+            |app: &App| app.world().get::<Health>(enemy).unwrap().0;
+
+        // 2 seconds of simulation.
+        app.step(128);
+        assert_eq!(health(&app), 100.0);
+
+        app.world_mut().entity_mut(enemy).remove::<Invulnerable>();
+        app.step(128);
+        assert!(
+            health(&app) < 100.0,
+            "Enemy should be damaged once vulnerable."
+        );
+    }
+
+    #[test]
+    fn test_no_dangling_targets_after_despawn_fuzz() {
+        let mut app = combat_app();
+        let mut rng = GameRng::new(7);
+
+        let tower = || Tower {
+            range: 6.0,
+            damage: 10.0,
+            attack_cooldown: 0.25,
+            projectile_speed: 20.0,
+        };
+        let mut towers = Vec::new();
+        let mut enemies = Vec::new();
+
+        for _ in 0..40 {
+            let position = Vec3::new(
+                rng.range_f32(-8.0, 8.0),
+                0.0,
+                rng.range_f32(-8.0, 8.0),
+            );
+
+            match rng.f32() {
+                roll if roll < 0.3 => towers.push(app.spawn_tower(
+                    "gun_tower",
+                    tower(),
+                    position,
+                )),
+                roll if roll < 0.7 => {
+                    enemies.push(app.spawn_enemy(
+                        rng.range_f32(5.0, 50.0),
+                        position,
+                    ))
+                }
+                roll => {
+                    // Despawn either side of the relationship.
+                    let entities = match roll < 0.85 {
+                        true => &mut towers,
+                        false => &mut enemies,
+                    };
+                    if entities.is_empty() == false {
+                        let index = (rng.f32()
+                            * entities.len() as f32)
+                            as usize;
+                        let entity = entities.swap_remove(index);
+                        if let Ok(entity) =
+                            app.world_mut().get_entity_mut(entity)
+                        {
+                            entity.despawn();
+                        }
+                    }
+                }
+            }
+
+            app.step((rng.f32() * 8.0) as usize + 1);
+            app.assert_no_dangling_targets();
+        }
+    }
+
+    #[test]
+    fn test_towers_spawned_together_have_desynced_cooldowns() {
+        let mut app = combat_app();
+        app.insert_resource(CooldownJitter::default());
+
+        let tower = || Tower {
+            range: 10.0,
+            damage: 10.0,
+            attack_cooldown: 1.0,
+            projectile_speed: 20.0,
+        };
+        let towers = [Vec3::ZERO, Vec3::X * 2.0].map(|position| {
+            app.spawn_tower("gun_tower", tower(), position)
+        });
+
+        let [a, b] = towers.map(|tower| {
+            app.world().get::<AttackCooldown>(tower).unwrap().0
+        });
+        assert_ne!(a, b);
+        for cooldown in [a, b] {
+            assert!(
+                (0.0..CooldownJitter::default().max)
+                    .contains(&cooldown)
+            );
+        }
+
+        // Disabled jitter keeps the towers ready to fire.
+        app.insert_resource(CooldownJitter { max: 0.0 });
+        let tower = app.spawn_tower("gun_tower", tower(), Vec3::Z);
+        assert_eq!(
+            app.world().get::<AttackCooldown>(tower).unwrap().0,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_tower_kills_enemy_and_drops_corn() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 10.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        let enemy = app.spawn_enemy(10.0, Vec3::new(0.0, 0.0, 4.0));
+
+        // 2 seconds of simulation.
+        app.step(128);
+
+        assert!(
+            app.world().get_entity(enemy).is_err(),
+            "Enemy should have been killed."
+        );
+        assert!(app.world().get::<Target>(tower).is_none());
+
+        let current_scene = app
+            .world()
+            .resource::<CurrentScene>()
+            .get()
+            .expect("Should have a scene.");
+        let corn_count = app
+            .world_mut()
+            .query_filtered::<&ChildOf, With<SceneRoot>>()
+            .iter(app.world())
+            .filter(|child_of| child_of.parent() == current_scene)
+            .count();
+
+        assert_eq!(corn_count, 1, "A corn should have dropped.");
+    }
+
+    #[test]
+    fn test_simultaneous_deaths_scatter_corns() {
+        let mut app = combat_app();
+
+        let enemies = (0..4)
+            .map(|_| {
+                let enemy = app.spawn_enemy(10.0, Vec3::ZERO);
+                // Prevent physics from pushing the enemies apart.
+                app.world_mut()
+                    .entity_mut(enemy)
+                    .insert(RigidBody::Kinematic);
+                enemy
+            })
+            .collect::<Vec<_>>();
+        app.step(1);
+
+        for enemy in enemies {
+            app.world_mut().get_mut::<Health>(enemy).unwrap().0 = 0.0;
+        }
+        app.step(1);
+
+        let corn_positions = app
+            .world_mut()
+            .query_filtered::<&Transform, With<SceneRoot>>()
+            .iter(app.world())
+            .map(|transform| transform.translation)
+            .collect::<Vec<_>>();
+
+        assert_eq!(corn_positions.len(), 4);
+        for (i, a) in corn_positions.iter().enumerate() {
+            for b in corn_positions.iter().skip(i + 1) {
+                assert!(
+                    a.distance(*b) > 0.01,
+                    "Corns should not overlap: {a} and {b}."
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_death_drop_drops_multiple_corns() {
+        let mut app = combat_app();
+
+        let enemy = app.spawn_enemy(10.0, Vec3::ZERO);
+        app.world_mut().entity_mut(enemy).insert(DeathDrop {
+            count: 3,
+            ..default()
+        });
+        app.step(1);
+
+        app.world_mut().get_mut::<Health>(enemy).unwrap().0 = 0.0;
+        app.step(1);
+
+        let corn_count = app
+            .world_mut()
+            .query_filtered::<(), With<SceneRoot>>()
+            .iter(app.world())
+            .count();
+
+        assert_eq!(corn_count, 3);
+    }
+
+    #[test]
+    fn test_no_death_drop_drops_nothing() {
+        let mut app = combat_app();
+
+        let enemy = app.spawn_enemy(10.0, Vec3::ZERO);
+        app.world_mut().entity_mut(enemy).remove::<DeathDrop>();
+        app.step(1);
+
+        app.world_mut().get_mut::<Health>(enemy).unwrap().0 = 0.0;
+        app.step(1);
+
+        assert!(app.world().get_entity(enemy).is_err());
+        let drop_count = app
+            .world_mut()
+            .query_filtered::<(), With<SceneRoot>>()
+            .iter(app.world())
+            .count();
+        assert_eq!(drop_count, 0);
+    }
+
+    #[test]
+    fn test_bad_death_drop_still_despawns_every_enemy() {
+        let mut app = combat_app();
+
+        let enemies = (0..4)
+            .map(|i| {
+                let enemy = app.spawn_enemy(10.0, Vec3::X * i as f32);
+                app.world_mut().entity_mut(enemy).insert(DeathDrop {
+                    prefab: "missing".to_string(),
+                    count: 1,
+                });
+                enemy
+            })
+            .collect::<Vec<_>>();
+        app.step(1);
+
+        for &enemy in enemies.iter() {
+            app.world_mut().get_mut::<Health>(enemy).unwrap().0 = 0.0;
+        }
+        app.step(1);
+
+        for enemy in enemies {
+            assert!(app.world().get_entity(enemy).is_err());
+        }
+    }
+
+    #[test]
+    fn test_corn_drops_without_current_scene() {
+        let mut app = combat_app();
+        app.insert_resource(CurrentScene::default());
+
+        let enemy = app.spawn_enemy(10.0, Vec3::ZERO);
+        app.step(1);
+
+        app.world_mut().get_mut::<Health>(enemy).unwrap().0 = 0.0;
+        app.step(1);
+
+        assert!(app.world().get_entity(enemy).is_err());
+
+        let orphan_corn_count = app
+            .world_mut()
+            .query_filtered::<(), (With<SceneRoot>, Without<ChildOf>)>()
+            .iter(app.world())
+            .count();
+        assert_eq!(orphan_corn_count, 1, "Corn should not be lost.");
+    }
+
+    #[test]
+    fn test_bounty_awards_currency() {
+        let mut app = combat_app();
+
+        let enemy = app.spawn_enemy(10.0, Vec3::ZERO);
+        app.world_mut().entity_mut(enemy).insert(Bounty(10));
+        app.step(1);
+
+        app.world_mut().get_mut::<Health>(enemy).unwrap().0 = 0.0;
+        app.step(1);
+
+        assert_eq!(app.world().resource::<Currency>().0, 10);
+    }
+
+    #[test]
+    fn test_bounty_scaled_by_economy_config() {
+        let mut app = combat_app();
+        app.world_mut().resource_mut::<EconomyConfig>().bounty_mult =
+            1.5;
+
+        let enemy = app.spawn_enemy(10.0, Vec3::ZERO);
+        app.world_mut().entity_mut(enemy).insert(Bounty(10));
+        app.step(1);
+
+        app.world_mut().get_mut::<Health>(enemy).unwrap().0 = 0.0;
+        app.step(1);
+
+        assert_eq!(app.world().resource::<Currency>().0, 15);
+    }
+
+    #[test]
+    fn test_obstacle_blocks_projectile() {
+        let mut app = combat_app();
+
+        app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 10.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        let enemy = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 6.0));
+
+        // Wall in between the tower and the enemy.
+        app.world_mut().spawn((
+            RigidBody::Static,
+            Collider::cuboid(4.0, 4.0, 0.5),
+            CollisionLayers::new(GameLayer::Obstacle, LayerMask::ALL),
+            Transform::from_xyz(0.0, 0.0, 3.0),
+        ));
+
+        let mut fired = false;
+        for _ in 0..64 {
+            app.update();
+
+            let projectile_count = app
+                .world_mut()
+                .query::<&Projectile>()
+                .iter(app.world())
+                .count();
+            fired |= projectile_count > 0;
+
+            if fired && projectile_count == 0 {
+                break;
+            }
+        }
+
+        assert!(fired, "Tower should have fired.");
+        assert_eq!(
+            app.world_mut()
+                .query::<&Projectile>()
+                .iter(app.world())
+                .count(),
+            0,
+            "Projectile should be despawned by the wall."
+        );
+        assert_eq!(
+            app.world().get::<Health>(enemy).unwrap().0,
+            100.0
+        );
+    }
+
+    #[test]
+    fn test_swept_fast_projectile_hits_enemy() {
+        let fire = |swept: bool| {
+            let mut app = combat_app();
+
+            let tower = app.spawn_tower(
+                "gun_tower",
+                Tower {
+                    range: 20.0,
+                    damage: 10.0,
+                    attack_cooldown: 10.0,
+                    // Covers more than 30 units per step.
+                    projectile_speed: 2000.0,
+                },
+                Vec3::ZERO,
+            );
+            app.world_mut()
+                .entity_mut(tower)
+                .insert(ProjectileShape { swept, ..default() });
+
+            let enemy =
+                app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 10.0));
+
+            app.step(64);
+            app.world().get::<Health>(enemy).unwrap().0
+        };
+
+        assert!(fire(true) < 100.0, "Swept projectile should hit.");
+        assert_eq!(
+            fire(false),
+            100.0,
+            "Unswept projectile should step over the enemy."
+        );
+    }
+
+    #[test]
+    fn test_overheated_tower_resumes_after_cooling() {
+        #[derive(Resource, Default)]
+        struct ShotCount(u32);
+
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 0.0,
+                attack_cooldown: 0.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut().entity_mut(tower).insert(Heat {
+            max: 3.0,
+            per_shot: 1.0,
+            // Takes 1.5 seconds to cool down from max.
+            cooldown_rate: 2.0,
+            ..default()
+        });
+        app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+
+        app.init_resource::<ShotCount>().add_observer(
+            |_: Trigger<TowerFired>, mut shots: ResMut<ShotCount>| {
+                shots.0 += 1;
+            },
+        );
+        let shots = |app: &App| app.world().resource::<ShotCount>().0;
+
+        // Burst until overheated.
+        app.step(32);
+        let burst = shots(&app);
+        assert!(burst >= 3);
+        assert!(app.world().get::<Heat>(tower).unwrap().overheated);
+
+        // Still cooling down.
+        app.step(64);
+        assert_eq!(shots(&app), burst);
+
+        app.step(64);
+        assert!(
+            shots(&app) > burst,
+            "Tower should fire again once cooled down."
+        );
+    }
+
+    #[test]
+    fn test_tower_fires_again_after_cooldown() {
+        #[derive(Resource, Default)]
+        struct ShotCount(u32);
+
+        let mut app = combat_app();
+
+        app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 0.0,
+                attack_cooldown: 2.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+
+        app.init_resource::<ShotCount>().add_observer(
+            |_: Trigger<TowerFired>, mut shots: ResMut<ShotCount>| {
+                shots.0 += 1;
+            },
+        );
+        let shots = |app: &App| app.world().resource::<ShotCount>().0;
+
+        // Time to turn and fire the first shot.
+        app.step(64);
+        assert_eq!(shots(&app), 1);
+
+        // Past the cooldown.
+        app.step(160);
+        assert!(
+            shots(&app) >= 2,
+            "Tower should fire a second projectile."
+        );
+        assert!(
+            app.world_mut()
+                .query::<&AttackCooldown>()
+                .iter(app.world())
+                .all(|cooldown| cooldown.0 >= 0.0),
+            "Cooldowns should be clamped at zero."
+        );
+    }
+
+    #[test]
+    fn test_stale_target_does_not_stop_other_towers() {
+        #[derive(Resource, Default)]
+        struct Shooters(Vec<Entity>);
+
+        let mut app = combat_app();
+
+        let tower_stats = || Tower {
+            range: 10.0,
+            damage: 0.0,
+            attack_cooldown: 0.5,
+            projectile_speed: 20.0,
+        };
+        let stale_tower =
+            app.spawn_tower("gun_tower", tower_stats(), Vec3::ZERO);
+        let other_tower = app.spawn_tower(
+            "gun_tower",
+            tower_stats(),
+            Vec3::new(30.0, 0.0, 0.0),
+        );
+        let stale_enemy =
+            app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+        app.spawn_enemy(100.0, Vec3::new(30.0, 0.0, 5.0));
+
+        app.init_resource::<Shooters>().add_observer(
+            |trigger: Trigger<TowerFired>,
+             mut shooters: ResMut<Shooters>| {
+                shooters.0.push(trigger.target());
+            },
+        );
+
+        app.step(8);
+        assert!(app.world().get::<Target>(stale_tower).is_some());
+
+        // Waiting to be despawned without a transform.
+        app.world_mut()
+            .entity_mut(stale_enemy)
+            .remove::<GlobalTransform>();
+        // Only count the shots fired after the target went stale.
+        app.world_mut().resource_mut::<Shooters>().0.clear();
+        app.step(64);
+
+        let world = app.world();
+        assert!(world.get::<Target>(stale_tower).is_none());
+        assert!(
+            world.resource::<Shooters>().0.contains(&other_tower),
+            "Other towers should keep firing."
+        );
+    }
+
+    #[test]
+    fn test_lobbed_shots() {
+        let lobbed_shots = LobbedShots::default();
+        let start = Vec3::new(0.0, 0.5, 0.0);
+        let target = Vec3::new(3.0, 0.0, 8.0);
+
+        let mut velocity = lobbed_shots
+            .launch_velocity(start, target, 12.0)
+            .unwrap();
+        assert!(velocity.y > 0.0, "Shots should arc upwards.");
+
+        // Follow the arc until it reaches the target distance.
+        let delta_time = 0.001;
+        let mut position = start;
+        while position.xz().distance(start.xz())
+            < target.xz().distance(start.xz())
+        {
+            velocity.y -= lobbed_shots.gravity * delta_time;
+            position += velocity * delta_time;
+        }
+        assert!(position.distance(target) < 0.1);
+
+        // Out of ballistic range.
+        assert!(
+            lobbed_shots
+                .launch_velocity(start, target * 10.0, 12.0)
+                .is_none()
+        );
+
+        let mut app = combat_app();
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 2.0,
+                attack_cooldown: 10.0,
+                projectile_speed: 12.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut().entity_mut(tower).insert(lobbed_shots);
+        let enemy = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 6.0));
+
+        app.step(96);
+        assert_eq!(app.world().get::<Health>(enemy).unwrap().0, 98.0);
+    }
+
+    #[test]
+    fn test_chain_shots() {
+        #[derive(Resource, Default)]
+        struct Arcs(Vec<Vec<Vec3>>);
+
+        let mut app = combat_app();
+        app.init_resource::<Arcs>().add_observer(
+            |trigger: Trigger<ChainArced>, mut arcs: ResMut<Arcs>| {
+                arcs.0.push(trigger.path.clone());
+            },
+        );
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 4.0,
+                damage: 10.0,
+                attack_cooldown: 10.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut().entity_mut(tower).insert(ChainShots {
+            jumps: 3,
+            range: 3.0,
+            falloff: 0.5,
+        });
+
+        let first = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 3.0));
+        let second = app.spawn_enemy(100.0, Vec3::new(2.0, 0.0, 5.0));
+        let third = app.spawn_enemy(100.0, Vec3::new(4.0, 0.0, 6.0));
+        // Too far from the others to be jumped to.
+        let far = app.spawn_enemy(100.0, Vec3::new(-8.0, 0.0, 3.0));
+
+        app.step(64);
+
+        let health =
+            |enemy| app.world().get::<Health>(enemy).unwrap().0;
+        assert_eq!(health(first), 90.0);
+        assert_eq!(health(second), 95.0);
+        assert_eq!(health(third), 97.5);
+        assert_eq!(health(far), 100.0);
+
+        let arcs = &app.world().resource::<Arcs>().0;
+        assert_eq!(arcs.len(), 1);
+        assert_eq!(arcs[0].len(), 3);
+    }
+
+    #[test]
+    fn test_armor_reduces_damage() {
+        fn damage_taken(armor: f32) -> f32 {
+            let mut app = combat_app();
+
+            app.spawn_tower(
+                "gun_tower",
+                Tower {
+                    range: 10.0,
+                    damage: 10.0,
+                    attack_cooldown: 10.0,
+                    projectile_speed: 20.0,
+                },
+                Vec3::ZERO,
+            );
+            let enemy =
+                app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 3.0));
+            app.world_mut().entity_mut(enemy).insert(Armor(armor));
+
+            app.step(64);
+            100.0 - app.world().get::<Health>(enemy).unwrap().0
+        }
+
+        // Same as no armor at all.
+        assert_eq!(damage_taken(0.0), 10.0);
+        assert_eq!(damage_taken(4.0), 6.0);
+        // Never heals the enemy.
+        assert_eq!(damage_taken(25.0), 0.0);
+    }
+
+    #[test]
+    fn test_critical_hits() {
+        #[derive(Resource, Default)]
+        struct Crits(Vec<f32>);
+
+        /// Returns the damage dealt and the landed crits.
+        fn fire_once(crit: CriticalHits) -> (f32, Vec<f32>, u64) {
+            let mut app = combat_app();
+            app.init_resource::<Crits>().add_observer(
+                |trigger: Trigger<CritLanded>,
+                 mut crits: ResMut<Crits>| {
+                    crits.0.push(trigger.amount);
+                },
+            );
+
+            let tower = app.spawn_tower(
+                "gun_tower",
+                Tower {
+                    range: 10.0,
+                    damage: 2.0,
+                    attack_cooldown: 10.0,
+                    projectile_speed: 20.0,
+                },
+                Vec3::ZERO,
+            );
+            app.world_mut().entity_mut(tower).insert(crit);
+            let enemy =
+                app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 3.0));
+
+            app.step(64);
+
+            let world = app.world_mut();
+            let damage =
+                100.0 - world.get::<Health>(enemy).unwrap().0;
+            let crits = world.resource::<Crits>().0.clone();
+            let next = world.resource_mut::<GameRng>().next_u64();
+            (damage, crits, next)
+        }
+
+        let (damage, crits, _) = fire_once(CriticalHits {
+            chance: 1.0,
+            multiplier: 3.0,
+        });
+        assert_eq!(damage, 6.0);
+        assert_eq!(crits, vec![6.0]);
+
+        // No chance is the same as no crits at all.
+        let (damage, crits, next) = fire_once(CriticalHits {
+            chance: 0.0,
+            multiplier: 3.0,
+        });
+        assert_eq!(damage, 2.0);
+        assert!(crits.is_empty());
+        assert_eq!(next, GameRng::new(0).next_u64());
+    }
+
+    #[test]
+    fn test_execute_targets_finishable_enemy() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 10.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut()
+            .entity_mut(tower)
+            .insert(TargetingMode::Execute);
+
+        // Closer to the final target, preferred by default.
+        let full_enemy =
+            app.spawn_enemy(100.0, Vec3::new(3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(full_enemy)
+            .insert(Path::new(vec![IVec2::ZERO]));
+
+        let low_enemy =
+            app.spawn_enemy(5.0, Vec3::new(-3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(low_enemy)
+            .insert(Path::new(vec![IVec2::ZERO; 5]));
+
+        // Without health, never finishable.
+        let unknown_enemy =
+            app.spawn_enemy(5.0, Vec3::new(0.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(unknown_enemy)
+            .insert(Path::new(vec![IVec2::ZERO]))
+            .remove::<Health>();
+
+        let mut target = None;
+        for _ in 0..16 {
+            app.update();
+
+            target =
+                app.world().get::<Target>(tower).map(|t| t.entity());
+            if target.is_some() {
+                break;
+            }
+        }
+
+        assert_eq!(target, Some(low_enemy));
+    }
+
+    #[test]
+    fn test_health_modes_rank_enemies_without_health_last() {
+        let score = |mode: TargetingMode, health| {
+            mode.score(3, 0.5, 2.0, health)
+        };
+
+        for mode in [TargetingMode::Strongest, TargetingMode::Weakest]
+        {
+            assert!(score(mode, None) < score(mode, Some(1.0)));
+            assert!(score(mode, None) < score(mode, Some(1000.0)));
+        }
+
+        let weights = ThreatWeights::default();
+        assert_eq!(
+            score(TargetingMode::Threat(weights), None),
+            weights.score(3, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_path_completing_enemy_stops_being_targeted() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 0.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+
+        // Shortest path, preferred until it completes its path.
+        let leaking =
+            app.spawn_enemy(100.0, Vec3::new(3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(leaking)
+            .insert(Path::new(vec![IVec2::ZERO]));
+
+        let walking =
+            app.spawn_enemy(100.0, Vec3::new(-3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(walking)
+            .insert(Path::new(vec![IVec2::ZERO; 5]));
+
+        let target = |app: &mut App| {
+            for _ in 0..16 {
+                app.update();
+
+                let target = app
+                    .world()
+                    .get::<Target>(tower)
+                    .map(|t| t.entity());
+                if target.is_some() {
+                    return target;
+                }
+            }
+            None
+        };
+
+        assert_eq!(target(&mut app), Some(leaking));
+
+        // Reached the end without a tower to attack.
+        app.world_mut().entity_mut(leaking).insert(TargetReached);
+        app.step(1);
+
+        assert_eq!(target(&mut app), Some(walking));
+    }
+
+    #[test]
+    fn test_friendly_fire_toggles_damage_on_friendly_units() {
+        // Health of a friendly unit of player B after
+        // a shot from player A.
+        let fire = |enabled: bool| {
+            let mut app = combat_app();
+            app.insert_resource(FriendlyFire { enabled });
+
+            let friendly = app
+                .world_mut()
+                .spawn((
+                    Team::PlayerB,
+                    Health(10.0),
+                    RigidBody::Kinematic,
+                    Collider::sphere(0.5),
+                    GameLayer::friendly_layers(),
+                    Transform::from_xyz(0.0, 0.0, 2.0),
+                ))
+                .id();
+
+            app.world_mut().spawn((
+                Projectile {
+                    velocity: Vec3::Z * 10.0,
+                    damage: 4.0,
+                    lifetime: 3.0,
+                    ..default()
+                },
+                Team::PlayerA,
+                Transform::default(),
+            ));
+
+            app.step(32);
+            app.world().get::<Health>(friendly).unwrap().0
+        };
+
+        assert_eq!(fire(true), 6.0);
+        assert_eq!(fire(false), 10.0, "Should fly through.");
+    }
+
+    #[test]
+    fn test_unknown_tower_does_not_stop_other_towers() {
+        let mut app = combat_app();
+
+        let tower = || Tower {
+            range: 10.0,
+            damage: 1.0,
+            attack_cooldown: 100.0,
+            projectile_speed: 1.0,
+        };
+        let unknown = app.spawn_tower(
+            "gun_towr",
+            tower(),
+            Vec3::new(-1.0, 0.0, 0.0),
+        );
+        let known = app.spawn_tower(
+            "gun_tower",
+            tower(),
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+        app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+
+        // Fired shots reset the cooldown.
+        let fired = |app: &App, tower: Entity| {
+            app.world()
+                .get::<AttackCooldown>(tower)
+                .is_some_and(|cooldown| cooldown.0 > 0.0)
+        };
+
+        for _ in 0..64 {
+            app.update();
+            if fired(&app, known) {
+                break;
+            }
+        }
+
+        assert!(fired(&app, known), "Known tower should fire.");
+        assert!(fired(&app, unknown) == false);
+    }
+
+    #[test]
+    fn test_missing_model_does_not_drop_other_shots() {
+        let mut app = combat_app();
+        app.world_mut()
+            .resource_mut::<PrefabAssets>()
+            .named_prefabs
+            .remove(&PrefabName::FileName("roasted_corn").cast());
+
+        let tower = || Tower {
+            range: 10.0,
+            damage: 1.0,
+            attack_cooldown: 100.0,
+            projectile_speed: 1.0,
+        };
+        let cannon = app.spawn_tower(
+            "cannon_tower",
+            tower(),
+            Vec3::new(-1.0, 0.0, 0.0),
+        );
+        let gun = app.spawn_tower(
+            "gun_tower",
+            tower(),
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+        app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+
+        // Fired shots reset the cooldown.
+        let fired = |app: &App, tower: Entity| {
+            app.world()
+                .get::<AttackCooldown>(tower)
+                .is_some_and(|cooldown| cooldown.0 > 0.0)
+        };
+
+        for _ in 0..64 {
+            app.update();
+            if fired(&app, gun) {
+                break;
+            }
+        }
+
+        assert!(fired(&app, gun), "Gun tower should fire.");
+        assert!(fired(&app, cannon) == false);
+        let projectile_count = app
+            .world_mut()
+            .query::<&Projectile>()
+            .iter(app.world())
+            .count();
+        assert_eq!(projectile_count, 1);
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn test_targeting_debug_records_candidates() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 0.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+
+        let far = app.spawn_enemy(100.0, Vec3::new(3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(far)
+            .insert(Path::new(vec![IVec2::ZERO; 5]));
+        let near = app.spawn_enemy(100.0, Vec3::new(-3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(near)
+            .insert(Path::new(vec![IVec2::ZERO]));
+
+        for _ in 0..16 {
+            app.update();
+            if app.world().get::<Target>(tower).is_some() {
+                break;
+            }
+        }
+
+        let pass = app
+            .world()
+            .resource::<TargetingDebug>()
+            .get(tower)
+            .cloned()
+            .expect("Targeting pass should be recorded.");
+
+        assert_eq!(pass.chosen, Some(near));
+        assert_eq!(pass.candidates.len(), 2);
+        let remaining_path = |enemy: Entity| {
+            pass.candidates
+                .iter()
+                .find(|c| c.enemy == enemy)
+                .map(|c| c.remaining_path)
+        };
+        assert_eq!(remaining_path(near), Some(1));
+        assert_eq!(remaining_path(far), Some(5));
+
+        // Despawned towers are forgotten.
+        app.world_mut().entity_mut(tower).despawn();
+        assert!(
+            app.world()
+                .resource::<TargetingDebug>()
+                .get(tower)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_tower_keeps_target_when_better_enemy_appears() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 0.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+
+        let first = app.spawn_enemy(100.0, Vec3::new(3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(first)
+            .insert(Path::new(vec![IVec2::ZERO; 5]));
+
+        let target = |app: &App| {
+            app.world().get::<Target>(tower).map(|t| t.entity())
+        };
+
+        for _ in 0..16 {
+            app.update();
+            if target(&app).is_some() {
+                break;
+            }
+        }
+        assert_eq!(target(&app), Some(first));
+
+        // Shorter path, preferred by the default heuristic.
+        let better =
+            app.spawn_enemy(100.0, Vec3::new(-3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(better)
+            .insert(Path::new(vec![IVec2::ZERO]));
+
+        for _ in 0..64 {
+            app.update();
+            assert_eq!(
+                target(&app),
+                Some(first),
+                "Should not thrash."
+            );
+        }
+    }
+
+    #[test]
+    fn test_projectile_spawns_at_muzzle_offset() {
+        const OFFSET: Vec3 = Vec3::new(0.3, 1.0, 0.5);
+
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 1.0,
+                attack_cooldown: 100.0,
+                projectile_speed: 1.0,
+            },
+            Vec3::ZERO,
+        );
+        app.world_mut()
+            .entity_mut(tower)
+            .insert(MuzzleOffset(OFFSET));
+        // Straight ahead, the tower doesn't need to turn.
+        app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+
+        let mut start = None;
+        for _ in 0..64 {
+            app.update();
+
+            start = app
+                .world_mut()
+                .query_filtered::<&Transform, With<Projectile>>()
+                .iter(app.world())
+                .map(|transform| transform.translation)
+                .next();
+            if start.is_some() {
+                break;
+            }
+        }
+
+        let start = start.expect("Tower should have fired.");
+        assert!(
+            start.distance(OFFSET) < 0.1,
+            "Expected the projectile near {OFFSET}, got {start}."
+        );
+    }
+
+    #[test]
+    fn test_no_target_entity_is_never_selected() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 0.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+
+        // Closer to the final target, preferred by default.
+        let dummy = app.spawn_enemy(100.0, Vec3::new(3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(dummy)
+            .insert((NoTarget, Path::new(vec![IVec2::ZERO])));
+
+        let enemy = app.spawn_enemy(100.0, Vec3::new(-3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(enemy)
+            .insert(Path::new(vec![IVec2::ZERO; 5]));
+
+        let mut targeted = false;
+        for _ in 0..64 {
+            app.update();
+
+            let target =
+                app.world().get::<Target>(tower).map(|t| t.entity());
+            assert_ne!(target, Some(dummy));
+            targeted |= target == Some(enemy);
+        }
+
+        assert!(targeted, "The enemy beside it should be targeted.");
+    }
+
+    #[test]
+    fn test_threat_targeting_weights() {
+        /// Returns the tower's target, the tank and the runner.
+        fn find_threat_target(
+            threat: ThreatWeights,
+        ) -> (Option<Entity>, Entity, Entity) {
+            let mut app = combat_app();
+
+            let tower = app.spawn_tower(
+                "gun_tower",
+                Tower {
+                    range: 10.0,
+                    damage: 1.0,
+                    attack_cooldown: 1.0,
+                    projectile_speed: 20.0,
+                },
+                Vec3::ZERO,
+            );
+            app.world_mut()
+                .entity_mut(tower)
+                .insert(TargetingMode::Threat(threat));
+
+            // Far from the end of the path, but with lots of health.
+            let tank =
+                app.spawn_enemy(100.0, Vec3::new(3.0, 0.0, 3.0));
+            app.world_mut()
+                .entity_mut(tank)
+                .insert(Path::new(vec![IVec2::ZERO; 10]));
+
+            // About to leak, but with little health.
+            let runner =
+                app.spawn_enemy(10.0, Vec3::new(-3.0, 0.0, 3.0));
+            app.world_mut()
+                .entity_mut(runner)
+                .insert(Path::new(vec![IVec2::ZERO; 2]));
+
+            let mut target = None;
+            for _ in 0..16 {
+                app.update();
+
+                target = app
+                    .world()
+                    .get::<Target>(tower)
+                    .map(|t| t.entity());
+                if target.is_some() {
+                    break;
+                }
+            }
+
+            (target, tank, runner)
+        }
+
+        let (target, tank, _) = find_threat_target(ThreatWeights {
+            path_weight: 1.0,
+            health_weight: 1.0,
+        });
+        assert_eq!(target, Some(tank));
+
+        let (target, _, runner) = find_threat_target(ThreatWeights {
+            path_weight: 1.0,
+            health_weight: 0.01,
+        });
+        assert_eq!(target, Some(runner));
+    }
+
+    #[test]
+    fn test_targeting_modes() {
+        /// Returns the tower's target.
+        fn find_mode_target(
+            mode: TargetingMode,
+            enemies: [(f32, Vec3, usize); 3],
+        ) -> (Option<Entity>, [Entity; 3]) {
+            let mut app = combat_app();
+
+            let tower = app.spawn_tower(
+                "gun_tower",
+                Tower {
+                    range: 10.0,
+                    damage: 1.0,
+                    attack_cooldown: 1.0,
+                    projectile_speed: 20.0,
+                },
+                Vec3::ZERO,
+            );
+            app.world_mut().entity_mut(tower).insert(mode);
+
+            let enemies =
+                enemies.map(|(health, position, path_len)| {
+                    let enemy = app.spawn_enemy(health, position);
+                    app.world_mut().entity_mut(enemy).insert(
+                        Path::new(vec![IVec2::ZERO; path_len]),
+                    );
+                    enemy
+                });
+
+            let mut target = None;
+            for _ in 0..16 {
+                app.update();
+
+                target = app
+                    .world()
+                    .get::<Target>(tower)
+                    .map(|t| t.entity());
+                if target.is_some() {
+                    break;
+                }
+            }
+
+            (target, enemies)
+        }
+
+        // (health, position, path length)
+        let enemies = [
+            (50.0, Vec3::new(0.0, 0.0, 2.0), 6),
+            (100.0, Vec3::new(4.0, 0.0, 4.0), 2),
+            (10.0, Vec3::new(-6.0, 0.0, 5.0), 9),
+        ];
+
+        for (mode, expected) in [
+            (TargetingMode::First, 1),
+            (TargetingMode::Last, 2),
+            (TargetingMode::Closest, 0),
+            (TargetingMode::Strongest, 1),
+            (TargetingMode::Weakest, 2),
+        ] {
+            let (target, enemies) = find_mode_target(mode, enemies);
+            assert_eq!(target, Some(enemies[expected]), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn test_first_targets_least_remaining_path() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 1.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+
+        let short = app.spawn_enemy(100.0, Vec3::new(3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(short)
+            .insert(Path::new(vec![IVec2::ZERO; 3]));
+
+        // Longer path, but mostly walked already.
+        let walked =
+            app.spawn_enemy(100.0, Vec3::new(-3.0, 0.0, 3.0));
+        app.world_mut()
+            .entity_mut(walked)
+            .insert(Path::new(vec![IVec2::ZERO; 8]));
+        for _ in 0..7 {
+            app.world_mut()
+                .get_mut::<PathIndex>(walked)
+                .unwrap()
+                .increment();
+        }
+
+        app.step(4);
+        assert_eq!(
+            app.world().get::<Target>(tower).map(|t| t.entity()),
+            Some(walked)
+        );
+    }
+
+    #[test]
+    fn test_slow_shots_slow_without_damage() {
+        let mut app = combat_app();
+
+        let enemy = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 3.0));
+        app.step(1);
+
+        let fire = |app: &mut App, slow: Slow| {
+            app.world_mut().spawn((
+                Transform::default(),
+                Projectile {
+                    velocity: Vec3::Z * 20.0,
+                    damage: 10.0,
+                    lifetime: 3.0,
+                    on_hit_effect: OnHitEffect::Slow(slow),
+                    ..default()
+                },
+            ));
+            app.step(16);
+        };
+
+        fire(
+            &mut app,
+            Slow {
+                factor: 0.5,
+                duration: 2.0,
+            },
+        );
+        assert_eq!(
+            app.world().get::<Health>(enemy).unwrap().0,
+            100.0
+        );
+        app.step(32);
+        let slow = *app.world().get::<Slow>(enemy).unwrap();
+        assert_eq!(slow.factor, 0.5);
+
+        // A weaker slow refreshes the duration only.
+        fire(
+            &mut app,
+            Slow {
+                factor: 0.8,
+                duration: 2.0,
+            },
+        );
+        let refreshed = *app.world().get::<Slow>(enemy).unwrap();
+        assert_eq!(refreshed.factor, 0.5);
+        assert!(refreshed.duration > slow.duration);
+
+        // Wears off eventually.
+        app.step(160);
+        assert!(app.world().get::<Slow>(enemy).is_none());
+    }
+
+    #[test]
+    fn test_momentum_scales_with_distance() {
+        let mut app = combat_app();
+
+        let near = app.spawn_enemy(100.0, Vec3::new(-5.0, 0.0, 1.5));
+        let far = app.spawn_enemy(100.0, Vec3::new(5.0, 0.0, 8.0));
+        app.step(1);
+
+        for x in [-5.0, 5.0] {
+            app.world_mut().spawn((
+                Transform::from_xyz(x, 0.0, 0.0),
+                Projectile {
+                    velocity: Vec3::Z * 10.0,
+                    damage: 10.0,
+                    lifetime: 3.0,
+                    ..default()
+                },
+                Momentum::from(MomentumShots {
+                    momentum: 0.2,
+                    min_multiplier: 0.5,
+                    max_multiplier: 3.0,
+                }),
+            ));
+        }
+
+        app.step(64);
+
+        let damage = |enemy: Entity| {
+            100.0 - app.world().get::<Health>(enemy).unwrap().0
+        };
+
+        assert!(damage(near) > 10.0);
+        assert!(
+            damage(far) > damage(near),
+            "Long flights should deal more damage."
+        );
+    }
+
+    #[test]
+    fn test_wind_deflects_projectile() {
+        let mut app = combat_app();
+        app.insert_resource(WindField {
+            force: Vec3::X * 4.0,
+        });
+
+        let projectile = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                Projectile {
+                    velocity: Vec3::Z * 10.0,
+                    damage: 0.0,
+                    lifetime: 3.0,
+                    ..default()
+                },
+            ))
+            .id();
+        let homing = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                Projectile {
+                    velocity: Vec3::Z * 10.0,
+                    damage: 0.0,
+                    lifetime: 3.0,
+                    ..default()
+                },
+                Homing {
+                    target: None,
+                    turn_rate: 0.0,
+                    reacquisitions: 0,
+                },
+            ))
+            .id();
+
+        app.step(32);
+
+        let translation = |entity| {
+            app.world().get::<Transform>(entity).unwrap().translation
+        };
+
+        assert!(translation(projectile).z > 0.0);
+        assert!(
+            translation(projectile).x > 0.1,
+            "Should drift towards the wind."
+        );
+        assert_eq!(
+            translation(homing).x,
+            0.0,
+            "Homing projectiles should ignore the wind."
+        );
+    }
+
+    #[test]
+    fn test_damage_inserts_hit_flash() {
+        let mut app = combat_app();
+
+        app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 10.0,
+                attack_cooldown: 1.0,
+                projectile_speed: 20.0,
+            },
+            Vec3::ZERO,
+        );
+        let enemy = app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 4.0));
+
+        let mut hit = false;
+        for _ in 0..128 {
+            app.update();
+
+            if app.world().get::<Health>(enemy).unwrap().0 < 100.0 {
+                hit = true;
+                break;
+            }
+        }
+
+        assert!(hit, "Enemy should have been hit.");
+        assert!(app.world().get::<HitFlash>(enemy).is_some());
+    }
+
+    /// Compares the shared [`EnemyGrid`] lookup against a
+    /// [`SpatialQuery`] per tower with 100 towers and 200 enemies.
+    ///
+    /// Run with `cargo test --release bench_ -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_enemy_lookup() {
+        use std::time::Instant;
+
+        use bevy::ecs::system::RunSystemOnce;
+
+        const ITERATIONS: u32 = 100;
+
+        let mut app = combat_app();
+        let mut rng = GameRng::new(0);
+
+        for x in 0..10 {
+            for z in 0..10 {
+                app.spawn_tower(
+                    "gun_tower",
+                    Tower {
+                        range: 6.0,
+                        damage: 0.0,
+                        attack_cooldown: 1.0,
+                        projectile_speed: 20.0,
+                    },
+                    Vec3::new(
+                        x as f32 * 4.0 - 20.0,
+                        0.0,
+                        z as f32 * 4.0 - 20.0,
+                    ),
+                );
+            }
+        }
+
+        for _ in 0..200 {
+            let position = rng.in_disk(24.0);
+            app.spawn_enemy(
+                100.0,
+                Vec3::new(position.x, 0.0, position.y),
+            );
+        }
+        app.step(2);
+
+        let start = Instant::now();
+        let mut spatial_count = 0;
+        for _ in 0..ITERATIONS {
+            spatial_count = app
+                .world_mut()
+                .run_system_once(
+                    |q_towers: Query<(&Tower, &GlobalTransform)>,
+                     spatial_query: SpatialQuery| {
+                        q_towers
+                            .iter()
+                            .map(|(tower, global_transform)| {
+                                spatial_query
+                                    .shape_intersections(
+                                        &Collider::sphere(tower.range),
+                                        global_transform.translation(),
+                                        Quat::IDENTITY,
+                                        &GameLayer::Enemy.query_filter(),
+                                    )
+                                    .len()
+                            })
+                            .sum::<usize>()
+                    },
+                )
+                .unwrap();
+        }
+        let spatial_elapsed = start.elapsed();
+
+        let start = Instant::now();
+        let mut grid_count = 0;
+        for _ in 0..ITERATIONS {
+            app.world_mut()
+                .run_system_once(rebuild_enemy_grid)
+                .unwrap();
+            grid_count = app
+                .world_mut()
+                .run_system_once(
+                    |q_towers: Query<(&Tower, &GlobalTransform)>,
+                     enemy_grid: Res<EnemyGrid>| {
+                        q_towers
+                            .iter()
+                            .map(|(tower, global_transform)| {
+                                enemy_grid
+                                    .in_radius(
+                                        global_transform
+                                            .translation(),
+                                        tower.range,
+                                    )
+                                    .count()
+                            })
+                            .sum::<usize>()
+                    },
+                )
+                .unwrap();
+        }
+        let grid_elapsed = start.elapsed();
+
+        println!(
+            "Spatial query: {:?} ({spatial_count} hits), enemy grid: {:?} ({grid_count} hits) per frame.",
+            spatial_elapsed / ITERATIONS,
+            grid_elapsed / ITERATIONS,
+        );
+    }
+
+    #[test]
+    fn test_projectile_models_are_reused() {
+        let mut app = combat_app();
+
+        let tower = app.spawn_tower(
+            "gun_tower",
+            Tower {
+                range: 10.0,
+                damage: 1.0,
+                // Only fire when the cooldown gets reset below.
+                attack_cooldown: 100.0,
+                projectile_speed: 1.0,
+            },
+            Vec3::ZERO,
+        );
+        app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+
+        let fire = |app: &mut App| {
+            app.world_mut()
+                .get_mut::<AttackCooldown>(tower)
+                .unwrap()
+                .0 = 0.0;
+
+            for _ in 0..64 {
+                app.update();
+
+                let fired = app
+                    .world_mut()
+                    .query::<(Entity, &PooledProjectileModel)>()
+                    .iter(app.world())
+                    .map(|(entity, pooled)| (entity, pooled.0))
+                    .next();
+                if fired.is_some() {
+                    return fired;
+                }
+            }
+            None
+        };
+
+        let (projectile, model) =
+            fire(&mut app).expect("Tower should have fired.");
+        assert_eq!(
+            app.world().get::<Visibility>(model),
+            Some(&Visibility::Inherited)
+        );
+
+        app.world_mut().despawn(projectile);
+        assert_eq!(
+            app.world().get::<Visibility>(model),
+            Some(&Visibility::Hidden)
+        );
+        assert_eq!(
+            app.world()
+                .resource::<ProjectilePool>()
+                .free_count("popcorn"),
+            1
+        );
+
+        let (_, next_model) =
+            fire(&mut app).expect("Tower should have fired again.");
+        assert_eq!(next_model, model);
+        assert_eq!(
+            app.world()
+                .resource::<ProjectilePool>()
+                .free_count("popcorn"),
+            0
+        );
+    }
+
+    #[test]
+    fn test_bigger_tower_fires_bigger_projectiles() {
+        // Model scale and collider radius of the first shot.
+        let fire = |shape: Option<ProjectileShape>| {
+            let mut app = combat_app();
+
+            let tower = app.spawn_tower(
+                "gun_tower",
+                Tower {
+                    range: 10.0,
+                    damage: 1.0,
+                    attack_cooldown: 100.0,
+                    projectile_speed: 1.0,
+                },
+                Vec3::ZERO,
+            );
+            if let Some(shape) = shape {
+                app.world_mut().entity_mut(tower).insert(shape);
+            }
+            app.spawn_enemy(100.0, Vec3::new(0.0, 0.0, 5.0));
+
+            for _ in 0..64 {
+                app.update();
+
+                let fired = app
+                    .world_mut()
+                    .query::<(&Collider, &PooledProjectileModel)>()
+                    .iter(app.world())
+                    .map(|(collider, pooled)| {
+                        let radius = collider
+                            .shape_scaled()
+                            .as_ball()
+                            .map(|ball| ball.radius);
+                        (radius, pooled.0)
+                    })
+                    .next();
+
+                if let Some((radius, model)) = fired {
+                    let scale = app
+                        .world()
+                        .get::<Transform>(model)
+                        .map(|transform| transform.scale);
+                    return (scale, radius);
+                }
+            }
+
+            panic!("Tower should have fired.");
+        };
+
+        let (scale, radius) = fire(None);
+        assert_eq!(scale, Some(Vec3::splat(0.2)));
+        assert_eq!(radius, Some(0.2));
+
+        let (big_scale, big_radius) = fire(Some(ProjectileShape {
+            scale: 0.4,
+            ..default()
+        }));
+        assert_eq!(big_scale, Some(Vec3::splat(0.4)));
+        assert!(big_radius.is_some_and(|r| (r - 0.4).abs() < 1e-5));
+    }
+
+    /// Compares spawning 200 projectiles with their own scene
+    /// against batching them with pooled models, in a single frame.
+    ///
+    /// Run with `cargo test --release bench_ -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_projectile_spawning() {
+        use std::time::{Duration, Instant};
+
+        use bevy::ecs::system::RunSystemOnce;
+
+        const COUNT: usize = 200;
+        const ITERATIONS: u32 = 100;
+
+        let mut app = combat_app();
+
+        let projectile = || Projectile {
+            velocity: Vec3::Z,
+            damage: 1.0,
+            lifetime: 3.0,
+            ..default()
+        };
+
+        let despawn_projectiles = |app: &mut App| {
+            let projectiles = app
+                .world_mut()
+                .query_filtered::<Entity, With<Projectile>>()
+                .iter(app.world())
+                .collect::<Vec<_>>();
+            for projectile in projectiles {
+                app.world_mut().despawn(projectile);
+            }
+        };
+
+        let mut individual_elapsed = Duration::ZERO;
+        for _ in 0..ITERATIONS {
+            let start = Instant::now();
+            app.world_mut()
+                .run_system_once(move |mut commands: Commands| {
+                    for _ in 0..COUNT {
+                        commands.spawn((
+                            Transform::default(),
+                            projectile(),
+                            Visibility::Inherited,
+                            Children::spawn(Spawn((
+                                SceneRoot::default(),
+                                Transform::from_scale(Vec3::splat(
+                                    0.2,
+                                )),
+                            ))),
+                        ));
+                    }
+                })
+                .unwrap();
+            individual_elapsed += start.elapsed();
+
+            despawn_projectiles(&mut app);
+        }
+
+        let spawn_pooled =
+            move |mut commands: Commands,
+                  mut pool: ResMut<ProjectilePool>| {
+                let mut batch = Vec::with_capacity(COUNT);
+                let mut models = Vec::with_capacity(COUNT);
+
+                for _ in 0..COUNT {
+                    let model_entity = pool
+                        .acquire("popcorn")
+                        .unwrap_or_else(|| {
+                            commands
+                                .spawn((
+                                    ProjectileModel("popcorn"),
+                                    SceneRoot::default(),
+                                    Visibility::Hidden,
+                                ))
+                                .id()
+                        });
+
+                    models.push((
+                        model_entity,
+                        (
+                            Transform::from_scale(Vec3::splat(0.2)),
+                            Visibility::Inherited,
+                        ),
+                    ));
+                    batch.push((
+                        Transform::default(),
+                        projectile(),
+                        PooledProjectileModel(model_entity),
+                    ));
+                }
+
+                commands.spawn_batch(batch);
+                commands.insert_batch(models);
+            };
+
+        // Fill up the pool first, as it would be during peak combat.
+        app.world_mut().run_system_once(spawn_pooled).unwrap();
+        despawn_projectiles(&mut app);
+
+        let mut pooled_elapsed = Duration::ZERO;
+        for _ in 0..ITERATIONS {
+            let start = Instant::now();
+            app.world_mut().run_system_once(spawn_pooled).unwrap();
+            pooled_elapsed += start.elapsed();
+
+            despawn_projectiles(&mut app);
+        }
+
+        println!(
+            "Individual spawns: {:?}, pooled batch: {:?} per frame.",
+            individual_elapsed / ITERATIONS,
+            pooled_elapsed / ITERATIONS,
+        );
+    }
+}
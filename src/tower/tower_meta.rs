@@ -0,0 +1,227 @@
+use bevy::asset::{AssetLoader, io::Reader};
+use bevy::asset::{AsyncReadExt, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use super::TowerPrefabName;
+use super::tower_attack::{Tower, TowerSfx};
+
+/// Plugin to handle tower metadata loading, used to describe
+/// tower items before they are placed.
+pub(super) struct TowerMetaPlugin;
+
+impl Plugin for TowerMetaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<TowerMetaAsset>()
+            .init_asset_loader::<TowerMetaAssetLoader>();
+
+        app.add_systems(PreStartup, load_tower_registry)
+            .add_observer(apply_tower_meta);
+    }
+}
+
+/// Give towers the parts of their [`TowerMeta`]
+/// that their prefabs don't carry.
+fn apply_tower_meta(
+    trigger: Trigger<OnInsert, TowerPrefabName>,
+    mut commands: Commands,
+    q_towers: Query<&TowerPrefabName, With<Tower>>,
+    registry: TowerMetaRegistry,
+) {
+    let entity = trigger.target();
+    let Some(meta) = q_towers
+        .get(entity)
+        .ok()
+        .and_then(|prefab_name| registry.get_tower(&prefab_name.0))
+    else {
+        return;
+    };
+
+    commands.entity(entity).insert_if_new(meta.sfx.clone());
+}
+
+/// Startup system: load "towers.tower_meta.ron" and insert as a resource.
+fn load_tower_registry(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(TowerMetaAssetHandle(
+        asset_server.load("towers.tower_meta.ron"),
+    ));
+}
+
+/// Map of tower item ID to its [`TowerMeta`].
+#[derive(Asset, TypePath, Deref, Debug, Clone, Deserialize)]
+pub struct TowerMetaAsset(HashMap<String, TowerMeta>);
+
+/// Stats of the tower built from a tower item - loaded from RON files.
+///
+/// Should be kept in sync with the [`Tower`][super::tower_attack::Tower]
+/// of the tower's prefab.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TowerMeta {
+    pub range: f32,
+    pub damage: f32,
+    pub attack_cooldown: f32,
+    /// File name of the projectile prefab.
+    pub projectile: String,
+    /// Localization key of the projectile's effect description.
+    pub description: String,
+    /// Currency worth of the tower, see
+    /// [`BuildCost`][super::BuildCost].
+    #[serde(default)]
+    pub cost: u32,
+    #[serde(default)]
+    pub sfx: TowerSfx,
+}
+
+impl TowerMeta {
+    /// Localization key of each stat along with its value.
+    pub fn tooltip_stats(&self) -> [(&'static str, String); 4] {
+        [
+            ("tooltip.range", format!("{:.1}", self.range)),
+            ("tooltip.damage", format!("{:.1}", self.damage)),
+            (
+                "tooltip.cooldown",
+                format!("{:.1}s", self.attack_cooldown),
+            ),
+            ("tooltip.projectile", self.projectile.clone()),
+        ]
+    }
+}
+
+#[derive(Resource)]
+pub struct TowerMetaAssetHandle(pub Handle<TowerMetaAsset>);
+
+#[derive(SystemParam)]
+pub struct TowerMetaRegistry<'w> {
+    pub handle: Res<'w, TowerMetaAssetHandle>,
+    pub assets: Res<'w, Assets<TowerMetaAsset>>,
+}
+
+impl TowerMetaRegistry<'_> {
+    pub fn get(&self) -> Option<&TowerMetaAsset> {
+        self.assets.get(&self.handle.0)
+    }
+
+    pub fn get_tower(&self, item_id: &str) -> Option<&TowerMeta> {
+        self.get()?.get(item_id)
+    }
+}
+
+#[derive(Default)]
+pub struct TowerMetaAssetLoader;
+
+impl AssetLoader for TowerMetaAssetLoader {
+    type Asset = TowerMetaAsset;
+
+    type Settings = ();
+
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut ron_str = String::new();
+        reader.read_to_string(&mut ron_str).await?;
+
+        // Hot reloads keep the previous asset on a typo.
+        ron::from_str::<TowerMetaAsset>(&ron_str).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tower_meta.ron"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    const TOWERS: &str = r#"({
+        "gun_tower": (
+            range: 3.0,
+            damage: 1.0,
+            attack_cooldown: 0.5,
+            projectile: "popcorn",
+            description: "tooltip.gun_tower",
+            sfx: (fire: Some("gun_fire.wav")),
+        ),
+    })"#;
+
+    #[test]
+    fn test_tower_tooltip_populated_from_registry() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<TowerMetaAsset>();
+
+        let towers = app
+            .world_mut()
+            .resource_mut::<Assets<TowerMetaAsset>>()
+            .add(ron::from_str::<TowerMetaAsset>(TOWERS).unwrap());
+        app.insert_resource(TowerMetaAssetHandle(towers));
+
+        let (stats, description, missing) = app
+            .world_mut()
+            .run_system_once(|registry: TowerMetaRegistry| {
+                let tower = registry.get_tower("gun_tower").unwrap();
+                (
+                    tower.tooltip_stats(),
+                    tower.description.clone(),
+                    registry.get_tower("cannon_tower").is_none(),
+                )
+            })
+            .unwrap();
+
+        assert_eq!(
+            stats,
+            [
+                ("tooltip.range", "3.0".to_string()),
+                ("tooltip.damage", "1.0".to_string()),
+                ("tooltip.cooldown", "0.5s".to_string()),
+                ("tooltip.projectile", "popcorn".to_string()),
+            ]
+        );
+        assert_eq!(description, "tooltip.gun_tower");
+        // Towers without metadata are simply not found.
+        assert!(missing);
+    }
+
+    #[test]
+    fn test_placed_tower_gets_its_sfx() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<TowerMetaAsset>()
+            .add_observer(apply_tower_meta);
+
+        let towers = app
+            .world_mut()
+            .resource_mut::<Assets<TowerMetaAsset>>()
+            .add(ron::from_str::<TowerMetaAsset>(TOWERS).unwrap());
+        app.insert_resource(TowerMetaAssetHandle(towers));
+
+        let tower = Tower {
+            range: 3.0,
+            damage: 1.0,
+            attack_cooldown: 0.5,
+            projectile_speed: 10.0,
+        };
+        let entity = app
+            .world_mut()
+            .spawn((tower, TowerPrefabName::new("gun_tower")))
+            .id();
+
+        let sfx = app.world().get::<TowerSfx>(entity).unwrap();
+        assert_eq!(sfx.fire.as_deref(), Some("gun_fire.wav"));
+        assert_eq!(sfx.impact, None);
+    }
+}
@@ -0,0 +1,290 @@
+use bevy::asset::{AssetLoader, io::Reader};
+use bevy::asset::{AsyncReadExt, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+use serde::Deserialize;
+
+use crate::action::{PlayerAction, TargetAction};
+use crate::difficulty::Difficulty;
+use crate::player::currency::Currency;
+use crate::player::economy::EconomyConfig;
+
+use super::inspect::InspectedTower;
+use super::tower_attack::{Health, MaxHealth, Tower};
+use super::{BuildCost, InPlacementMode, TowerPrefabName};
+
+/// Plugin to upgrade placed towers through the tiers loaded
+/// from "towers.tower_tiers.ron".
+pub(super) struct TowerUpgradePlugin;
+
+impl Plugin for TowerUpgradePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<TowerTiersAsset>()
+            .init_asset_loader::<TowerTiersAssetLoader>();
+
+        app.add_systems(PreStartup, load_tower_tiers)
+            .add_systems(Update, upgrade_inspected_tower)
+            .add_observer(upgrade_tower);
+
+        app.register_type::<TowerTier>();
+    }
+}
+
+/// Startup system: load "towers.tower_tiers.ron".
+fn load_tower_tiers(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(TowerTiersAssetHandle(
+        asset_server.load("towers.tower_tiers.ron"),
+    ));
+}
+
+/// Upgrade the inspected tower on [`PlayerAction::Upgrade`].
+fn upgrade_inspected_tower(
+    mut commands: Commands,
+    q_players: Query<
+        (&InspectedTower, &TargetAction),
+        Without<InPlacementMode>,
+    >,
+    q_actions: Query<&ActionState<PlayerAction>>,
+) {
+    for (inspected, target_action) in q_players.iter() {
+        let Ok(action) = q_actions.get(target_action.get()) else {
+            continue;
+        };
+
+        if action.just_pressed(&PlayerAction::Upgrade) {
+            commands.trigger_targets(UpgradeTower, **inspected);
+        }
+    }
+}
+
+/// Spend [`Currency`] to bring the tower to its next [`TowerTier`].
+///
+/// [`MaxHealth`] is immutable and only seeds the [`Health`]
+/// in its `on_add` hook, re-inserting it would only trigger
+/// `on_insert`. It is removed and inserted again instead,
+/// with the [`Health`] scaled to keep its ratio afterwards.
+fn upgrade_tower(
+    trigger: Trigger<UpgradeTower>,
+    mut commands: Commands,
+    mut q_towers: Query<(
+        &mut Tower,
+        &TowerPrefabName,
+        Option<&TowerTier>,
+        Option<(&MaxHealth, &Health)>,
+        Option<&mut BuildCost>,
+    )>,
+    registry: TowerTiersRegistry,
+    economy: Res<EconomyConfig>,
+    difficulty: Res<Difficulty>,
+    mut currency: ResMut<Currency>,
+) {
+    let entity = trigger.target();
+    let Ok((mut tower, prefab_name, tier, max_health, build_cost)) =
+        q_towers.get_mut(entity)
+    else {
+        return;
+    };
+
+    let level = tier.map(|tier| tier.0).unwrap_or_default();
+    let Some(next_tier) = registry.get_tier(&prefab_name.0, level)
+    else {
+        // Already at the max tier, or not upgradable at all.
+        return;
+    };
+
+    let cost = economy.tower_cost(next_tier.cost, *difficulty);
+    if currency.0 < cost {
+        return;
+    }
+    currency.0 -= cost;
+    // Upgrades are refunded along with the tower.
+    if let Some(mut build_cost) = build_cost {
+        build_cost.0 += cost;
+    }
+
+    tower.damage *= next_tier.damage_mult;
+    tower.range *= next_tier.range_mult;
+    tower.projectile_speed *= next_tier.projectile_speed_mult;
+
+    let mut entity_cmd = commands.entity(entity);
+    entity_cmd.insert(TowerTier(level + 1));
+
+    if let Some((max_health, health)) = max_health {
+        let max_health = max_health.0 * next_tier.health_mult;
+        // Inserted after the hook seeds the full health.
+        entity_cmd
+            .remove::<MaxHealth>()
+            .insert(MaxHealth(max_health))
+            .insert(Health(health.0 * next_tier.health_mult));
+    }
+
+    info!("Upgraded {} to tier {}.", prefab_name.0, level + 1);
+}
+
+/// Triggered on a placed tower to upgrade it to its next [`TowerTier`].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct UpgradeTower;
+
+/// Number of upgrades applied to a tower, starts at 0.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Component, Default)]
+pub struct TowerTier(pub u32);
+
+/// Map of tower prefab name to its upgrade tiers,
+/// the first entry brings the tower to tier 1.
+#[derive(Asset, TypePath, Deref, Debug, Clone, Deserialize)]
+pub struct TowerTiersAsset(HashMap<String, Vec<TowerTierStats>>);
+
+/// Cost and stat multipliers of an upgrade, applied on top
+/// of the previous tier - loaded from RON files.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct TowerTierStats {
+    pub cost: u32,
+    pub damage_mult: f32,
+    pub range_mult: f32,
+    pub projectile_speed_mult: f32,
+    pub health_mult: f32,
+}
+
+impl Default for TowerTierStats {
+    fn default() -> Self {
+        Self {
+            cost: 0,
+            damage_mult: 1.0,
+            range_mult: 1.0,
+            projectile_speed_mult: 1.0,
+            health_mult: 1.0,
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct TowerTiersAssetHandle(pub Handle<TowerTiersAsset>);
+
+#[derive(SystemParam)]
+pub struct TowerTiersRegistry<'w> {
+    pub handle: Res<'w, TowerTiersAssetHandle>,
+    pub assets: Res<'w, Assets<TowerTiersAsset>>,
+}
+
+impl TowerTiersRegistry<'_> {
+    pub fn get(&self) -> Option<&TowerTiersAsset> {
+        self.assets.get(&self.handle.0)
+    }
+
+    /// The upgrade from `level` to the next tier.
+    pub fn get_tier(
+        &self,
+        prefab_name: &str,
+        level: u32,
+    ) -> Option<&TowerTierStats> {
+        self.get()?.get(prefab_name)?.get(level as usize)
+    }
+}
+
+#[derive(Default)]
+pub struct TowerTiersAssetLoader;
+
+impl AssetLoader for TowerTiersAssetLoader {
+    type Asset = TowerTiersAsset;
+
+    type Settings = ();
+
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut ron_str = String::new();
+        reader.read_to_string(&mut ron_str).await?;
+
+        // Hot reloads keep the previous asset on a typo.
+        ron::from_str::<TowerTiersAsset>(&ron_str).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tower_tiers.ron"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TIERS: &str = r#"({
+        "gun_tower": [
+            (
+                cost: 10,
+                damage_mult: 2.0,
+                range_mult: 1.5,
+                health_mult: 2.0,
+            ),
+        ],
+    })"#;
+
+    #[test]
+    fn test_upgrade_scales_stats_and_health() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<TowerTiersAsset>()
+            .insert_resource(Currency(15))
+            .init_resource::<EconomyConfig>()
+            .init_resource::<Difficulty>()
+            .add_observer(upgrade_tower);
+
+        let tiers = app
+            .world_mut()
+            .resource_mut::<Assets<TowerTiersAsset>>()
+            .add(ron::from_str::<TowerTiersAsset>(TIERS).unwrap());
+        app.insert_resource(TowerTiersAssetHandle(tiers));
+
+        let tower = app
+            .world_mut()
+            .spawn((
+                Tower {
+                    range: 3.0,
+                    damage: 1.0,
+                    attack_cooldown: 0.5,
+                    projectile_speed: 20.0,
+                },
+                TowerPrefabName::new("gun_tower"),
+                MaxHealth(50.0),
+            ))
+            .id();
+        app.world_mut().flush();
+        app.world_mut().get_mut::<Health>(tower).unwrap().0 = 10.0;
+
+        app.world_mut().trigger_targets(UpgradeTower, tower);
+        app.update();
+
+        let world = app.world();
+        let stats = world.get::<Tower>(tower).unwrap();
+        assert_eq!(stats.damage, 2.0);
+        assert_eq!(stats.range, 4.5);
+        assert_eq!(stats.projectile_speed, 20.0);
+        assert_eq!(world.get::<TowerTier>(tower).unwrap().0, 1);
+        assert_eq!(world.get::<MaxHealth>(tower).unwrap().0, 100.0);
+        // Keeps the health ratio instead of fully repairing.
+        assert_eq!(world.get::<Health>(tower).unwrap().0, 20.0);
+        assert_eq!(world.resource::<Currency>().0, 5);
+
+        // No tier left.
+        app.world_mut().trigger_targets(UpgradeTower, tower);
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<TowerTier>(tower).unwrap().0, 1);
+        assert_eq!(world.resource::<Currency>().0, 5);
+    }
+}
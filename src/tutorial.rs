@@ -0,0 +1,252 @@
+use bevy::asset::{AssetLoader, io::Reader};
+use bevy::asset::{AsyncReadExt, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::enemy::spawner::WaveStarted;
+use crate::player::currency::Currency;
+use crate::settings::Settings;
+use crate::tile::PlacedOn;
+use crate::ui::Screen;
+
+/// Plugin to load the tutorial steps and advance through them
+/// as the players progress in a level.
+pub(super) struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<TutorialAsset>()
+            .init_asset_loader::<TutorialAssetLoader>()
+            .init_resource::<TutorialProgress>();
+
+        app.add_systems(PreStartup, load_tutorial)
+            .add_systems(OnEnter(Screen::EnterLevel), reset_tutorial)
+            .add_systems(
+                Update,
+                advance_tutorial.run_if(in_state(Screen::EnterLevel)),
+            )
+            .add_observer(count_started_waves);
+    }
+}
+
+/// Startup system: load "tutorial.tutorial.ron" and insert as a resource.
+fn load_tutorial(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(TutorialAssetHandle(
+        asset_server.load("tutorial.tutorial.ron"),
+    ));
+}
+
+/// Start from the first step on every level.
+fn reset_tutorial(mut commands: Commands) {
+    commands.insert_resource(TutorialProgress::default());
+}
+
+fn count_started_waves(
+    _: Trigger<WaveStarted>,
+    mut progress: ResMut<TutorialProgress>,
+) {
+    progress.waves_started += 1;
+}
+
+/// Move on to the next [`TutorialStep`] once the
+/// condition of the current one is met.
+fn advance_tutorial(
+    mut progress: ResMut<TutorialProgress>,
+    tutorial: Res<TutorialAssetHandle>,
+    tutorials: Res<Assets<TutorialAsset>>,
+    settings: Res<Settings>,
+    currency: Res<Currency>,
+    q_placed_towers: Query<(), With<PlacedOn>>,
+    time: Res<Time>,
+) {
+    if settings.show_tutorial == false {
+        return;
+    }
+
+    let Some(steps) = tutorials.get(&tutorial.0) else {
+        return;
+    };
+    let Some(step) = progress.current_step(steps) else {
+        return;
+    };
+
+    progress.step_elapsed += time.delta_secs();
+
+    let context = TutorialContext {
+        step_elapsed: progress.step_elapsed,
+        waves_started: progress.waves_started,
+        towers_placed: q_placed_towers.iter().count(),
+        currency: currency.0,
+    };
+
+    if step.condition.is_met(&context) {
+        progress.step += 1;
+        progress.step_elapsed = 0.0;
+    }
+}
+
+/// Progress through the [`TutorialAsset`] of the current level.
+#[derive(Resource, Default, Debug)]
+pub struct TutorialProgress {
+    /// Index of the current [`TutorialStep`].
+    pub step: usize,
+    /// Seconds since the current step was shown.
+    pub step_elapsed: f32,
+    /// Number of waves started in the current level.
+    pub waves_started: u32,
+}
+
+impl TutorialProgress {
+    /// The step being shown, [`None`] once the tutorial is finished.
+    pub fn current_step<'a>(
+        &self,
+        steps: &'a TutorialAsset,
+    ) -> Option<&'a TutorialStep> {
+        steps.get(self.step)
+    }
+}
+
+/// What the players have done so far, used to
+/// check a [`TutorialCondition`].
+#[derive(Debug, Clone, Copy)]
+pub struct TutorialContext {
+    pub step_elapsed: f32,
+    pub waves_started: u32,
+    pub towers_placed: usize,
+    pub currency: u32,
+}
+
+#[derive(Asset, TypePath, Deref, Debug, Clone, Deserialize)]
+pub struct TutorialAsset(Vec<TutorialStep>);
+
+/// A single prompt of the tutorial - loaded from RON files.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TutorialStep {
+    /// Locale key of the prompt.
+    pub prompt: String,
+    /// Dismiss the prompt and advance once this is met.
+    pub condition: TutorialCondition,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum TutorialCondition {
+    /// Seconds since the step was shown.
+    Elapsed(f32),
+    /// Number of waves started.
+    WaveStarted(u32),
+    /// Number of towers placed by the players.
+    TowerPlaced(usize),
+    CurrencyAtLeast(u32),
+}
+
+impl TutorialCondition {
+    pub fn is_met(&self, context: &TutorialContext) -> bool {
+        match *self {
+            TutorialCondition::Elapsed(secs) => {
+                context.step_elapsed >= secs
+            }
+            TutorialCondition::WaveStarted(count) => {
+                context.waves_started >= count
+            }
+            TutorialCondition::TowerPlaced(count) => {
+                context.towers_placed >= count
+            }
+            TutorialCondition::CurrencyAtLeast(amount) => {
+                context.currency >= amount
+            }
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct TutorialAssetHandle(pub Handle<TutorialAsset>);
+
+#[derive(Default)]
+pub struct TutorialAssetLoader;
+
+impl AssetLoader for TutorialAssetLoader {
+    type Asset = TutorialAsset;
+
+    type Settings = ();
+
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut ron_str = String::new();
+        reader.read_to_string(&mut ron_str).await?;
+
+        // Hot reloads keep the previous asset on a typo.
+        ron::from_str::<TutorialAsset>(&ron_str).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tutorial.ron"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const STEPS: &str = r#"([
+        (
+            prompt: "tutorial.currency",
+            condition: CurrencyAtLeast(10),
+        ),
+        (
+            prompt: "tutorial.place_tower",
+            condition: TowerPlaced(1),
+        ),
+    ])"#;
+
+    #[test]
+    fn test_meeting_condition_advances_step() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<TutorialAsset>()
+            .init_resource::<TutorialProgress>()
+            .init_resource::<Settings>()
+            .init_resource::<Currency>()
+            .add_systems(Update, advance_tutorial);
+
+        let steps = app
+            .world_mut()
+            .resource_mut::<Assets<TutorialAsset>>()
+            .add(ron::from_str::<TutorialAsset>(STEPS).unwrap());
+        app.insert_resource(TutorialAssetHandle(steps));
+
+        let step = |app: &App| {
+            app.world().resource::<TutorialProgress>().step
+        };
+
+        app.update();
+        assert_eq!(step(&app), 0);
+
+        app.world_mut().resource_mut::<Currency>().0 = 10;
+        app.update();
+        assert_eq!(step(&app), 1);
+
+        // Already met conditions of past steps don't skip ahead.
+        app.update();
+        assert_eq!(step(&app), 1);
+
+        let tile = app.world_mut().spawn_empty().id();
+        app.world_mut().spawn(PlacedOn(tile));
+        app.update();
+        assert_eq!(step(&app), 2);
+
+        // Finished.
+        app.update();
+        assert_eq!(step(&app), 2);
+    }
+}
@@ -3,14 +3,32 @@ use bevy::ecs::spawn::SpawnWith;
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 use bevy::window::{CursorGrabMode, PrimaryWindow};
-use widgets::button::{ButtonBackground, LabelButton};
+use locale::LocalizedText;
+use screen_fade::ScreenFade;
+use widgets::button::{
+    ButtonActivated, ButtonBackground, LabelButton,
+};
 
+use crate::AppPluginConfig;
 use crate::asset_pipeline::{AssetState, SceneAssetsLoader};
 
+mod bounty_ui;
+#[cfg(feature = "dev")]
+mod debug_overlay;
+pub mod font;
 mod game_over_ui;
 mod health_bar_ui;
+mod heat_gauge_ui;
 mod inventory_ui;
+mod loadout_ui;
+pub mod locale;
+mod player_hud_ui;
 mod player_mark_ui;
+pub mod player_ui;
+mod radial_menu_ui;
+mod reticle_ui;
+pub mod screen_fade;
+mod tutorial_ui;
 mod wave_countdown_ui;
 pub mod widgets;
 pub mod world_space;
@@ -21,15 +39,36 @@ impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
             world_space::WorldSpaceUiPlugin,
+            locale::LocalePlugin,
+            player_ui::PlayerUiPlugin,
             widgets::WidgetsPlugin,
             inventory_ui::InventoryUiPlugin,
             health_bar_ui::HealthBarUiPlugin,
             player_mark_ui::PlayerMarkUiPlugin,
+            player_hud_ui::PlayerHudUiPlugin,
             game_over_ui::GameOverUiPlugin,
             wave_countdown_ui::WaveCountdownUiPlugin,
+            bounty_ui::BountyUiPlugin,
+            reticle_ui::ReticleUiPlugin,
+            screen_fade::ScreenFadePlugin,
+            tutorial_ui::TutorialUiPlugin,
+            heat_gauge_ui::HeatGaugeUiPlugin,
         ));
 
+        app.add_plugins((
+            loadout_ui::LoadoutUiPlugin,
+            font::UiFontPlugin,
+            radial_menu_ui::RadialMenuUiPlugin,
+        ));
+
+        #[cfg(feature = "dev")]
+        app.add_plugins(debug_overlay::DebugOverlayPlugin);
+
         app.add_sub_state::<Screen>()
+            .add_systems(
+                OnEnter(AssetState::Loaded),
+                enter_start_screen,
+            )
             .add_systems(
                 OnEnter(Screen::Menu),
                 (
@@ -124,14 +163,14 @@ fn setup_menu(mut commands: Commands) {
                         padding: UiRect::all(Val::Px(10.0)),
                         ..default()
                     },
-                    Text::new("Bunguette"),
+                    LocalizedText::new("menu.title"),
                     TextFont::from_font_size(FONT_SIZE * 1.5),
                     TextColor(font_color.into()),
                 )),
                 SpawnWith(move |parent: &mut ChildSpawner| {
                     parent
                         .spawn(
-                            LabelButton::new("Play")
+                            LabelButton::new("menu.play")
                                 .with_background(
                                     ButtonBackground::new(play_color),
                                 )
@@ -141,11 +180,18 @@ fn setup_menu(mut commands: Commands) {
                         )
                         .observe(play_on_click);
 
+                    loadout_ui::spawn_loadout_menu(
+                        parent,
+                        exit_color,
+                        font_color,
+                        FONT_SIZE * 0.6,
+                    );
+
                     // Only add exit button for non-web game.
                     #[cfg(not(target_arch = "wasm32"))]
                     parent
                         .spawn(
-                            LabelButton::new("Exit")
+                            LabelButton::new("menu.exit")
                                 .with_background(
                                     ButtonBackground::new(exit_color),
                                 )
@@ -160,17 +206,31 @@ fn setup_menu(mut commands: Commands) {
     ));
 }
 
-fn play_on_click(
-    _: Trigger<Pointer<Click>>,
+/// Enter the configured start screen once all assets are loaded.
+fn enter_start_screen(
+    config: Option<Res<AppPluginConfig>>,
     mut screen: ResMut<NextState<Screen>>,
 ) {
-    // screen.set(Screen::LevelSelection);
-    screen.set(Screen::EnterLevel);
+    let Some(config) = config else {
+        return;
+    };
+
+    if config.start_screen != Screen::default() {
+        screen.set(config.start_screen.clone());
+    }
+}
+
+fn play_on_click(
+    _: Trigger<ButtonActivated>,
+    mut screen_fade: ResMut<ScreenFade>,
+) {
+    // screen_fade.transition_to(Screen::LevelSelection);
+    screen_fade.transition_to(Screen::EnterLevel);
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 fn exit_on_click(
-    _: Trigger<Pointer<Click>>,
+    _: Trigger<ButtonActivated>,
     mut exit: EventWriter<AppExit>,
 ) {
     exit.write(AppExit::Success);
@@ -186,3 +246,87 @@ pub enum Screen {
     EnterLevel, // TODO: Create substates for levels (1, 2, 3, ...).
     GameOver,
 }
+
+#[cfg(test)]
+mod test {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::asset_pipeline::{CurrentScene, SceneAssets};
+    use crate::enemy::Enemy;
+    use crate::enemy::spawner::SpawnWave;
+    use crate::test_util::empty_gltf;
+    use crate::tower::Projectile;
+
+    #[test]
+    fn test_restart_resets_level() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_asset::<Gltf>()
+        .init_asset::<Scene>()
+        .init_resource::<CurrentScene>()
+        .insert_state(AssetState::Loaded)
+        .add_sub_state::<Screen>()
+        .add_sub_state::<SpawnWave>()
+        .add_systems(OnEnter(Screen::EnterLevel), load_level1);
+
+        let default_scene = empty_gltf(app.world_mut());
+        let level1 = empty_gltf(app.world_mut());
+        app.insert_resource(SceneAssets {
+            default_scene,
+            level1,
+        });
+
+        let set_screen = |app: &mut App, screen: Screen| {
+            app.world_mut()
+                .resource_mut::<NextState<Screen>>()
+                .set(screen);
+            app.update();
+        };
+
+        set_screen(&mut app, Screen::EnterLevel);
+
+        // Halfway through the level.
+        app.world_mut()
+            .resource_mut::<NextState<SpawnWave>>()
+            .set(SpawnWave::Two);
+        app.update();
+        app.world_mut().spawn(Enemy {
+            movement_speed: 1.0,
+            damage: 1.0,
+            attack_cooldown: 1.0,
+        });
+        app.world_mut().spawn(Projectile {
+            velocity: Vec3::Z,
+            damage: 1.0,
+            lifetime: 1.0,
+            ..default()
+        });
+
+        set_screen(&mut app, Screen::GameOver);
+        set_screen(&mut app, Screen::EnterLevel);
+
+        assert_eq!(
+            app.world().resource::<State<SpawnWave>>().get(),
+            &SpawnWave::None
+        );
+        assert_eq!(
+            app.world_mut()
+                .query_filtered::<(), With<Enemy>>()
+                .iter(app.world())
+                .count(),
+            0
+        );
+        assert_eq!(
+            app.world_mut()
+                .query_filtered::<(), With<Projectile>>()
+                .iter(app.world())
+                .count(),
+            0
+        );
+    }
+}
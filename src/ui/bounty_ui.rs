@@ -0,0 +1,97 @@
+use bevy::color::palettes::tailwind::*;
+use bevy::prelude::*;
+
+use crate::player::PlayerType;
+use crate::player::currency::BountyAwarded;
+use crate::ui::player_ui::PlayerUi;
+use crate::ui::world_space::{RelatedWorldUis, WorldUi};
+
+pub(super) struct BountyUiPlugin;
+
+impl Plugin for BountyUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(spawn_bounty_text)
+            .add_systems(Update, float_bounty_text);
+    }
+}
+
+/// Spawn a "+N" text on both player's viewport.
+fn spawn_bounty_text(
+    trigger: Trigger<BountyAwarded>,
+    mut commands: Commands,
+) {
+    let bounty = trigger.event();
+
+    // The enemy is already despawned, use an anchor instead.
+    let anchor = commands
+        .spawn((
+            BountyText::default(),
+            Transform::from_translation(bounty.position),
+        ))
+        .id();
+
+    for player_type in [PlayerType::A, PlayerType::B] {
+        commands.spawn((
+            Text::new(format!("+{}", bounty.amount)),
+            TextFont::from_font_size(20.0),
+            TextColor(AMBER_400.into()),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            Pickable::IGNORE,
+            WorldUi::new(anchor).with_world_offset(Vec3::Y * 1.5),
+            PlayerUi(player_type),
+        ));
+    }
+}
+
+/// Rise and fade out the bounty texts.
+fn float_bounty_text(
+    mut commands: Commands,
+    mut q_anchors: Query<(
+        &mut BountyText,
+        &mut Transform,
+        &RelatedWorldUis,
+        Entity,
+    )>,
+    mut q_text_colors: Query<&mut TextColor>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut bounty_text, mut transform, world_uis, entity) in
+        q_anchors.iter_mut()
+    {
+        bounty_text.elapsed += dt;
+
+        let progress =
+            (bounty_text.elapsed / BountyText::DURATION).min(1.0);
+        if progress >= 1.0 {
+            // Despawns the related world uis as well.
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation.y += BountyText::RISE_SPEED * dt;
+
+        for ui in world_uis.iter() {
+            if let Ok(mut color) = q_text_colors.get_mut(ui) {
+                color.0.set_alpha(1.0 - progress);
+            }
+        }
+    }
+}
+
+/// Anchor of the floating bounty texts.
+#[derive(Component, Default, Debug)]
+struct BountyText {
+    elapsed: f32,
+}
+
+impl BountyText {
+    /// Duration in seconds before the text disappears.
+    const DURATION: f32 = 0.8;
+    /// Upward speed of the text in world units per second.
+    const RISE_SPEED: f32 = 1.0;
+}
@@ -0,0 +1,155 @@
+use bevy::color::palettes::tailwind::*;
+use bevy::diagnostic::{
+    DiagnosticsStore, FrameTimeDiagnosticsPlugin,
+};
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::camera_controller::UI_RENDER_LAYER;
+use crate::enemy::Enemy;
+use crate::enemy::culling::CameraCulled;
+use crate::enemy::spawner::SpawnWave;
+use crate::player::player_mark::PlayerMark;
+use crate::tower::Projectile;
+use crate::tower::combat_timings::CombatTimings;
+use crate::tower::tower_attack::Tower;
+
+/// Dev only overlay with live counts of the combat simulation.
+/// Toggled with [`DebugOverlay::TOGGLE_KEY`].
+pub(super) struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        if app.is_plugin_added::<FrameTimeDiagnosticsPlugin>()
+            == false
+        {
+            app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+        }
+
+        app.add_systems(Startup, spawn_debug_overlay).add_systems(
+            Update,
+            (
+                toggle_debug_overlay,
+                update_debug_overlay.run_if(debug_overlay_visible),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn spawn_debug_overlay(mut commands: Commands) {
+    commands.spawn((
+        DebugOverlay,
+        UI_RENDER_LAYER,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(ZINC_950.with_alpha(0.7).into()),
+        BorderRadius::all(Val::Px(6.0)),
+        Pickable::IGNORE,
+        FocusPolicy::Pass,
+        // Should be on top of all other uis.
+        GlobalZIndex(30),
+        Visibility::Hidden,
+        Children::spawn(Spawn((
+            DebugOverlayText,
+            Text::default(),
+            TextFont::from_font_size(14.0),
+            TextColor(LIME_300.into()),
+        ))),
+    ));
+}
+
+fn debug_overlay_visible(
+    q_overlay: Query<&Visibility, With<DebugOverlay>>,
+) -> bool {
+    q_overlay
+        .single()
+        .is_ok_and(|viz| *viz != Visibility::Hidden)
+}
+
+fn toggle_debug_overlay(
+    mut q_overlay: Query<&mut Visibility, With<DebugOverlay>>,
+    kbd_inputs: Res<ButtonInput<KeyCode>>,
+) -> Result {
+    if kbd_inputs.just_pressed(DebugOverlay::TOGGLE_KEY) {
+        q_overlay.single_mut()?.toggle_visible_hidden();
+    }
+
+    Ok(())
+}
+
+fn update_debug_overlay(
+    mut q_text: Query<&mut Text, With<DebugOverlayText>>,
+    q_towers: Query<(), With<Tower>>,
+    q_enemies: Query<(), With<Enemy>>,
+    q_projectiles: Query<(), With<Projectile>>,
+    q_culled: Query<&CameraCulled>,
+    wave: Option<Res<State<SpawnWave>>>,
+    player_mark: Option<Res<PlayerMark>>,
+    diagnostics: Res<DiagnosticsStore>,
+    combat_timings: Option<Res<CombatTimings>>,
+) -> Result {
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.smoothed())
+        .unwrap_or_default();
+    let frame_time = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|frame_time| frame_time.smoothed())
+        .unwrap_or_default();
+
+    let heaviest_system = combat_timings
+        .as_ref()
+        .and_then(|timings| timings.heaviest())
+        .map(|(name, duration)| {
+            format!(
+                "{name} ({:.2} ms)",
+                duration.as_secs_f64() * 1000.0
+            )
+        })
+        .unwrap_or("-".to_string());
+
+    let wave = wave
+        .map(|wave| format!("{:?}", wave.get()))
+        .unwrap_or("-".to_string());
+    let base_health = player_mark
+        .map(|mark| mark.0.to_string())
+        .unwrap_or("-".to_string());
+
+    let (culled_a, culled_b) =
+        q_culled.iter().fold((0, 0), |(a, b), culled| {
+            (a + culled.a as usize, b + culled.b as usize)
+        });
+
+    q_text.single_mut()?.0 = format!(
+        "FPS: {fps:.0} ({frame_time:.2} ms)\n\
+        Heaviest combat system: {heaviest_system}\n\
+        Towers: {}\n\
+        Enemies: {}\n\
+        Culled enemies: A {culled_a} | B {culled_b}\n\
+        Projectiles: {}\n\
+        Wave: {wave}\n\
+        Base health: {base_health}",
+        q_towers.iter().len(),
+        q_enemies.iter().len(),
+        q_projectiles.iter().len(),
+    );
+
+    Ok(())
+}
+
+/// Root node of the debug overlay.
+#[derive(Component)]
+pub struct DebugOverlay;
+
+impl DebugOverlay {
+    pub const TOGGLE_KEY: KeyCode = KeyCode::F3;
+}
+
+#[derive(Component)]
+pub struct DebugOverlayText;
@@ -0,0 +1,158 @@
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+
+/// Plugin to swap the embedded UI font with a custom one.
+pub(super) struct UiFontPlugin;
+
+impl Plugin for UiFontPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiFontConfig>()
+            .init_resource::<UiFont>();
+
+        app.register_type::<UiFontConfig>();
+
+        app.add_systems(
+            Update,
+            (
+                load_custom_font
+                    .run_if(resource_changed::<UiFontConfig>),
+                resolve_custom_font,
+                apply_ui_font,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Start loading the custom font of the [`UiFontConfig`],
+/// or go back to the embedded font.
+fn load_custom_font(
+    mut commands: Commands,
+    config: Res<UiFontConfig>,
+    asset_server: Res<AssetServer>,
+    mut ui_font: ResMut<UiFont>,
+) {
+    match &config.custom {
+        Some(path) => {
+            commands.insert_resource(PendingUiFont(
+                asset_server.load(path.clone()),
+            ));
+        }
+        None => {
+            commands.remove_resource::<PendingUiFont>();
+            ui_font.set_if_neq(UiFont::default());
+        }
+    }
+}
+
+/// Use the custom font once it's loaded,
+/// keeping the embedded font if it fails to load.
+fn resolve_custom_font(
+    mut commands: Commands,
+    pending: Option<Res<PendingUiFont>>,
+    asset_server: Res<AssetServer>,
+    mut ui_font: ResMut<UiFont>,
+) {
+    let Some(pending) = pending else {
+        return;
+    };
+
+    match asset_server.load_state(&pending.0) {
+        LoadState::Loaded => {
+            ui_font.set_if_neq(UiFont(pending.0.clone()));
+        }
+        LoadState::Failed(err) => {
+            warn!("Failed to load custom UI font: {err}");
+            ui_font.set_if_neq(UiFont::default());
+        }
+        LoadState::NotLoaded | LoadState::Loading => return,
+    }
+
+    commands.remove_resource::<PendingUiFont>();
+}
+
+/// Apply the [`UiFont`] to every UI text, including the
+/// labels of [`LabelButton`][super::widgets::button::LabelButton]s
+/// and the HUD.
+fn apply_ui_font(
+    mut q_fonts: Query<&mut TextFont, With<Text>>,
+    ui_font: Res<UiFont>,
+) {
+    for mut font in q_fonts.iter_mut() {
+        if ui_font.is_changed() == false && font.is_added() == false {
+            continue;
+        }
+
+        if font.font != ui_font.0 {
+            font.font = ui_font.0.clone();
+        }
+    }
+}
+
+/// Font choice of the UI theme.
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource)]
+pub struct UiFontConfig {
+    /// Asset path of a custom font, e.g. for glyphs that
+    /// the embedded font lacks. The embedded font is used
+    /// when `None` or when the font fails to load.
+    pub custom: Option<String>,
+}
+
+/// The font applied to every UI text,
+/// the default handle is the embedded font.
+#[derive(Resource, Deref, Default, Debug, Clone, PartialEq)]
+pub struct UiFont(pub Handle<Font>);
+
+/// Custom font that is still loading.
+#[derive(Resource, Debug)]
+struct PendingUiFont(Handle<Font>);
+
+#[cfg(test)]
+mod test {
+    use bevy::text::FontLoader;
+
+    use super::*;
+
+    #[test]
+    fn test_failed_custom_font_keeps_embedded_font() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Font>()
+            .init_asset_loader::<FontLoader>()
+            .add_plugins(UiFontPlugin);
+
+        let text = app
+            .world_mut()
+            .spawn((Text::new("Play"), TextFont::default()))
+            .id();
+
+        app.insert_resource(UiFontConfig {
+            custom: Some("fonts/missing.ttf".to_string()),
+        });
+
+        // Wait for the load to fail.
+        for _ in 0..200 {
+            app.update();
+            if app.world().contains_resource::<PendingUiFont>()
+                == false
+            {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+
+        assert!(
+            app.world().contains_resource::<PendingUiFont>() == false,
+            "Custom font should have failed to load."
+        );
+        assert_eq!(
+            *app.world().resource::<UiFont>(),
+            UiFont::default()
+        );
+        assert_eq!(
+            app.world().get::<TextFont>(text).unwrap().font,
+            Handle::default()
+        );
+    }
+}
@@ -6,7 +6,12 @@ use crate::camera_controller::UI_RENDER_LAYER;
 use crate::player::player_mark::PlayerMark;
 
 use super::Screen;
-use super::widgets::button::{ButtonBackground, LabelButton};
+use super::locale::LocalizedText;
+use super::screen_fade::ScreenFade;
+use super::widgets::button::{
+    ButtonActivated, ButtonBackground, LabelButton,
+};
+use super::widgets::menu_navigation::BackButton;
 
 pub(super) struct GameOverUiPlugin;
 
@@ -67,45 +72,83 @@ fn spawn_game_over_ui(
                         padding: UiRect::all(Val::Px(10.0)),
                         ..default()
                     },
-                    if win {
-                        Text::new("Victory!")
+                    LocalizedText::new(if win {
+                        "game_over.victory"
                     } else {
-                        Text::new("Failed!")
-                    },
+                        "game_over.failed"
+                    }),
                     TextColor(font_color.into()),
                     TextLayout::new_with_justify(JustifyText::Center),
                     TextFont::from_font_size(FONT_SIZE * 1.5),
                 )),
                 SpawnWith(move |parent: &mut ChildSpawner| {
-                    parent
-                        .spawn(
-                            LabelButton::new(if win {
-                                "Continue"
-                            } else {
-                                "Retry"
-                            })
+                    let retry_button = || {
+                        LabelButton::new("game_over.retry")
                             .with_background(ButtonBackground::new(
-                                if win {
-                                    green_color
-                                } else {
-                                    red_color
-                                }
-                                .with_alpha(0.45),
+                                red_color.with_alpha(0.45),
                             ))
                             .with_text_color(font_color)
                             .with_font_size(FONT_SIZE)
-                            .build(),
-                        )
-                        .observe(return_to_main_menu);
+                            .build()
+                    };
+
+                    if win {
+                        parent
+                            .spawn((
+                                BackButton,
+                                LabelButton::new(
+                                    "game_over.continue",
+                                )
+                                .with_background(
+                                    ButtonBackground::new(
+                                        green_color.with_alpha(0.45),
+                                    ),
+                                )
+                                .with_text_color(font_color)
+                                .with_font_size(FONT_SIZE)
+                                .build(),
+                            ))
+                            .observe(return_to_main_menu);
+                        parent
+                            .spawn(retry_button())
+                            .observe(restart_level);
+                    } else {
+                        parent
+                            .spawn(retry_button())
+                            .observe(restart_level);
+                        parent
+                            .spawn((
+                                BackButton,
+                                LabelButton::new("game_over.menu")
+                                    .with_background(
+                                        ButtonBackground::new(
+                                            bg_color.with_alpha(0.45),
+                                        ),
+                                    )
+                                    .with_text_color(font_color)
+                                    .with_font_size(FONT_SIZE * 0.6)
+                                    .build(),
+                            ))
+                            .observe(return_to_main_menu);
+                    }
                 }),
             )),
         ))),
     ));
 }
 
+/// Reload the level from scratch without going through the menu,
+/// the [`Difficulty`][crate::difficulty::Difficulty] is kept as is.
+fn restart_level(
+    _: Trigger<ButtonActivated>,
+    mut screen_fade: ResMut<ScreenFade>,
+) {
+    screen_fade.transition_to(Screen::EnterLevel)
+}
+
 fn return_to_main_menu(
-    _: Trigger<Pointer<Click>>,
-    mut next_screen: ResMut<NextState<Screen>>,
+    _: Trigger<ButtonActivated>,
+    mut screen_fade: ResMut<ScreenFade>,
 ) {
-    next_screen.set(Screen::Menu)
+    screen_fade.transition_to(Screen::Menu)
 }
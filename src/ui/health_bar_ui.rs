@@ -5,17 +5,27 @@ use crate::camera_controller::split_screen::{
     CameraType, QueryCameras,
 };
 use crate::enemy::Enemy;
+use crate::player::PlayerType;
 use crate::tower::tower_attack::{Health, MaxHealth};
+use crate::ui::player_ui::PlayerUi;
 use crate::ui::world_space::WorldUi;
 
 pub struct HealthBarUiPlugin;
 
 impl Plugin for HealthBarUiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(spawn_health_bar).add_systems(
-            Update,
-            (update_health_bars, update_health_bar_visibility),
-        );
+        app.init_resource::<HealthBarPool>()
+            .add_observer(spawn_health_bar)
+            .add_observer(release_pooled_health_bar)
+            .add_systems(
+                Update,
+                (update_health_bars, update_health_bar_visibility),
+            )
+            .add_systems(
+                PostUpdate,
+                follow_health_bar_owner
+                    .before(TransformSystem::TransformPropagate),
+            );
     }
 }
 
@@ -23,67 +33,164 @@ fn spawn_health_bar(
     trigger: Trigger<OnAdd, Health>,
     mut commands: Commands,
     q_entity: Query<
-        (&Health, &MaxHealth, Has<Enemy>),
-        Without<HasHealthBar>,
+        Has<Enemy>,
+        (With<Health>, With<MaxHealth>, Without<HasHealthBar>),
     >,
-    q_cameras: QueryCameras<Entity>,
-) -> Result {
+    mut q_anchors: Query<&mut HealthBarAnchor>,
+    mut pool: ResMut<HealthBarPool>,
+) {
     let entity = trigger.target();
 
-    let Ok((_health, _max_health, is_enemy)) = q_entity.get(entity)
-    else {
-        return Ok(());
+    let Ok(is_enemy) = q_entity.get(entity) else {
+        return;
     };
 
-    let color = if is_enemy { RED_500 } else { GREEN_500 };
+    if is_enemy == false {
+        // Create health bars for both players.
+        let health_bar_a = create_health_bar(
+            &mut commands,
+            entity,
+            GREEN_500,
+            PlayerType::A,
+        );
+        let health_bar_b = create_health_bar(
+            &mut commands,
+            entity,
+            GREEN_500,
+            PlayerType::B,
+        );
 
-    let camera_a = q_cameras.get(CameraType::A)?;
-    let camera_b = q_cameras.get(CameraType::B)?;
+        commands.entity(entity).insert(HasHealthBar {
+            camera_a: health_bar_a,
+            camera_b: health_bar_b,
+        });
+        return;
+    }
 
-    let create_health_bar = |commands: &mut Commands,
-                             camera_entity: Entity|
-     -> Entity {
-        let fill_bar = commands
-            .spawn((
-                Node {
-                    width: Val::Percent(100.0),
-                    height: Val::Percent(100.0),
-                    ..default()
-                },
-                BackgroundColor(color.into()),
-                BorderRadius::all(Val::VMin(0.2)),
-            ))
-            .id();
-
-        commands
-            .spawn((
-                Node {
-                    position_type: PositionType::Absolute,
-                    width: Val::VMin(6.0),
-                    height: Val::VMin(0.6),
-                    ..default()
-                },
-                BackgroundColor(Color::BLACK.with_alpha(0.9)),
-                BorderRadius::all(Val::VMin(0.2)),
-                WorldUi::new(entity).with_world_offset(Vec3::Y * 1.0),
-                UiTargetCamera(camera_entity),
-            ))
-            .add_child(fill_bar)
-            .id()
-    };
+    // Enemies come and go in large waves, reuse the bars instead.
+    let (anchor_entity, camera_a, camera_b) =
+        if let Some(anchor_entity) = pool.free.pop() {
+            let Ok(mut anchor) = q_anchors.get_mut(anchor_entity)
+            else {
+                return;
+            };
+            anchor.owner = Some(entity);
+
+            (anchor_entity, anchor.camera_a, anchor.camera_b)
+        } else if pool.count < HealthBarPool::CAPACITY {
+            pool.count += 1;
+
+            let anchor_entity =
+                commands.spawn(Transform::default()).id();
+            let camera_a = create_health_bar(
+                &mut commands,
+                anchor_entity,
+                RED_500,
+                PlayerType::A,
+            );
+            let camera_b = create_health_bar(
+                &mut commands,
+                anchor_entity,
+                RED_500,
+                PlayerType::B,
+            );
+
+            commands.entity(anchor_entity).insert(HealthBarAnchor {
+                owner: Some(entity),
+                camera_a,
+                camera_b,
+            });
+
+            (anchor_entity, camera_a, camera_b)
+        } else {
+            // Too many enemies alive, skip the health bar.
+            return;
+        };
 
-    // Create health bars for both cameras
-    let health_bar_a = create_health_bar(&mut commands, camera_a);
-    let health_bar_b = create_health_bar(&mut commands, camera_b);
+    commands.entity(entity).insert((
+        HasHealthBar { camera_a, camera_b },
+        PooledHealthBar(anchor_entity),
+    ));
+}
+
+fn create_health_bar(
+    commands: &mut Commands,
+    target: Entity,
+    color: Srgba,
+    player_type: PlayerType,
+) -> Entity {
+    let fill_bar = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(color.into()),
+            BorderRadius::all(Val::VMin(0.2)),
+        ))
+        .id();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::VMin(6.0),
+                height: Val::VMin(0.6),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.9)),
+            BorderRadius::all(Val::VMin(0.2)),
+            WorldUi::new(target).with_world_offset(Vec3::Y * 1.0),
+            PlayerUi(player_type),
+        ))
+        .add_child(fill_bar)
+        .id()
+}
+
+/// Return the health bars of a removed enemy back to the [`HealthBarPool`].
+fn release_pooled_health_bar(
+    trigger: Trigger<OnRemove, PooledHealthBar>,
+    q_pooled: Query<&PooledHealthBar>,
+    mut q_anchors: Query<&mut HealthBarAnchor>,
+    mut q_visibilities: Query<&mut Visibility>,
+    mut pool: ResMut<HealthBarPool>,
+) -> Result {
+    let anchor_entity = q_pooled.get(trigger.target())?.0;
+    let mut anchor = q_anchors.get_mut(anchor_entity)?;
+    anchor.owner = None;
+
+    for bar in [anchor.camera_a, anchor.camera_b] {
+        if let Ok(mut visibility) = q_visibilities.get_mut(bar) {
+            *visibility = Visibility::Hidden;
+        }
+    }
 
-    commands.entity(entity).insert(HasHealthBar {
-        camera_a: health_bar_a,
-        camera_b: health_bar_b,
-    });
+    pool.free.push(anchor_entity);
 
     Ok(())
 }
 
+/// Move the pooled health bar anchors to their current owner.
+fn follow_health_bar_owner(
+    mut q_anchors: Query<(&HealthBarAnchor, &mut Transform)>,
+    q_global_transforms: Query<
+        &GlobalTransform,
+        Without<HealthBarAnchor>,
+    >,
+) {
+    for (anchor, mut transform) in q_anchors.iter_mut() {
+        let Some(owner_transform) = anchor
+            .owner
+            .and_then(|owner| q_global_transforms.get(owner).ok())
+        else {
+            continue;
+        };
+
+        transform.translation = owner_transform.translation();
+    }
+}
+
 fn update_health_bars(
     q_entities: Query<
         (&Health, &MaxHealth, &HasHealthBar),
@@ -112,46 +219,58 @@ fn update_health_bars(
     }
 }
 
+/// Only show the health bars that are near and within the
+/// viewport of each camera.
 fn update_health_bar_visibility(
     q_entities: Query<
         (&GlobalTransform, &HasHealthBar),
         With<Health>,
     >,
     mut q_health_bars: Query<&mut Visibility, With<WorldUi>>,
-    q_cameras: QueryCameras<&GlobalTransform>,
+    q_cameras: QueryCameras<(&GlobalTransform, &Camera)>,
 ) -> Result {
     const MAX_DISTANCE_SQ: f32 = 10.0 * 10.0;
 
-    let camera_a_position =
-        q_cameras.get(CameraType::A)?.translation();
-    let camera_b_position =
-        q_cameras.get(CameraType::B)?.translation();
+    let camera_a = q_cameras.get(CameraType::A)?;
+    let camera_b = q_cameras.get(CameraType::B)?;
+
+    let is_visible =
+        |(camera_transform, camera): (&GlobalTransform, &Camera),
+         position: Vec3| {
+            if camera_transform
+                .translation()
+                .distance_squared(position)
+                > MAX_DISTANCE_SQ
+            {
+                return false;
+            }
+
+            let rect =
+                camera.logical_viewport_rect().unwrap_or_default();
+            camera
+                .world_to_viewport(camera_transform, position)
+                .is_ok_and(|viewport| rect.contains(viewport))
+        };
 
     for (entity_transform, health_bars) in q_entities.iter() {
         let entity_position = entity_transform.translation();
 
-        if let Ok(mut visibility) =
-            q_health_bars.get_mut(health_bars.camera_a)
-        {
-            let distance_sq =
-                camera_a_position.distance_squared(entity_position);
-            *visibility = if distance_sq > MAX_DISTANCE_SQ {
-                Visibility::Hidden
-            } else {
-                Visibility::Inherited
+        for (bar, camera) in [
+            (health_bars.camera_a, camera_a),
+            (health_bars.camera_b, camera_b),
+        ] {
+            let Ok(mut visibility) = q_health_bars.get_mut(bar)
+            else {
+                continue;
             };
-        }
 
-        if let Ok(mut visibility) =
-            q_health_bars.get_mut(health_bars.camera_b)
-        {
-            let distance_sq =
-                camera_b_position.distance_squared(entity_position);
-            *visibility = if distance_sq > MAX_DISTANCE_SQ {
-                Visibility::Hidden
-            } else {
-                Visibility::Inherited
-            };
+            visibility.set_if_neq(
+                if is_visible(camera, entity_position) {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                },
+            );
         }
     }
 
@@ -163,3 +282,102 @@ pub struct HasHealthBar {
     pub camera_a: Entity,
     pub camera_b: Entity,
 }
+
+/// Reusable enemy health bars.
+#[derive(Resource, Default, Debug)]
+pub struct HealthBarPool {
+    /// Anchors that are not assigned to any enemy.
+    free: Vec<Entity>,
+    /// Number of anchors spawned in total.
+    count: usize,
+}
+
+impl HealthBarPool {
+    /// Max number of enemy health bars alive at once.
+    pub const CAPACITY: usize = 128;
+}
+
+/// World space anchor of a pair of pooled health bars,
+/// follows the [`owner`](Self::owner) around.
+#[derive(Component, Debug)]
+struct HealthBarAnchor {
+    owner: Option<Entity>,
+    camera_a: Entity,
+    camera_b: Entity,
+}
+
+/// The [`HealthBarAnchor`] borrowed by the enemy.
+#[derive(Component, Debug)]
+#[component(immutable)]
+struct PooledHealthBar(Entity);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn spawn_wave(app: &mut App, count: usize) -> Vec<Entity> {
+        let enemies = (0..count)
+            .map(|_| {
+                app.world_mut()
+                    .spawn((
+                        Enemy {
+                            movement_speed: 0.0,
+                            damage: 0.0,
+                            attack_cooldown: 1.0,
+                        },
+                        MaxHealth(10.0),
+                    ))
+                    .id()
+            })
+            .collect();
+        app.update();
+
+        enemies
+    }
+
+    fn anchors(app: &mut App) -> Vec<Entity> {
+        let mut anchors = app
+            .world_mut()
+            .query_filtered::<Entity, With<HealthBarAnchor>>()
+            .iter(app.world())
+            .collect::<Vec<_>>();
+        anchors.sort();
+
+        anchors
+    }
+
+    #[test]
+    fn test_health_bars_are_reused_across_waves() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<HealthBarPool>()
+            .add_observer(spawn_health_bar)
+            .add_observer(release_pooled_health_bar);
+
+        let wave = spawn_wave(&mut app, 5);
+        let first_anchors = anchors(&mut app);
+        assert_eq!(first_anchors.len(), 5);
+
+        for enemy in wave {
+            app.world_mut().despawn(enemy);
+        }
+        app.update();
+        assert_eq!(
+            app.world().resource::<HealthBarPool>().free.len(),
+            5
+        );
+
+        let wave = spawn_wave(&mut app, 5);
+        assert_eq!(anchors(&mut app), first_anchors);
+        assert!(wave.iter().all(|enemy| {
+            app.world().get::<PooledHealthBar>(*enemy).is_some()
+        }));
+
+        let bar_count = app
+            .world_mut()
+            .query::<&WorldUi>()
+            .iter(app.world())
+            .count();
+        assert_eq!(bar_count, 10, "No new bars should be spawned.");
+    }
+}
@@ -0,0 +1,106 @@
+use bevy::color::palettes::tailwind::*;
+use bevy::prelude::*;
+
+use crate::player::PlayerType;
+use crate::tower::tower_attack::Heat;
+use crate::ui::player_ui::PlayerUi;
+use crate::ui::world_space::WorldUi;
+
+pub(super) struct HeatGaugeUiPlugin;
+
+impl Plugin for HeatGaugeUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(spawn_heat_gauge)
+            .add_systems(Update, update_heat_gauges);
+    }
+}
+
+/// Show a heat gauge above towers with [`Heat`] for both players.
+fn spawn_heat_gauge(
+    trigger: Trigger<OnAdd, Heat>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+
+    let camera_a =
+        create_heat_gauge(&mut commands, entity, PlayerType::A);
+    let camera_b =
+        create_heat_gauge(&mut commands, entity, PlayerType::B);
+
+    commands
+        .entity(entity)
+        .insert(HasHeatGauge { camera_a, camera_b });
+}
+
+fn create_heat_gauge(
+    commands: &mut Commands,
+    target: Entity,
+    player_type: PlayerType,
+) -> Entity {
+    let fill_bar = commands
+        .spawn((
+            Node {
+                width: Val::Percent(0.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(AMBER_500.into()),
+            BorderRadius::all(Val::VMin(0.2)),
+        ))
+        .id();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::VMin(6.0),
+                height: Val::VMin(0.6),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.9)),
+            BorderRadius::all(Val::VMin(0.2)),
+            // Right above the health bar.
+            WorldUi::new(target).with_world_offset(Vec3::Y * 1.3),
+            PlayerUi(player_type),
+        ))
+        .add_child(fill_bar)
+        .id()
+}
+
+fn update_heat_gauges(
+    q_towers: Query<(&Heat, &HasHeatGauge), Changed<Heat>>,
+    q_children: Query<&Children>,
+    mut q_fill: Query<(&mut Node, &mut BackgroundColor)>,
+) {
+    for (heat, heat_gauges) in q_towers.iter() {
+        let width = Val::Percent(heat.fraction() * 100.0);
+        // Red while the tower is forced to cool down.
+        let color = match heat.overheated {
+            true => RED_500,
+            false => AMBER_500,
+        };
+
+        for gauge in [heat_gauges.camera_a, heat_gauges.camera_b] {
+            let Some(&fill_entity) = q_children
+                .get(gauge)
+                .ok()
+                .and_then(|children| children.first())
+            else {
+                continue;
+            };
+
+            if let Ok((mut node, mut background)) =
+                q_fill.get_mut(fill_entity)
+            {
+                node.width = width;
+                background.0 = color.into();
+            }
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct HasHeatGauge {
+    pub camera_a: Entity,
+    pub camera_b: Entity,
+}
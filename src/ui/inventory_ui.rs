@@ -1,14 +1,19 @@
+use bevy::color::palettes::css::WHITE;
 use bevy::color::palettes::tailwind::*;
 use bevy::ecs::spawn::SpawnWith;
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
-use crate::camera_controller::UI_RENDER_LAYER;
+use crate::difficulty::Difficulty;
 use crate::interaction::InteractionPlayer;
 use crate::player::PlayerType;
+use crate::player::economy::EconomyConfig;
+use crate::ui::player_ui::PlayerUi;
 
 use crate::inventory::Inventory;
 use crate::inventory::item::ItemRegistry;
+use crate::tower::tower_meta::{TowerMeta, TowerMetaRegistry};
+use crate::ui::locale::Localizer;
 
 pub struct InventoryUiPlugin;
 
@@ -44,6 +49,10 @@ fn spawn_inventory_ui(
         With<InteractionPlayer>,
     >,
     item_registry: ItemRegistry,
+    tower_registry: TowerMetaRegistry,
+    localizer: Localizer,
+    economy: Res<EconomyConfig>,
+    difficulty: Res<Difficulty>,
     inventory_ui: Res<InventoryUi>,
 ) -> Result {
     for (inventory, player_type) in q_players.iter() {
@@ -113,12 +122,12 @@ fn spawn_inventory_ui(
                 ))
             };
 
-        for (tower_id, count) in
-            inventory.towers().iter().filter(|(_, count)| **count > 0)
-        {
+        for tower_id in inventory.tower_order() {
+            let count = inventory.towers()[&tower_id];
+
             // Check if this tower is selected
             let is_selected =
-                inventory.selected_tower.as_ref() == Some(tower_id);
+                inventory.selected_tower.as_ref() == Some(&tower_id);
 
             //  Determine colors and border based on selection state
             let (bg_color, border_color) = if is_selected {
@@ -133,14 +142,30 @@ fn spawn_inventory_ui(
                     2.0,
                     bg_color.into(),
                     border_color.into(),
-                    tower_id,
-                    *count,
+                    &tower_id,
+                    count,
                 )?)
                 .id();
 
             commands.entity(tower_node).add_child(tower_item_node);
         }
 
+        // Stats of the selected tower, to compare before building.
+        if let Some(tower_id) = inventory.selected_tower.as_ref() {
+            let tower_meta = tower_registry.get_tower(tower_id);
+            // Same cost as the BuildCost stored on placement.
+            let cost = tower_meta
+                .map(|meta| {
+                    economy.tower_cost(meta.cost, *difficulty)
+                })
+                .unwrap_or_default();
+
+            commands.spawn((
+                tower_tooltip(tower_meta, cost, &localizer),
+                ChildOf(tower_node),
+            ));
+        }
+
         for (ingredient_id, count) in inventory
             .ingredients()
             .iter()
@@ -166,14 +191,72 @@ fn spawn_inventory_ui(
     Ok(())
 }
 
-/// Create split screen ui.
+/// Tooltip listing the stats and the `cost` of a tower item,
+/// towers without a [`TowerMeta`] only get a placeholder.
+///
+/// Translated right away as the inventory ui is rebuilt every frame.
+fn tower_tooltip(
+    tower_meta: Option<&TowerMeta>,
+    cost: u32,
+    localizer: &Localizer,
+) -> impl Bundle {
+    let font = TextFont::from_font_size(14.0);
+
+    let lines = match tower_meta {
+        Some(tower_meta) => tower_meta
+            .tooltip_stats()
+            .into_iter()
+            .map(|(key, value)| {
+                (format!("{}: {value}", localizer.t(key)), WHITE)
+            })
+            .chain([
+                (
+                    format!(
+                        "{}: {cost}",
+                        localizer.t("tooltip.cost")
+                    ),
+                    WHITE,
+                ),
+                (localizer.t(&tower_meta.description), AMBER_200),
+            ])
+            .collect::<Vec<_>>(),
+        None => vec![(localizer.t("tooltip.no_stats"), WHITE)],
+    };
+
+    (
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_self: AlignSelf::End,
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(SLATE_800.with_alpha(0.5).into()),
+        BorderRadius::all(Val::Px(8.0)),
+        Children::spawn(SpawnWith(
+            move |parent: &mut ChildSpawner| {
+                for (line, color) in lines {
+                    parent.spawn((
+                        Text::new(line),
+                        font.clone(),
+                        TextColor(color.into()),
+                    ));
+                }
+            },
+        )),
+    )
+}
+
+/// Create split screen ui, one root per player viewport.
 fn split_screen_ui(mut commands: Commands) {
     let split_bundle =
-        |tower_node: Entity, ingreient_node: Entity| {
+        |player_type: PlayerType,
+         tower_node: Entity,
+         ingreient_node: Entity| {
             (
+                PlayerUi(player_type),
                 Node {
-                    // Takes half the space.
-                    width: Val::Percent(50.0),
+                    // Takes the whole player viewport.
+                    width: Val::Percent(100.0),
                     height: Val::Percent(100.0),
                     // Push the child node towards the bottom.
                     flex_direction: FlexDirection::Column,
@@ -223,21 +306,15 @@ fn split_screen_ui(mut commands: Commands) {
     let b_towers = commands.spawn(items_bundle.clone()).id();
     let b_ingredients = commands.spawn(items_bundle).id();
 
-    commands.spawn((
-        UI_RENDER_LAYER,
-        // Root node.
-        Node {
-            width: Val::Percent(100.0),
-            height: Val::Percent(100.0),
-            flex_direction: FlexDirection::Row,
-            ..default()
-        },
-        FocusPolicy::Pass,
-        Pickable::IGNORE,
-        Children::spawn((
-            Spawn(split_bundle(a_towers, a_ingredients)),
-            Spawn(split_bundle(b_towers, b_ingredients)),
-        )),
+    commands.spawn(split_bundle(
+        PlayerType::A,
+        a_towers,
+        a_ingredients,
+    ));
+    commands.spawn(split_bundle(
+        PlayerType::B,
+        b_towers,
+        b_ingredients,
     ));
 
     commands.insert_resource(InventoryUi {
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+
+use crate::inventory::item::{ItemRegistry, ItemType};
+use crate::inventory::loadout::{
+    Loadout, LoadoutsAsset, LoadoutsAssetHandle, SelectedLoadout,
+};
+use crate::ui::locale::LocalizedText;
+use crate::ui::widgets::button::{
+    ButtonActivated, ButtonBackground, LabelButton,
+};
+
+pub(super) struct LoadoutUiPlugin;
+
+impl Plugin for LoadoutUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_loadout_label);
+    }
+}
+
+/// Spawn the loadout row of the main menu:
+/// create, select (cycle) and delete.
+pub(super) fn spawn_loadout_menu(
+    parent: &mut ChildSpawner,
+    color: Srgba,
+    font_color: Srgba,
+    font_size: f32,
+) {
+    let button = |label: &str| {
+        let mut button = LabelButton::new(label)
+            .with_background(ButtonBackground::new(color))
+            .with_text_color(font_color)
+            .with_font_size(font_size);
+        button.node.padding =
+            UiRect::axes(Val::Px(20.0), Val::Px(8.0));
+        button.node.margin = UiRect::all(Val::Px(4.0));
+        button.build()
+    };
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|parent| {
+            parent
+                .spawn(button("menu.loadout.new"))
+                .observe(new_loadout_on_click);
+            parent
+                .spawn((LoadoutSelector, button("menu.loadout.none")))
+                .observe(cycle_loadout_on_click);
+            parent
+                .spawn(button("menu.loadout.delete"))
+                .observe(delete_loadout_on_click);
+        });
+}
+
+/// Show the name of the [`SelectedLoadout`] on the selector.
+fn update_loadout_label(
+    q_selectors: Query<Entity, With<LoadoutSelector>>,
+    q_children: Query<&Children>,
+    mut q_texts: Query<&mut LocalizedText>,
+    selected_loadout: Res<SelectedLoadout>,
+) {
+    let label = selected_loadout
+        .0
+        .clone()
+        .unwrap_or_else(|| "menu.loadout.none".to_string());
+
+    for entity in q_selectors.iter() {
+        for child in q_children.iter_descendants(entity) {
+            let Ok(mut text) = q_texts.get_mut(child) else {
+                continue;
+            };
+
+            // Names are not localization keys and
+            // are shown as is.
+            if text.0 != label {
+                text.0 = label.clone();
+            }
+        }
+    }
+}
+
+/// Select the next loadout, going back to none after the last one.
+fn cycle_loadout_on_click(
+    _: Trigger<ButtonActivated>,
+    loadout_assets: Res<Assets<LoadoutsAsset>>,
+    loadouts_handle: Res<LoadoutsAssetHandle>,
+    mut selected_loadout: ResMut<SelectedLoadout>,
+) {
+    let Some(loadouts) = loadout_assets.get(&loadouts_handle.0)
+    else {
+        return;
+    };
+
+    let mut names = loadouts.keys();
+    let next = match &selected_loadout.0 {
+        Some(current) => {
+            names.skip_while(|name| *name != current).nth(1).cloned()
+        }
+        None => names.next().cloned(),
+    };
+
+    selected_loadout.0 = next;
+}
+
+/// Create a copy of the selected loadout, or a loadout
+/// of every tower if none is selected, and select it.
+fn new_loadout_on_click(
+    _: Trigger<ButtonActivated>,
+    mut loadout_assets: ResMut<Assets<LoadoutsAsset>>,
+    loadouts_handle: Res<LoadoutsAssetHandle>,
+    mut selected_loadout: ResMut<SelectedLoadout>,
+    item_registry: ItemRegistry,
+) -> Result {
+    let Some(items) = item_registry.get() else {
+        return Ok(());
+    };
+    let Some(loadouts) = loadout_assets.get_mut(&loadouts_handle.0)
+    else {
+        return Ok(());
+    };
+
+    let loadout = match selected_loadout
+        .0
+        .as_ref()
+        .and_then(|name| loadouts.get(name))
+    {
+        Some(loadout) => Loadout {
+            towers: loadout.valid_towers(items),
+        },
+        None => {
+            let mut towers = items
+                .iter()
+                .filter(|(_, item_meta)| {
+                    item_meta.item_type == ItemType::Tower
+                })
+                .map(|(id, _)| id.clone())
+                .collect::<Vec<_>>();
+            towers.sort();
+
+            Loadout { towers }
+        }
+    };
+
+    selected_loadout.0 = Some(loadouts.insert_new(loadout));
+    loadouts.save()
+}
+
+fn delete_loadout_on_click(
+    _: Trigger<ButtonActivated>,
+    mut loadout_assets: ResMut<Assets<LoadoutsAsset>>,
+    loadouts_handle: Res<LoadoutsAssetHandle>,
+    mut selected_loadout: ResMut<SelectedLoadout>,
+) -> Result {
+    let Some(name) = selected_loadout.0.take() else {
+        return Ok(());
+    };
+    let Some(loadouts) = loadout_assets.get_mut(&loadouts_handle.0)
+    else {
+        return Ok(());
+    };
+
+    loadouts.remove(&name);
+    loadouts.save()
+}
+
+/// The button that shows and cycles the [`SelectedLoadout`].
+#[derive(Component)]
+struct LoadoutSelector;
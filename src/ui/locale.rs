@@ -0,0 +1,227 @@
+use bevy::asset::{AssetLoader, io::Reader};
+use bevy::asset::{AsyncReadExt, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::settings::Settings;
+
+/// Plugin to handle localized string tables and live text updates.
+pub(super) struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<LocaleAsset>()
+            .init_asset_loader::<LocaleAssetLoader>()
+            .init_resource::<Locale>();
+
+        app.register_type::<Locale>();
+
+        app.add_systems(PreStartup, load_locales).add_systems(
+            Update,
+            (
+                apply_language_setting
+                    .run_if(resource_changed::<Settings>),
+                update_localized_texts,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Startup system: load every "<code>.locale.ron" string table.
+fn load_locales(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    let handles = Language::ALL
+        .iter()
+        .map(|language| {
+            (
+                *language,
+                asset_server.load(format!(
+                    "locales/{}.locale.ron",
+                    language.code()
+                )),
+            )
+        })
+        .collect();
+
+    commands.insert_resource(LocaleAssetHandles(handles));
+}
+
+/// Switch the [`Locale`] to the language picked in the [`Settings`].
+fn apply_language_setting(
+    settings: Res<Settings>,
+    mut locale: ResMut<Locale>,
+) {
+    if locale.language != settings.language {
+        locale.language = settings.language;
+    }
+}
+
+/// Rewrite [`LocalizedText`]s when the [`Locale`] changes
+/// or when the string tables finish (re)loading.
+fn update_localized_texts(
+    mut q_texts: Query<(Ref<LocalizedText>, &mut Text)>,
+    localizer: Localizer,
+) {
+    let refresh_all = localizer.locale.is_changed()
+        || localizer.assets.is_changed();
+
+    for (localized_text, mut text) in q_texts.iter_mut() {
+        if refresh_all == false
+            && localized_text.is_changed() == false
+        {
+            continue;
+        }
+
+        let translated = localizer.t(&localized_text.0);
+        if text.0 != translated {
+            text.0 = translated;
+        }
+    }
+}
+
+/// Supported languages.
+#[derive(
+    Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash,
+)]
+pub enum Language {
+    #[default]
+    English,
+    Malay,
+}
+
+impl Language {
+    pub const ALL: &[Language] =
+        &[Language::English, Language::Malay];
+
+    /// File name prefix of the language's string table.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Malay => "ms",
+        }
+    }
+}
+
+/// The currently selected language, follows
+/// [`Settings::language`].
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct Locale {
+    pub language: Language,
+}
+
+#[derive(Resource)]
+pub struct LocaleAssetHandles(HashMap<Language, Handle<LocaleAsset>>);
+
+/// Tags a [`Text`] entity whose content is the translation
+/// of the stored key. The text is updated live on [`Locale`] changes.
+#[derive(Component, Deref, Debug, Clone)]
+#[require(Text)]
+pub struct LocalizedText(pub String);
+
+impl LocalizedText {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+}
+
+#[derive(SystemParam)]
+pub struct Localizer<'w> {
+    pub locale: Res<'w, Locale>,
+    pub handles: Res<'w, LocaleAssetHandles>,
+    pub assets: Res<'w, Assets<LocaleAsset>>,
+}
+
+impl Localizer<'_> {
+    /// Get the string table of a specific language.
+    pub fn get(&self, language: Language) -> Option<&LocaleAsset> {
+        self.assets.get(self.handles.0.get(&language)?)
+    }
+
+    /// Translate `key` into the current language.
+    ///
+    /// Falls back to English and then to the key itself
+    /// if no translation can be found.
+    pub fn t(&self, key: &str) -> String {
+        [self.locale.language, Language::English]
+            .iter()
+            .find_map(|language| self.get(*language)?.get(key))
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+}
+
+/// Map of string key to the translated string.
+#[derive(Asset, TypePath, Deref, Debug, Clone, Deserialize)]
+pub struct LocaleAsset(HashMap<String, String>);
+
+#[derive(Default)]
+pub struct LocaleAssetLoader;
+
+impl AssetLoader for LocaleAssetLoader {
+    type Asset = LocaleAsset;
+
+    type Settings = ();
+
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut ron_str = String::new();
+        reader.read_to_string(&mut ron_str).await?;
+
+        ron::from_str::<LocaleAsset>(&ron_str).map_err(|err| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Failed to parse {}: {err}",
+                    load_context.path().display()
+                ),
+            )
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["locale.ron"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_locale_follows_language_setting() {
+        let mut app = App::new();
+        app.init_resource::<Settings>()
+            .init_resource::<Locale>()
+            .add_systems(
+                Update,
+                apply_language_setting
+                    .run_if(resource_changed::<Settings>),
+            );
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<Locale>().language,
+            Language::English
+        );
+
+        app.world_mut().resource_mut::<Settings>().language =
+            Language::Malay;
+        app.update();
+        assert_eq!(
+            app.world().resource::<Locale>().language,
+            Language::Malay
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use bevy::color::palettes::tailwind::*;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::WindowResized;
+
+use crate::camera_controller::split_screen::split_viewports;
+use crate::character_controller::CharacterController;
+use crate::inventory::Inventory;
+use crate::player::currency::Currency;
+use crate::player::{PlayerType, QueryPlayers};
+use crate::tower::tower_attack::{Health, MaxHealth};
+use crate::ui::Screen;
+use crate::ui::player_ui::PlayerUi;
+
+pub(super) struct PlayerHudUiPlugin;
+
+impl Plugin for PlayerHudUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(Screen::EnterLevel),
+            spawn_player_huds,
+        )
+        .add_systems(
+            Update,
+            (
+                anchor_player_huds,
+                (update_hud_health, update_hud_labels)
+                    .run_if(in_state(Screen::EnterLevel)),
+            ),
+        );
+    }
+}
+
+fn spawn_player_huds(
+    mut commands: Commands,
+    windows: Query<&Window>,
+) {
+    let window = windows.single().ok();
+
+    for player_type in [PlayerType::A, PlayerType::B] {
+        let hud = PlayerHud(player_type);
+        let color = player_type.color();
+
+        let mut node = Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(PlayerHud::WIDTH),
+            height: Val::Px(PlayerHud::HEIGHT),
+            padding: UiRect::all(Val::Px(8.0)),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::SpaceBetween,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        };
+        // Anchor right away instead of waiting for a resize.
+        if let Some(window) = window {
+            hud.anchor(&mut node, window);
+        }
+
+        commands.spawn((
+            hud,
+            PlayerUi(player_type),
+            StateScoped(Screen::EnterLevel),
+            node,
+            BackgroundColor(ZINC_900.with_alpha(0.5).into()),
+            BorderColor(color.with_alpha(0.8).into()),
+            BorderRadius::all(Val::Px(8.0)),
+            Pickable::IGNORE,
+            FocusPolicy::Pass,
+            Children::spawn((
+                // Health bar.
+                Spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(10.0),
+                        ..default()
+                    },
+                    BackgroundColor(ZINC_700.into()),
+                    BorderRadius::all(Val::Px(4.0)),
+                    Pickable::IGNORE,
+                    Children::spawn(Spawn((
+                        HudHealthFill(player_type),
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(color.into()),
+                        BorderRadius::all(Val::Px(4.0)),
+                        Pickable::IGNORE,
+                    ))),
+                )),
+                Spawn((
+                    HudLabel(player_type),
+                    Text::default(),
+                    TextFont::from_font_size(14.0),
+                    TextColor(color.into()),
+                    Pickable::IGNORE,
+                )),
+            )),
+        ));
+    }
+}
+
+/// Keep the huds at the bottom center of their player's viewport half,
+/// following the same split as the cameras.
+fn anchor_player_huds(
+    mut resize_events: EventReader<WindowResized>,
+    windows: Query<&Window>,
+    mut q_huds: Query<(&PlayerHud, &mut Node)>,
+) {
+    let Some(resize_event) = resize_events.read().last() else {
+        return;
+    };
+    let Ok(window) = windows.get(resize_event.window) else {
+        return;
+    };
+
+    for (hud, mut node) in q_huds.iter_mut() {
+        hud.anchor(&mut node, window);
+    }
+}
+
+fn update_hud_health(
+    mut q_fills: Query<(&HudHealthFill, &mut Node)>,
+    q_players: QueryPlayers<
+        (&Health, &MaxHealth),
+        With<CharacterController>,
+    >,
+) {
+    for (fill, mut node) in q_fills.iter_mut() {
+        let Ok((health, max_health)) = q_players.get(**fill) else {
+            continue;
+        };
+
+        let percent = (health.0 / max_health.0).clamp(0.0, 1.0);
+        node.width = Val::Percent(percent * 100.0);
+    }
+}
+
+fn update_hud_labels(
+    mut q_labels: Query<(&HudLabel, &mut Text)>,
+    q_players: QueryPlayers<&Inventory, With<CharacterController>>,
+    currency: Res<Currency>,
+) {
+    for (label, mut text) in q_labels.iter_mut() {
+        let selected_tower = q_players
+            .get(**label)
+            .ok()
+            .and_then(|inventory| inventory.selected_tower.clone())
+            .unwrap_or_else(|| "-".to_string());
+
+        let content = format!("{} | {selected_tower}", currency.0);
+        if text.0 != content {
+            text.0 = content;
+        }
+    }
+}
+
+/// Root node of a player's hud.
+#[derive(Component, Deref, Debug, Clone, Copy)]
+pub struct PlayerHud(pub PlayerType);
+
+impl PlayerHud {
+    pub const WIDTH: f32 = 240.0;
+    pub const HEIGHT: f32 = 56.0;
+    /// Space left below the hud for the inventory.
+    pub const BOTTOM_OFFSET: f32 = 160.0;
+
+    /// Place the hud at the bottom center of the player's viewport.
+    pub fn anchor(&self, node: &mut Node, window: &Window) {
+        let [viewport_a, viewport_b] =
+            split_viewports(window.physical_size());
+        let viewport = match self.0 {
+            PlayerType::A => viewport_a,
+            PlayerType::B => viewport_b,
+        };
+        let viewport_size =
+            viewport.physical_size.as_vec2() / window.scale_factor();
+
+        node.left = Val::Px((viewport_size.x - Self::WIDTH) * 0.5);
+        node.top = Val::Px(
+            viewport_size.y - Self::HEIGHT - Self::BOTTOM_OFFSET,
+        );
+    }
+}
+
+/// Fill of the health bar, scaled with the player's [`Health`].
+#[derive(Component, Deref, Debug, Clone, Copy)]
+struct HudHealthFill(PlayerType);
+
+/// Shows the currency and the selected tower.
+#[derive(Component, Deref, Debug, Clone, Copy)]
+struct HudLabel(PlayerType);
+
+#[cfg(test)]
+mod test {
+    use bevy::window::WindowResolution;
+
+    use super::*;
+
+    #[test]
+    fn test_resize_updates_hud_anchors() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<WindowResized>()
+            .add_systems(Update, anchor_player_huds);
+
+        let window = app
+            .world_mut()
+            .spawn(Window {
+                resolution: WindowResolution::new(1280.0, 720.0),
+                ..default()
+            })
+            .id();
+
+        let huds =
+            [PlayerType::A, PlayerType::B].map(|player_type| {
+                app.world_mut()
+                    .spawn((PlayerHud(player_type), Node::default()))
+                    .id()
+            });
+
+        let resize = |app: &mut App, width: f32, height: f32| {
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .resolution
+                .set(width, height);
+            app.world_mut().send_event(WindowResized {
+                window,
+                width,
+                height,
+            });
+            app.update();
+        };
+
+        let anchors = |app: &App| {
+            huds.map(|hud| {
+                let node = app.world().get::<Node>(hud).unwrap();
+                (node.left, node.top)
+            })
+        };
+
+        resize(&mut app, 1280.0, 720.0);
+        let expected = (
+            Val::Px((640.0 - PlayerHud::WIDTH) * 0.5),
+            Val::Px(
+                720.0 - PlayerHud::HEIGHT - PlayerHud::BOTTOM_OFFSET,
+            ),
+        );
+        assert_eq!(anchors(&app), [expected; 2]);
+
+        resize(&mut app, 1920.0, 1080.0);
+        let expected = (
+            Val::Px((960.0 - PlayerHud::WIDTH) * 0.5),
+            Val::Px(
+                1080.0 - PlayerHud::HEIGHT - PlayerHud::BOTTOM_OFFSET,
+            ),
+        );
+        assert_eq!(anchors(&app), [expected; 2]);
+    }
+}
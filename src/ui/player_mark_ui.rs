@@ -3,7 +3,10 @@ use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
 use crate::camera_controller::UI_RENDER_LAYER;
-use crate::player::player_mark::{PlayerMark, init_player_mark};
+use crate::player::player_mark::{
+    BaseShield, PlayerMark, init_base_shield, init_player_mark,
+};
+use crate::ui::locale::{Locale, Localizer};
 
 use super::Screen;
 
@@ -13,14 +16,21 @@ impl Plugin for PlayerMarkUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             OnEnter(Screen::EnterLevel),
-            spawn_player_mark_ui.after(init_player_mark),
+            spawn_player_mark_ui
+                .after(init_player_mark)
+                .after(init_base_shield),
         )
         .add_systems(
             Update,
-            update_player_mark_ui.run_if(
-                in_state(Screen::EnterLevel)
-                    .and(resource_changed::<PlayerMark>),
-            ),
+            (
+                update_player_mark_ui
+                    .run_if(resource_changed::<PlayerMark>),
+                update_base_shield_ui.run_if(
+                    resource_changed::<BaseShield>
+                        .or(resource_changed::<Locale>),
+                ),
+            )
+                .run_if(in_state(Screen::EnterLevel)),
         );
     }
 }
@@ -83,6 +93,15 @@ fn spawn_player_mark_ui(
                     Text::new(player_mark.to_string()),
                     PlayerMarkUiText,
                 )),
+                Spawn((
+                    Node {
+                        margin: UiRect::left(Val::Px(20.0)),
+                        ..default()
+                    },
+                    Text::default(),
+                    TextColor(SKY_400.into()),
+                    BaseShieldUiText,
+                )),
             )),
         ))),
     ));
@@ -97,5 +116,33 @@ fn update_player_mark_ui(
     Ok(())
 }
 
+/// Show the remaining [`BaseShield`] charges,
+/// hidden on difficulties without any.
+fn update_base_shield_ui(
+    base_shield: Res<BaseShield>,
+    localizer: Localizer,
+    mut q_text: Query<(&mut Text, &mut Node), With<BaseShieldUiText>>,
+) -> Result {
+    let (mut text, mut node) = q_text.single_mut()?;
+
+    if base_shield.max == 0 {
+        node.display = Display::None;
+        return Ok(());
+    }
+
+    node.display = Display::DEFAULT;
+    **text = format!(
+        "{} {}/{}",
+        localizer.t("base.shield"),
+        base_shield.charges,
+        base_shield.max
+    );
+
+    Ok(())
+}
+
 #[derive(Component)]
 pub struct PlayerMarkUiText;
+
+#[derive(Component)]
+pub struct BaseShieldUiText;
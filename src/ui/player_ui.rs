@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+use crate::camera_controller::split_screen::{
+    CameraType, QueryCameras,
+};
+use crate::camera_controller::{
+    UI_A_RENDER_LAYER, UI_B_RENDER_LAYER,
+};
+use crate::player::PlayerType;
+
+pub(super) struct PlayerUiPlugin;
+
+impl Plugin for PlayerUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(setup_player_ui);
+    }
+}
+
+/// Target the [`PlayerUi`] node to the player's camera so that it
+/// gets laid out and rendered within that player's viewport half only.
+fn setup_player_ui(
+    trigger: Trigger<OnAdd, PlayerUi>,
+    mut commands: Commands,
+    q_player_uis: Query<&PlayerUi>,
+    q_cameras: QueryCameras<Entity>,
+) -> Result {
+    let entity = trigger.target();
+
+    let player_ui = q_player_uis.get(entity)?;
+
+    let (camera_type, render_layer) = match player_ui.0 {
+        PlayerType::A => (CameraType::A, UI_A_RENDER_LAYER),
+        PlayerType::B => (CameraType::B, UI_B_RENDER_LAYER),
+    };
+
+    commands.entity(entity).insert((
+        UiTargetCamera(q_cameras.get(camera_type)?),
+        render_layer,
+    ));
+
+    Ok(())
+}
+
+/// Ui that belongs to a specific player, the node will be rendered
+/// on that player's viewport half instead of the
+/// [`CameraFull`][crate::camera_controller::split_screen::CameraFull].
+///
+/// Only needs to be added to the root ui node.
+#[derive(Component, Deref, Debug, Clone, Copy)]
+#[component(immutable)]
+pub struct PlayerUi(pub PlayerType);
@@ -0,0 +1,182 @@
+use bevy::color::palettes::tailwind::*;
+use bevy::ecs::spawn::SpawnWith;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::inventory::item::ItemRegistry;
+use crate::player::PlayerType;
+use crate::tower::build_tool::RadialMenu;
+use crate::ui::player_ui::PlayerUi;
+
+pub(super) struct RadialMenuUiPlugin;
+
+impl Plugin for RadialMenuUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_radial_menu_ui, highlight_radial_slot).chain(),
+        )
+        .add_observer(despawn_radial_menu_ui);
+    }
+}
+
+/// Lay the tower icons of a newly opened [`RadialMenu`] in a
+/// ring at the center of the player's viewport half.
+fn spawn_radial_menu_ui(
+    mut commands: Commands,
+    q_menus: Query<
+        (&RadialMenu, &PlayerType, Entity),
+        Added<RadialMenu>,
+    >,
+    item_registry: ItemRegistry,
+) {
+    /// Distance from the center to each slot.
+    const RADIUS: f32 = 120.0;
+
+    for (menu, player_type, entity) in q_menus.iter() {
+        let slot_angle =
+            core::f32::consts::TAU / menu.slots.len().max(1) as f32;
+        let slots = menu
+            .slots
+            .iter()
+            .enumerate()
+            .map(|(index, tower_id)| {
+                // Clockwise from the top, see
+                // [`radial_slot`][crate::tower::build_tool::radial_slot].
+                let (sin, cos) =
+                    (index as f32 * slot_angle).sin_cos();
+                let offset = Vec2::new(sin, -cos) * RADIUS;
+                let icon = item_registry
+                    .get_item(tower_id)
+                    .map(|item| item.icon.clone());
+
+                (index, offset, icon)
+            })
+            .collect::<Vec<_>>();
+
+        commands.spawn((
+            PlayerUi(*player_type),
+            RadialMenuUi(entity),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+            FocusPolicy::Pass,
+            // Zero sized anchor at the center of the viewport.
+            children![(
+                Node {
+                    width: Val::ZERO,
+                    height: Val::ZERO,
+                    ..default()
+                },
+                Pickable::IGNORE,
+                Children::spawn(SpawnWith(
+                    move |parent: &mut ChildSpawner| {
+                        for (index, offset, icon) in slots {
+                            parent
+                                .spawn(radial_slot_node(
+                                    index, offset,
+                                ))
+                                .with_children(|slot| {
+                                    if let Some(icon) = icon {
+                                        slot.spawn((
+                                            Node {
+                                                width: Val::Percent(
+                                                    100.0,
+                                                ),
+                                                height: Val::Percent(
+                                                    100.0,
+                                                ),
+                                                ..default()
+                                            },
+                                            ImageNode::new(icon),
+                                        ));
+                                    }
+                                });
+                        }
+                    },
+                )),
+            )],
+        ));
+    }
+}
+
+fn radial_slot_node(index: usize, offset: Vec2) -> impl Bundle {
+    const SLOT_SIZE: f32 = 72.0;
+
+    (
+        RadialSlot(index),
+        Node {
+            position_type: PositionType::Absolute,
+            // Centered on the ring.
+            left: Val::Px(offset.x - SLOT_SIZE * 0.5),
+            top: Val::Px(offset.y - SLOT_SIZE * 0.5),
+            width: Val::Px(SLOT_SIZE),
+            height: Val::Px(SLOT_SIZE),
+            padding: UiRect::all(Val::Px(6.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(SLATE_800.with_alpha(0.6).into()),
+        BorderColor(SLATE_200.into()),
+        BorderRadius::MAX,
+        Pickable::IGNORE,
+    )
+}
+
+/// Highlight the hovered slot of every [`RadialMenu`].
+fn highlight_radial_slot(
+    q_menus: Query<&RadialMenu, Changed<RadialMenu>>,
+    q_menu_uis: Query<(&RadialMenuUi, Entity)>,
+    q_children: Query<&Children>,
+    mut q_slots: Query<(
+        &RadialSlot,
+        &mut BackgroundColor,
+        &mut BorderColor,
+    )>,
+) {
+    for (menu_ui, entity) in q_menu_uis.iter() {
+        let Ok(menu) = q_menus.get(menu_ui.0) else {
+            continue;
+        };
+
+        for child in q_children.iter_descendants(entity) {
+            let Ok((slot, mut background, mut border)) =
+                q_slots.get_mut(child)
+            else {
+                continue;
+            };
+
+            let (bg_color, border_color) =
+                match menu.hovered == Some(slot.0) {
+                    true => (EMERALD_800, EMERALD_500),
+                    false => (SLATE_800, SLATE_200),
+                };
+            background.0 = bg_color.with_alpha(0.6).into();
+            border.0 = border_color.into();
+        }
+    }
+}
+
+fn despawn_radial_menu_ui(
+    trigger: Trigger<OnRemove, RadialMenu>,
+    mut commands: Commands,
+    q_menu_uis: Query<(&RadialMenuUi, Entity)>,
+) {
+    for (menu_ui, entity) in q_menu_uis.iter() {
+        if menu_ui.0 == trigger.target() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Root of the ui of a player's [`RadialMenu`].
+#[derive(Component, Debug)]
+pub struct RadialMenuUi(pub Entity);
+
+#[derive(Component, Debug)]
+pub struct RadialSlot(pub usize);
@@ -0,0 +1,285 @@
+use avian3d::prelude::*;
+use bevy::color::palettes::tailwind::*;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::camera_controller::split_screen::{
+    CameraType, QueryCameras,
+};
+use crate::character_controller::CharacterController;
+use crate::enemy::IsEnemy;
+use crate::physics::GameLayer;
+use crate::player::{PlayerType, QueryPlayers};
+use crate::settings::{ReticleStyle, Settings};
+use crate::tower::InPlacementMode;
+use crate::ui::Screen;
+use crate::ui::player_ui::PlayerUi;
+
+pub(super) struct ReticleUiPlugin;
+
+impl Plugin for ReticleUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(Screen::EnterLevel), spawn_reticles)
+            .add_systems(
+                PostUpdate,
+                update_reticles
+                    .after(TransformSystem::TransformPropagate)
+                    .run_if(in_state(Screen::EnterLevel)),
+            );
+    }
+}
+
+fn spawn_reticles(mut commands: Commands) {
+    for player_type in [PlayerType::A, PlayerType::B] {
+        commands.spawn((
+            PlayerUi(player_type),
+            StateScoped(Screen::EnterLevel),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            Pickable::IGNORE,
+            FocusPolicy::Pass,
+            Children::spawn(Spawn((
+                Reticle(player_type),
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                Pickable::IGNORE,
+                FocusPolicy::Pass,
+                Children::spawn((
+                    // Horizontal and vertical strokes of the cross.
+                    Spawn((CrossStroke, Node::default())),
+                    Spawn((CrossStroke, Node::default())),
+                )),
+            ))),
+        ));
+    }
+}
+
+/// Place each player's [`Reticle`] at their aim point and style it
+/// based on what is being aimed at.
+fn update_reticles(
+    mut q_reticles: Query<(
+        &Reticle,
+        &mut Node,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        &mut BorderRadius,
+        &Children,
+    )>,
+    mut q_strokes: Query<
+        (&mut Node, &mut BackgroundColor),
+        (With<CrossStroke>, Without<Reticle>),
+    >,
+    q_cameras: QueryCameras<(&GlobalTransform, &Camera)>,
+    q_players: QueryPlayers<
+        Has<InPlacementMode>,
+        With<CharacterController>,
+    >,
+    q_enemies: Query<&GlobalTransform, With<IsEnemy>>,
+    q_collider_ofs: Query<&ColliderOf>,
+    spatial_query: SpatialQuery,
+    settings: Res<Settings>,
+) {
+    for (
+        reticle,
+        mut node,
+        mut background,
+        mut border,
+        mut radius,
+        children,
+    ) in q_reticles.iter_mut()
+    {
+        let camera_type = match **reticle {
+            PlayerType::A => CameraType::A,
+            PlayerType::B => CameraType::B,
+        };
+        let Ok((camera_transform, camera)) =
+            q_cameras.get(camera_type)
+        else {
+            continue;
+        };
+
+        let aim = aim_point(
+            camera_transform,
+            &q_enemies,
+            &q_collider_ofs,
+            &spatial_query,
+        );
+
+        let rect = camera.logical_viewport_rect().unwrap_or_default();
+        let Ok(viewport) =
+            camera.world_to_viewport(camera_transform, aim.position)
+        else {
+            node.display = Display::None;
+            continue;
+        };
+        let viewport = viewport - rect.min;
+
+        let in_placement =
+            q_players.get(**reticle).is_ok_and(|placing| placing);
+
+        let color = if aim.on_enemy {
+            // Subtle highlight on valid targets.
+            reticle.color().mix(&RED_400, 0.6)
+        } else {
+            reticle.color()
+        };
+
+        let style = if in_placement {
+            ReticleStyle::Ring
+        } else {
+            settings.reticle_style
+        };
+
+        let mut size = match style {
+            ReticleStyle::Dot => 6.0,
+            ReticleStyle::Ring => 18.0,
+            ReticleStyle::Cross => 16.0,
+        };
+        if in_placement {
+            size = 28.0;
+        } else if aim.on_enemy {
+            size *= 1.3;
+        }
+
+        node.display = Display::DEFAULT;
+        node.width = Val::Px(size);
+        node.height = Val::Px(size);
+        node.justify_content = JustifyContent::Center;
+        node.align_items = AlignItems::Center;
+
+        // Center the reticle on the aim point.
+        let half_size = size * 0.5;
+        node.left = Val::Px(viewport.x - half_size);
+        node.top = Val::Px(viewport.y - half_size);
+
+        match style {
+            ReticleStyle::Dot => {
+                node.border = UiRect::ZERO;
+                background.0 = color.into();
+            }
+            ReticleStyle::Ring => {
+                node.border = UiRect::all(Val::Px(2.0));
+                background.0 = Color::NONE;
+            }
+            ReticleStyle::Cross => {
+                node.border = UiRect::ZERO;
+                background.0 = Color::NONE;
+            }
+        }
+        *border = BorderColor(color.into());
+        *radius = if in_placement {
+            // Square-ish while placing towers.
+            BorderRadius::all(Val::Px(4.0))
+        } else {
+            BorderRadius::MAX
+        };
+
+        let show_cross = style == ReticleStyle::Cross;
+        for (i, child) in children.iter().enumerate() {
+            let Ok((mut stroke_node, mut stroke_color)) =
+                q_strokes.get_mut(child)
+            else {
+                continue;
+            };
+
+            stroke_node.display = if show_cross {
+                Display::DEFAULT
+            } else {
+                Display::None
+            };
+            stroke_node.position_type = PositionType::Absolute;
+            (stroke_node.width, stroke_node.height) = if i == 0 {
+                (Val::Percent(100.0), Val::Px(2.0))
+            } else {
+                (Val::Px(2.0), Val::Percent(100.0))
+            };
+            stroke_color.0 = color.into();
+        }
+    }
+}
+
+/// Find the world position that the camera is aiming at.
+///
+/// Snaps to enemies near the aim ray, otherwise uses the first
+/// environment hit or falls back to [`Reticle::DEFAULT_DISTANCE`].
+fn aim_point(
+    camera_transform: &GlobalTransform,
+    q_enemies: &Query<&GlobalTransform, With<IsEnemy>>,
+    q_collider_ofs: &Query<&ColliderOf>,
+    spatial_query: &SpatialQuery,
+) -> AimPoint {
+    let origin = camera_transform.translation();
+    let direction = camera_transform.forward();
+
+    // Same detection as the player's attack.
+    let enemy_hit = spatial_query.cast_shape(
+        &Collider::sphere(1.7),
+        origin,
+        Quat::IDENTITY,
+        direction,
+        &ShapeCastConfig {
+            max_distance: Reticle::MAX_DISTANCE,
+            ..ShapeCastConfig::DEFAULT
+        },
+        &GameLayer::Enemy.query_filter(),
+    );
+
+    if let Some(enemy_transform) = enemy_hit.and_then(|hit| {
+        q_enemies
+            .get(
+                q_collider_ofs
+                    .get(hit.entity)
+                    .map(|c| c.body)
+                    .unwrap_or(hit.entity),
+            )
+            .ok()
+    }) {
+        return AimPoint {
+            position: enemy_transform.translation() + Vec3::Y * 0.5,
+            on_enemy: true,
+        };
+    }
+
+    let distance = spatial_query
+        .cast_ray(
+            origin,
+            direction,
+            Reticle::MAX_DISTANCE,
+            true,
+            &SpatialQueryFilter::default()
+                .with_mask(GameLayer::environment_mask()),
+        )
+        .map(|hit| hit.distance)
+        .unwrap_or(Reticle::DEFAULT_DISTANCE);
+
+    AimPoint {
+        position: origin + direction * distance,
+        on_enemy: false,
+    }
+}
+
+struct AimPoint {
+    position: Vec3,
+    on_enemy: bool,
+}
+
+/// Aim reticle of a player, rendered in their viewport half.
+#[derive(Component, Deref, Debug, Clone, Copy)]
+#[component(immutable)]
+struct Reticle(PlayerType);
+
+impl Reticle {
+    /// Max aim distance, same as the player's attack range.
+    const MAX_DISTANCE: f32 = 50.0;
+    /// Distance used when the aim ray hits nothing.
+    const DEFAULT_DISTANCE: f32 = 20.0;
+}
+
+/// Stroke of the [`ReticleStyle::Cross`].
+#[derive(Component)]
+struct CrossStroke;
@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::camera_controller::UI_RENDER_LAYER;
+
+use super::Screen;
+
+pub(super) struct ScreenFadePlugin;
+
+impl Plugin for ScreenFadePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenFade>()
+            .add_systems(Startup, spawn_fade_overlay)
+            .add_systems(Update, update_screen_fade);
+    }
+}
+
+fn spawn_fade_overlay(mut commands: Commands) {
+    commands.spawn((
+        FadeOverlay,
+        UI_RENDER_LAYER,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.0)),
+        FocusPolicy::Pass,
+        Visibility::Hidden,
+        // Should be on top of all other uis.
+        GlobalZIndex(50),
+    ));
+}
+
+/// Fade to black, switch the [`Screen`] (which loads the next scene
+/// under the overlay) and fade back in.
+fn update_screen_fade(
+    mut q_overlays: Query<
+        (&mut BackgroundColor, &mut FocusPolicy, &mut Visibility),
+        With<FadeOverlay>,
+    >,
+    mut fade: ResMut<ScreenFade>,
+    mut next_screen: ResMut<NextState<Screen>>,
+    time: Res<Time>,
+) -> Result {
+    let step = time.delta_secs() / ScreenFade::DURATION;
+
+    match fade.phase {
+        FadePhase::Idle => return Ok(()),
+        FadePhase::Out => {
+            fade.alpha = (fade.alpha + step).min(1.0);
+
+            if fade.alpha >= 1.0 {
+                if let Some(screen) = fade.target.take() {
+                    next_screen.set(screen);
+                }
+                fade.phase = FadePhase::In;
+            }
+        }
+        FadePhase::In => {
+            fade.alpha = (fade.alpha - step).max(0.0);
+
+            if fade.alpha <= 0.0 {
+                fade.phase = FadePhase::Idle;
+            }
+        }
+    }
+
+    let (mut color, mut focus_policy, mut visibility) =
+        q_overlays.single_mut()?;
+
+    color.0.set_alpha(fade.alpha);
+    // Block inputs during the transition.
+    *focus_policy = if fade.phase == FadePhase::Idle {
+        FocusPolicy::Pass
+    } else {
+        FocusPolicy::Block
+    };
+    *visibility = if fade.alpha > 0.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+
+    Ok(())
+}
+
+/// Full screen black overlay of the [`ScreenFade`].
+#[derive(Component)]
+struct FadeOverlay;
+
+/// Fade transition between [`Screen`]s that load a different scene.
+#[derive(Resource, Default, Debug)]
+pub struct ScreenFade {
+    phase: FadePhase,
+    target: Option<Screen>,
+    alpha: f32,
+}
+
+impl ScreenFade {
+    /// Duration in seconds of fading in or out.
+    pub const DURATION: f32 = 0.4;
+
+    /// Fade out and transition to the given [`Screen`].
+    ///
+    /// Ignored if a transition is already in progress.
+    pub fn transition_to(&mut self, screen: Screen) {
+        if self.phase != FadePhase::Idle {
+            return;
+        }
+
+        self.phase = FadePhase::Out;
+        self.target = Some(screen);
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+enum FadePhase {
+    #[default]
+    Idle,
+    Out,
+    In,
+}
@@ -0,0 +1,113 @@
+use bevy::color::palettes::css::WHITE;
+use bevy::color::palettes::tailwind::*;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::camera_controller::UI_RENDER_LAYER;
+use crate::settings::Settings;
+use crate::tutorial::{
+    TutorialAsset, TutorialAssetHandle, TutorialProgress,
+};
+use crate::ui::Screen;
+use crate::ui::locale::LocalizedText;
+
+pub(super) struct TutorialUiPlugin;
+
+impl Plugin for TutorialUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(Screen::EnterLevel),
+            spawn_tutorial_ui,
+        )
+        .add_systems(
+            Update,
+            update_tutorial_prompt
+                .run_if(
+                    resource_changed::<TutorialProgress>
+                        .or(resource_changed::<Settings>)
+                        .or(
+                            resource_changed::<Assets<TutorialAsset>>,
+                        ),
+                )
+                .run_if(in_state(Screen::EnterLevel)),
+        );
+    }
+}
+
+/// Spawn the tutorial prompt at the top center of the
+/// [`CameraFull`][crate::camera_controller::split_screen::CameraFull].
+fn spawn_tutorial_ui(mut commands: Commands) {
+    commands.spawn((
+        UI_RENDER_LAYER,
+        StateScoped(Screen::EnterLevel),
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            padding: UiRect::all(Val::Px(20.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Start,
+            ..default()
+        },
+        Pickable::IGNORE,
+        FocusPolicy::Pass,
+        Children::spawn(Spawn((
+            TutorialPrompt,
+            Node {
+                display: Display::None,
+                max_width: Val::Percent(40.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            Pickable::IGNORE,
+            FocusPolicy::Pass,
+            BackgroundColor(ZINC_900.with_alpha(0.6).into()),
+            BorderRadius::all(Val::Px(8.0)),
+            Children::spawn(Spawn((
+                TutorialPromptText,
+                LocalizedText::new(""),
+                TextFont::from_font_size(18.0),
+                TextColor(WHITE.into()),
+                TextLayout::new_with_justify(JustifyText::Center),
+                Pickable::IGNORE,
+            ))),
+        ))),
+    ));
+}
+
+/// Show the prompt of the current step, hiding it
+/// once the tutorial is finished or disabled.
+fn update_tutorial_prompt(
+    progress: Res<TutorialProgress>,
+    settings: Res<Settings>,
+    tutorial: Res<TutorialAssetHandle>,
+    tutorials: Res<Assets<TutorialAsset>>,
+    mut q_prompt: Query<&mut Node, With<TutorialPrompt>>,
+    mut q_text: Query<&mut LocalizedText, With<TutorialPromptText>>,
+) {
+    let (Ok(mut node), Ok(mut text)) =
+        (q_prompt.single_mut(), q_text.single_mut())
+    else {
+        return;
+    };
+
+    let step = tutorials
+        .get(&tutorial.0)
+        .and_then(|steps| progress.current_step(steps))
+        .filter(|_| settings.show_tutorial);
+
+    let Some(step) = step else {
+        node.display = Display::None;
+        return;
+    };
+
+    node.display = Display::DEFAULT;
+    if text.0 != step.prompt {
+        text.0 = step.prompt.clone();
+    }
+}
+
+#[derive(Component)]
+pub struct TutorialPrompt;
+
+#[derive(Component)]
+pub struct TutorialPromptText;
@@ -1,11 +1,24 @@
 use bevy::color::palettes::css::WHITE;
 use bevy::color::palettes::tailwind::*;
+use bevy::ecs::spawn::SpawnWith;
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
 
 use crate::camera_controller::UI_RENDER_LAYER;
-use crate::enemy::spawner::{SpawnWave, WaveCountdown};
+use crate::difficulty::Difficulty;
+use crate::enemy::GlobalEnemyModifiers;
+use crate::enemy::spawner::{
+    EnemySpawner, SpawnWave, StartWave, WaveConfig, WaveCountdown,
+    WaveStartMode,
+};
+use crate::enemy::waves::LevelWaves;
+use crate::player::currency::Currency;
+use crate::player::economy::EconomyConfig;
 use crate::ui::Screen;
+use crate::ui::locale::{Locale, Localizer};
+use crate::ui::widgets::button::{
+    ButtonActivated, ButtonBackground, LabelButton,
+};
 
 pub(super) struct WaveCountdownUiPlugin;
 
@@ -17,12 +30,32 @@ impl Plugin for WaveCountdownUiPlugin {
         )
         .add_systems(
             Update,
-            update_wave_countdown_ui
-                .run_if(in_state(Screen::EnterLevel))
-                .run_if(
+            (
+                update_wave_countdown_ui.run_if(
                     resource_changed::<WaveCountdown>
-                        .or(state_changed::<SpawnWave>),
+                        .or(state_changed::<SpawnWave>)
+                        .or(resource_changed::<Locale>),
                 ),
+                update_wave_income_ui.run_if(
+                    resource_changed::<WaveCountdown>
+                        .or(resource_changed::<Currency>)
+                        .or(resource_changed::<EconomyConfig>)
+                        .or(resource_changed::<Locale>),
+                ),
+                update_wave_start_button
+                    .run_if(resource_changed::<WaveCountdown>),
+                update_danger_meter.run_if(
+                    resource_changed::<WaveCountdown>
+                        .or(state_changed::<SpawnWave>)
+                        .or(resource_changed::<Difficulty>)
+                        .or(resource_changed::<GlobalEnemyModifiers>)
+                        .or(resource_changed::<Locale>)
+                        .or(
+                            any_match_filter::<Changed<EnemySpawner>>,
+                        ),
+                ),
+            )
+                .run_if(in_state(Screen::EnterLevel)),
         );
     }
 }
@@ -45,7 +78,7 @@ fn spawn_wave_countdown_ui(mut commands: Commands) {
         FocusPolicy::Pass,
         Children::spawn(Spawn((
             Node {
-                flex_direction: FlexDirection::Row,
+                flex_direction: FlexDirection::Column,
                 align_self: AlignSelf::End,
                 justify_self: JustifySelf::End,
                 justify_content: JustifyContent::Center,
@@ -64,19 +97,111 @@ fn spawn_wave_countdown_ui(mut commands: Commands) {
                 Val::Px(8.0),
             ),
             BorderRadius::all(Val::Px(8.0)),
-            Children::spawn(Spawn((
-                Text::new("Wave 1 - 00:00"),
-                TextFont::from_font_size(24.0),
-                TextColor(WHITE.into()),
-                WaveCountdownText,
-            ))),
+            Children::spawn((
+                Spawn((
+                    Text::new("Wave 1 - 00:00"),
+                    TextFont::from_font_size(24.0),
+                    TextColor(WHITE.into()),
+                    WaveCountdownText,
+                )),
+                Spawn((
+                    Text::default(),
+                    TextFont::from_font_size(16.0),
+                    TextColor(AMBER_400.into()),
+                    WaveIncomeText,
+                )),
+                Spawn(danger_meter()),
+                SpawnWith(|parent: &mut ChildSpawner| {
+                    parent
+                        .spawn((WaveStartButton, start_button()))
+                        .observe(start_wave_on_click);
+                }),
+            )),
         ))),
     ));
 }
 
+/// Label followed by a pip for every danger level.
+fn danger_meter() -> impl Bundle {
+    (
+        Node {
+            display: Display::None,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(4.0),
+            margin: UiRect::top(Val::Px(4.0)),
+            ..default()
+        },
+        Pickable::IGNORE,
+        DangerMeter,
+        Children::spawn((
+            Spawn((
+                Text::default(),
+                TextFont::from_font_size(16.0),
+                TextColor(WHITE.into()),
+                DangerText,
+            )),
+            SpawnWith(|parent: &mut ChildSpawner| {
+                for level in 1..=WaveConfig::MAX_DANGER_LEVEL {
+                    parent.spawn((
+                        Node {
+                            width: Val::Px(12.0),
+                            height: Val::Px(12.0),
+                            ..default()
+                        },
+                        BorderRadius::all(Val::Px(3.0)),
+                        BackgroundColor(ZINC_600.into()),
+                        Pickable::IGNORE,
+                        DangerPip(level),
+                    ));
+                }
+            }),
+        )),
+    )
+}
+
+fn start_button() -> impl Bundle {
+    let mut button = LabelButton::new("wave.start")
+        .with_background(ButtonBackground::new(EMERALD_500))
+        .with_text_color(WHITE)
+        .with_font_size(16.0);
+    button.node.display = Display::None;
+    button.node.padding = UiRect::axes(Val::Px(20.0), Val::Px(6.0));
+    button.node.margin = UiRect::top(Val::Px(6.0));
+    button.build()
+}
+
+fn start_wave_on_click(
+    _: Trigger<ButtonActivated>,
+    mut commands: Commands,
+) {
+    commands.trigger(StartWave);
+}
+
+/// Only show the start button while a
+/// [`WaveStartMode::Manual`] wave is waiting.
+fn update_wave_start_button(
+    countdown: Res<WaveCountdown>,
+    q_spawner: Query<&EnemySpawner>,
+    mut q_buttons: Query<&mut Node, With<WaveStartButton>>,
+) {
+    let waiting = countdown.finished() == false
+        && q_spawner
+            .single()
+            .is_ok_and(|s| s.start_mode == WaveStartMode::Manual);
+
+    for mut node in q_buttons.iter_mut() {
+        node.display = match waiting {
+            true => Display::DEFAULT,
+            false => Display::None,
+        };
+    }
+}
+
 fn update_wave_countdown_ui(
     countdown: Res<WaveCountdown>,
     current_wave: Res<State<SpawnWave>>,
+    q_spawner: Query<&EnemySpawner>,
+    localizer: Localizer,
     mut q_text: Query<
         (&mut Text, &mut TextColor),
         With<WaveCountdownText>,
@@ -86,20 +211,28 @@ fn update_wave_countdown_ui(
         return;
     };
 
-    let wave_name = match current_wave.get() {
-        SpawnWave::None => "Waiting",
-        SpawnWave::One => "Wave 1",
-        SpawnWave::Two => "Wave 2",
-        SpawnWave::Three => "Wave 3",
-    };
+    let wave_name = localizer.t(match current_wave.get() {
+        SpawnWave::None => "wave.waiting",
+        SpawnWave::One => "wave.one",
+        SpawnWave::Two => "wave.two",
+        SpawnWave::Three => "wave.three",
+    });
 
     let remaining = countdown.duration() - countdown.elapsed();
     let remaining_seconds = remaining.as_secs_f32().max(0.0);
 
     if remaining_seconds <= 0.0 {
         // When countdown finished, just show wave name
-        **text = wave_name.to_string();
+        **text = wave_name;
         text_color.0 = RED_400.into();
+    } else if q_spawner
+        .single()
+        .is_ok_and(|s| s.start_mode == WaveStartMode::Manual)
+    {
+        // The countdown is held until the players start the wave.
+        **text =
+            format!("{} - {}", wave_name, localizer.t("wave.ready"));
+        text_color.0 = WHITE.into();
     } else {
         // Show countdown timer
         let seconds = remaining_seconds as u32;
@@ -118,5 +251,105 @@ fn update_wave_countdown_ui(
     }
 }
 
+/// Show the stipend and interest that will be granted
+/// once the countdown finishes.
+fn update_wave_income_ui(
+    countdown: Res<WaveCountdown>,
+    currency: Res<Currency>,
+    difficulty: Res<Difficulty>,
+    economy: Res<EconomyConfig>,
+    localizer: Localizer,
+    mut q_text: Query<(&mut Text, &mut Node), With<WaveIncomeText>>,
+) {
+    let Ok((mut text, mut node)) = q_text.single_mut() else {
+        return;
+    };
+
+    // Only relevant in between waves.
+    if countdown.finished() {
+        node.display = Display::None;
+        return;
+    }
+
+    let (stipend, interest) =
+        economy.wave_income(*difficulty).payout(currency.0);
+
+    node.display = Display::DEFAULT;
+    **text = format!(
+        "{} +{stipend} | {} +{interest}",
+        localizer.t("wave.stipend"),
+        localizer.t("wave.interest"),
+    );
+}
+
+/// Show the danger level of the upcoming wave
+/// so players can gauge how much to invest.
+///
+/// Rates the [`WaveSpawns`][crate::enemy::waves::WaveSpawns]
+/// of the level when it overrides
+/// the [`WaveConfig`] of the spawner.
+fn update_danger_meter(
+    countdown: Res<WaveCountdown>,
+    current_wave: Res<State<SpawnWave>>,
+    q_spawner: Query<&EnemySpawner>,
+    level_waves: LevelWaves,
+    difficulty: Res<Difficulty>,
+    modifiers: Res<GlobalEnemyModifiers>,
+    localizer: Localizer,
+    mut q_meter: Query<&mut Node, With<DangerMeter>>,
+    mut q_text: Query<&mut Text, With<DangerText>>,
+    mut q_pips: Query<(&DangerPip, &mut BackgroundColor)>,
+) {
+    let Ok(mut node) = q_meter.single_mut() else {
+        return;
+    };
+
+    let health_multiplier =
+        modifiers.health_mult * difficulty.enemy_health_multiplier();
+    let level = match level_waves.get(current_wave.get()) {
+        Some(wave) => Some(wave.danger_level(health_multiplier)),
+        None => q_spawner
+            .single()
+            .ok()
+            .and_then(|spawner| spawner.wave(current_wave.get()))
+            .map(|wave| wave.danger_level(health_multiplier)),
+    };
+
+    // Only relevant in between waves.
+    let Some(level) = level.filter(|_| countdown.finished() == false)
+    else {
+        node.display = Display::None;
+        return;
+    };
+
+    node.display = Display::DEFAULT;
+    if let Ok(mut text) = q_text.single_mut() {
+        **text = localizer.t("wave.danger");
+    }
+
+    for (pip, mut background) in q_pips.iter_mut() {
+        background.0 = match pip.0 <= level {
+            true => RED_500.into(),
+            false => ZINC_600.into(),
+        };
+    }
+}
+
 #[derive(Component)]
 pub struct WaveCountdownText;
+
+#[derive(Component)]
+pub struct WaveIncomeText;
+
+#[derive(Component)]
+pub struct WaveStartButton;
+
+#[derive(Component)]
+pub struct DangerMeter;
+
+#[derive(Component)]
+pub struct DangerText;
+
+/// Filled when the danger level reaches this level.
+#[derive(Component)]
+pub struct DangerPip(pub u8);
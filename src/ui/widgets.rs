@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 pub mod button;
+pub mod menu_navigation;
 pub mod progress_bar;
 
 pub struct WidgetsPlugin;
@@ -9,6 +10,7 @@ impl Plugin for WidgetsPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
             button::ButtonPlugin,
+            menu_navigation::MenuNavigationPlugin,
             progress_bar::ProgressBarPlugin,
         ));
     }
@@ -1,6 +1,8 @@
 use bevy::color::palettes::tailwind::*;
 use bevy::prelude::*;
 
+use crate::ui::locale::LocalizedText;
+
 pub(super) struct ButtonPlugin;
 
 impl Plugin for ButtonPlugin {
@@ -14,6 +16,7 @@ pub struct LabelButton {
     pub background: ButtonBackground,
     pub text_color: Color,
     pub font_size: f32,
+    /// Localization key of the label, see [`LocalizedText`].
     pub label: String,
     pub node: Node,
 }
@@ -64,7 +67,7 @@ impl LabelButton {
                     ..default()
                 },
                 Children::spawn(Spawn((
-                    Text::new(self.label),
+                    LocalizedText::new(self.label),
                     TextLayout::new(
                         JustifyText::Center,
                         LineBreak::WordBoundary,
@@ -92,11 +95,19 @@ fn setup_hover_background(
         .observe(over_btn_background)
         .observe(out_btn_background)
         .observe(pressed_btn_background)
-        .observe(released_btn_background);
+        .observe(released_btn_background)
+        .observe(activate_on_click);
 
     Ok(())
 }
 
+fn activate_on_click(
+    trigger: Trigger<Pointer<Click>>,
+    mut commands: Commands,
+) {
+    commands.trigger_targets(ButtonActivated, trigger.target());
+}
+
 fn over_btn_background(
     trigger: Trigger<Pointer<Over>>,
     mut commands: Commands,
@@ -161,6 +172,11 @@ fn released_btn_background(
     Ok(())
 }
 
+/// Triggered on a button when it is clicked or confirmed
+/// through the [`MenuNavigationPlugin`][super::menu_navigation::MenuNavigationPlugin].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ButtonActivated;
+
 #[derive(Component)]
 pub struct ButtonBackground {
     pub out: Color,
@@ -0,0 +1,294 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+use crate::action::PlayerAction;
+use crate::action::input_context::{
+    InputContext, InputContexts, in_input_context,
+};
+
+use super::button::{ButtonActivated, ButtonBackground};
+
+/// Navigate the visible buttons with [`PlayerAction::Navigate`],
+/// [`PlayerAction::Confirm`] and [`PlayerAction::Back`] while in
+/// the [`InputContext::Menu`].
+pub(super) struct MenuNavigationPlugin;
+
+impl Plugin for MenuNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            navigate_buttons
+                .run_if(in_input_context(InputContext::Menu)),
+        )
+        .add_observer(highlight_focused_button)
+        .add_observer(unhighlight_focused_button);
+    }
+}
+
+/// Move the [`Focused`] button along the navigation direction,
+/// activate it on confirm, or the [`BackButton`] on back.
+fn navigate_buttons(
+    mut commands: Commands,
+    q_actions: Query<(
+        &ActionState<PlayerAction>,
+        &InputContexts,
+        Entity,
+    )>,
+    q_buttons: Query<
+        (
+            &GlobalTransform,
+            &ComputedNode,
+            &InheritedVisibility,
+            Has<Focused>,
+            Has<BackButton>,
+            Entity,
+        ),
+        With<ButtonBackground>,
+    >,
+    // Whether each player was holding a direction.
+    mut navigating: Local<HashMap<Entity, bool>>,
+) {
+    let buttons = q_buttons
+        .iter()
+        .filter(|(_, node, visibility, ..)| {
+            visibility.get() && node.size().cmpgt(Vec2::ZERO).all()
+        })
+        .map(|(transform, _, _, focused, back, entity)| {
+            (transform.translation().xy(), focused, back, entity)
+        })
+        .collect::<Vec<_>>();
+
+    if buttons.is_empty() {
+        return;
+    }
+
+    let focused = buttons
+        .iter()
+        .find(|(_, focused, ..)| *focused)
+        .map(|(position, _, _, entity)| (*position, *entity));
+
+    for (action, contexts, entity) in q_actions.iter() {
+        // Step once per push of the stick.
+        let input = action.axis_pair(&PlayerAction::Navigate);
+        let was_navigating =
+            navigating.insert(entity, input.length() > 0.5);
+
+        if contexts.current() != InputContext::Menu {
+            continue;
+        }
+
+        if action.just_pressed(&PlayerAction::Back) {
+            if let Some((.., entity)) =
+                buttons.iter().find(|(_, _, back, _)| *back)
+            {
+                commands.trigger_targets(ButtonActivated, *entity);
+            }
+            return;
+        }
+
+        if action.just_pressed(&PlayerAction::Confirm) {
+            match focused {
+                Some((_, entity)) => {
+                    commands.trigger_targets(ButtonActivated, entity);
+                }
+                None => focus(&mut commands, None, first(&buttons)),
+            }
+            return;
+        }
+
+        if input.length() > 0.5 && was_navigating != Some(true) {
+            // Ui coordinates grow downwards.
+            let direction = Vec2::new(input.x, -input.y);
+
+            let next = match focused {
+                Some((position, _)) => {
+                    neighbor(position, direction, &buttons)
+                }
+                None => first(&buttons),
+            };
+
+            focus(&mut commands, focused.map(|(_, e)| e), next);
+            return;
+        }
+    }
+}
+
+fn focus(
+    commands: &mut Commands,
+    current: Option<Entity>,
+    next: Option<Entity>,
+) {
+    let Some(next) = next else {
+        return;
+    };
+
+    if let Some(current) = current {
+        commands.entity(current).remove::<Focused>();
+    }
+    commands.entity(next).insert(Focused);
+}
+
+/// The top left button.
+fn first(buttons: &[(Vec2, bool, bool, Entity)]) -> Option<Entity> {
+    buttons
+        .iter()
+        .min_by(|(a, ..), (b, ..)| {
+            a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))
+        })
+        .map(|(.., entity)| *entity)
+}
+
+/// The closest button roughly in the given direction.
+fn neighbor(
+    position: Vec2,
+    direction: Vec2,
+    buttons: &[(Vec2, bool, bool, Entity)],
+) -> Option<Entity> {
+    let direction = direction.try_normalize()?;
+
+    buttons
+        .iter()
+        .filter_map(|(other, .., entity)| {
+            let offset = *other - position;
+            let distance = offset.length();
+
+            // Within 60 degrees of the direction.
+            (distance > 0.0 && offset.dot(direction) > distance * 0.5)
+                .then_some((distance, *entity))
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, entity)| entity)
+}
+
+fn highlight_focused_button(
+    trigger: Trigger<OnAdd, Focused>,
+    mut commands: Commands,
+    q_backgrounds: Query<&ButtonBackground>,
+) {
+    let entity = trigger.target();
+
+    if let Ok(background) = q_backgrounds.get(entity) {
+        commands
+            .entity(entity)
+            .insert(BackgroundColor(background.over));
+    }
+}
+
+fn unhighlight_focused_button(
+    trigger: Trigger<OnRemove, Focused>,
+    mut commands: Commands,
+    q_backgrounds: Query<&ButtonBackground>,
+) {
+    let entity = trigger.target();
+
+    // The button might be despawned along with its menu.
+    if let Ok(background) = q_backgrounds.get(entity) {
+        commands
+            .entity(entity)
+            .try_insert(BackgroundColor(background.out));
+    }
+}
+
+/// The button selected with [`PlayerAction::Navigate`].
+#[derive(Component, Debug)]
+pub struct Focused;
+
+/// Activated on [`PlayerAction::Back`] while visible.
+#[derive(Component, Debug)]
+pub struct BackButton;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Activated(Vec<Entity>);
+
+    fn spawn_button(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                ButtonBackground::new(Color::WHITE),
+                GlobalTransform::from_translation(
+                    position.extend(0.0),
+                ),
+                ComputedNode {
+                    size: Vec2::splat(10.0),
+                    ..ComputedNode::DEFAULT
+                },
+                InheritedVisibility::VISIBLE,
+            ))
+            .observe(
+                |trigger: Trigger<ButtonActivated>,
+                 mut activated: ResMut<Activated>| {
+                    activated.0.push(trigger.target());
+                },
+            )
+            .id()
+    }
+
+    #[test]
+    fn test_navigate_and_confirm_buttons() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, MenuNavigationPlugin))
+            .init_resource::<Activated>();
+
+        let top = spawn_button(&mut app, Vec2::new(100.0, 100.0));
+        let bottom = spawn_button(&mut app, Vec2::new(100.0, 200.0));
+        app.world_mut().entity_mut(bottom).insert(BackButton);
+
+        let mut contexts = InputContexts::default();
+        contexts.push(InputContext::Menu);
+        let player = app
+            .world_mut()
+            .spawn((contexts, ActionState::<PlayerAction>::default()))
+            .id();
+
+        let press = |app: &mut App, action: PlayerAction, pair| {
+            let mut action_state = app
+                .world_mut()
+                .get_mut::<ActionState<PlayerAction>>(player)
+                .unwrap();
+            match pair {
+                Some(pair) => {
+                    action_state.set_axis_pair(&action, pair)
+                }
+                None => action_state.press(&action),
+            }
+            app.update();
+
+            let mut action_state = app
+                .world_mut()
+                .get_mut::<ActionState<PlayerAction>>(player)
+                .unwrap();
+            match pair {
+                Some(_) => {
+                    action_state.set_axis_pair(&action, Vec2::ZERO)
+                }
+                None => action_state.release(&action),
+            }
+            app.update();
+        };
+
+        // Nothing focused yet.
+        press(&mut app, PlayerAction::Confirm, None);
+        assert!(app.world().get::<Focused>(top).is_some());
+        assert!(app.world().resource::<Activated>().0.is_empty());
+
+        // Down on the stick.
+        press(&mut app, PlayerAction::Navigate, Some(Vec2::NEG_Y));
+        assert!(app.world().get::<Focused>(top).is_none());
+        assert!(app.world().get::<Focused>(bottom).is_some());
+
+        press(&mut app, PlayerAction::Confirm, None);
+        assert_eq!(app.world().resource::<Activated>().0, [bottom]);
+
+        // Ignored outside of the menu context.
+        app.world_mut()
+            .get_mut::<InputContexts>(player)
+            .unwrap()
+            .pop(InputContext::Menu);
+        press(&mut app, PlayerAction::Back, None);
+        assert_eq!(app.world().resource::<Activated>().0, [bottom]);
+    }
+}